@@ -53,7 +53,7 @@ class TxPacketSend(Cmd[TxPacketSendRes]):
         return args
 
     def process(self, result: Any) -> TxPacketSendRes:
-        entry = find_entry(result, 'SendPacket')
+        entry = find_entry(result['events'], 'SendPacket')
         return from_dict(TxPacketSendRes, entry)
 
 # -----------------------------------------------------------------------------
@@ -1,5 +1,63 @@
+use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::TransferResult;
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics05_port::capabilities::ScopedCapabilityKeeper;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::signer::Signer;
+
+/// A context supplying read-only access to the account balances of the host chain.
+pub trait BankReader {
+    /// Returns the amount of tokens of denomination `denom` held by `account`.
+    fn balance(&self, account: &Signer, denom: &str) -> u64;
+}
+
+/// A context supplying the bank functionality which the ICS20 module requires to move tokens.
+/// Hosts implement this on top of their own bank module.
+pub trait BankKeeper {
+    /// Transfers `coin` from account `from` to account `to`.
+    fn send_coins(&mut self, from: &Signer, to: &Signer, coin: &Coin) -> Result<(), Error>;
+
+    /// Creates `coin` and credits it to `account`.
+    fn mint_coins(&mut self, account: &Signer, coin: &Coin) -> Result<(), Error>;
+
+    /// Removes `coin` from `account` and destroys it.
+    fn burn_coins(&mut self, account: &Signer, coin: &Coin) -> Result<(), Error>;
+}
 
 /// Captures all the dependencies which the ICS20 module requires to be able to dispatch and
 /// process IBC messages.
-pub trait Ics20Context: ChannelReader + ChannelKeeper + Clone {}
+pub trait Ics20Context: ChannelReader + ChannelKeeper + BankReader + BankKeeper + Clone {
+    /// Returns the capabilities claimed by the ICS20 module, i.e., those of the channels it owns.
+    fn capability_keeper(&self) -> &ScopedCapabilityKeeper;
+
+    /// Returns the write access to the capabilities claimed by the ICS20 module.
+    fn capability_keeper_mut(&mut self) -> &mut ScopedCapabilityKeeper;
+
+    /// Returns the account which holds the tokens escrowed on the given channel end.
+    fn get_channel_escrow_address(&self, port_id: &PortId, channel_id: &ChannelId) -> Signer;
+
+    /// Returns the denomination trace for the voucher denomination `ibc/{hash}`.
+    fn get_denom_trace(&self, hash: &str) -> Option<DenomTrace>;
+
+    /// Stores a denomination trace, indexed by its hash.
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Error>;
+
+    /// Applies the token movements resulting from the ICS20 logic to the host chain.
+    fn store_transfer_result(&mut self, result: TransferResult) -> Result<(), Error> {
+        match result {
+            TransferResult::Send { from, to, coin } => self.send_coins(&from, &to, &coin),
+            TransferResult::Mint {
+                account,
+                coin,
+                denom_trace,
+            } => {
+                if self.get_denom_trace(&denom_trace.hash()).is_none() {
+                    self.store_denom_trace(denom_trace)?;
+                }
+                self.mint_coins(&account, &coin)
+            }
+            TransferResult::Burn { account, coin } => self.burn_coins(&account, &coin),
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Denomination traces and coins, as defined by ICS20.
+//! A denomination trace records the path (i.e., the sequence of port and channel pairs) which a
+//! token followed from its origin chain, e.g. `transfer/channel-0/transfer/channel-3/uatom`.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle_encoding::{Encoding, Hex};
+
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+use ibc_proto::ibc::apps::transfer::v1::DenomTrace as RawDenomTrace;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The prefix of voucher denominations, which are represented as `ibc/{hash}`.
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DenomTrace {
+    /// The chain of port/channel identifiers used for tracing the source of the token,
+    /// e.g. `transfer/channel-0`. Empty for native tokens.
+    pub path: String,
+    /// The base denomination of the token on its origin chain.
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Parses a full denomination path, e.g. `transfer/channel-0/uatom`, into a trace.
+    /// The last element is the base denomination, all preceding ones form the path.
+    pub fn parse(full_denom: &str) -> Self {
+        match full_denom.rfind('/') {
+            Some(i) => Self {
+                path: full_denom[..i].to_string(),
+                base_denom: full_denom[i + 1..].to_string(),
+            },
+            None => Self {
+                path: String::new(),
+                base_denom: full_denom.to_string(),
+            },
+        }
+    }
+
+    /// Returns true if the token did not leave its origin chain yet.
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Returns the full denomination path, e.g. `transfer/channel-0/uatom`.
+    pub fn full_path(&self) -> String {
+        if self.is_native() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Returns the upper-case hex-encoded SHA256 hash of the full denomination path.
+    pub fn hash(&self) -> String {
+        let digest = Sha256::digest(self.full_path().as_bytes());
+        Hex::upper_case().encode_to_string(digest).unwrap()
+    }
+
+    /// Returns the denomination under which the token is held on the current chain: the base
+    /// denomination for native tokens, otherwise the voucher denomination `ibc/{hash}`.
+    pub fn ibc_denom(&self) -> String {
+        if self.is_native() {
+            self.base_denom.clone()
+        } else {
+            format!("{}{}", IBC_DENOM_PREFIX, self.hash())
+        }
+    }
+
+    /// Returns true if the trace starts with the given port and channel, i.e., the token was
+    /// last received over that channel end.
+    pub fn has_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.full_path()
+            .starts_with(&format!("{}/{}/", port_id, channel_id))
+    }
+
+    /// Prepends the given port and channel to the trace.
+    pub fn add_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> Self {
+        let prefix = format!("{}/{}", port_id, channel_id);
+        let path = if self.is_native() {
+            prefix
+        } else {
+            format!("{}/{}", prefix, self.path)
+        };
+
+        Self {
+            path,
+            base_denom: self.base_denom.clone(),
+        }
+    }
+
+    /// Removes the given port and channel from the start of the trace, returns `None` if the
+    /// trace does not start with them.
+    pub fn remove_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Self> {
+        if !self.has_prefix(port_id, channel_id) {
+            return None;
+        }
+
+        let prefix = format!("{}/{}/", port_id, channel_id);
+        Some(Self::parse(&self.full_path()[prefix.len()..]))
+    }
+}
+
+impl fmt::Display for DenomTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.full_path())
+    }
+}
+
+impl FromStr for DenomTrace {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.split('/').any(|part| part.trim().is_empty()) {
+            return Err(Kind::InvalidDenomination(s.to_string()).into());
+        }
+
+        Ok(Self::parse(s))
+    }
+}
+
+impl From<RawDenomTrace> for DenomTrace {
+    fn from(raw: RawDenomTrace) -> Self {
+        Self {
+            path: raw.path,
+            base_denom: raw.base_denom,
+        }
+    }
+}
+
+impl From<DenomTrace> for RawDenomTrace {
+    fn from(trace: DenomTrace) -> Self {
+        Self {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+/// Returns the hash part of a voucher denomination `ibc/{hash}`, or `None` if `denom` is not a
+/// voucher denomination.
+pub fn voucher_hash(denom: &str) -> Option<&str> {
+    denom.strip_prefix(IBC_DENOM_PREFIX)
+}
+
+/// An amount of tokens of a given denomination.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: u64,
+}
+
+impl Coin {
+    pub fn new(denom: impl ToString, amount: u64) -> Self {
+        Self {
+            denom: denom.to_string(),
+            amount,
+        }
+    }
+}
+
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<RawCoin> for Coin {
+    type Error = Error;
+
+    fn try_from(raw: RawCoin) -> Result<Self, Self::Error> {
+        if raw.denom.is_empty() {
+            return Err(Kind::InvalidDenomination(raw.denom).into());
+        }
+
+        let amount = raw
+            .amount
+            .parse()
+            .map_err(|_| Kind::InvalidAmount(raw.amount.clone()))?;
+
+        Ok(Self {
+            denom: raw.denom,
+            amount,
+        })
+    }
+}
+
+impl From<Coin> for RawCoin {
+    fn from(coin: Coin) -> Self {
+        Self {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use super::DenomTrace;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+
+    #[test]
+    fn parse_denom_trace() {
+        let native = DenomTrace::parse("uatom");
+        assert!(native.is_native());
+        assert_eq!(native.ibc_denom(), "uatom");
+
+        let trace = DenomTrace::parse("transfer/channel-0/transfer/channel-1/uatom");
+        assert_eq!(trace.path, "transfer/channel-0/transfer/channel-1");
+        assert_eq!(trace.base_denom, "uatom");
+        assert_eq!(
+            trace.full_path(),
+            "transfer/channel-0/transfer/channel-1/uatom"
+        );
+
+        assert!("transfer//uatom".parse::<DenomTrace>().is_err());
+        assert!("".parse::<DenomTrace>().is_err());
+    }
+
+    #[test]
+    fn voucher_denom() {
+        // The hash is the one computed by the Cosmos SDK for the same denomination trace.
+        let trace = DenomTrace::parse("transfer/channel-0/uatom");
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn denom_trace_prefix() {
+        let port_id: PortId = "transfer".parse().unwrap();
+        let channel_id: ChannelId = "channel-0".parse().unwrap();
+
+        let native = DenomTrace::parse("uatom");
+        assert!(!native.has_prefix(&port_id, &channel_id));
+
+        let prefixed = native.add_prefix(&port_id, &channel_id);
+        assert_eq!(prefixed.full_path(), "transfer/channel-0/uatom");
+        assert!(prefixed.has_prefix(&port_id, &channel_id));
+        assert_eq!(prefixed.remove_prefix(&port_id, &channel_id), Some(native));
+
+        let other: ChannelId = "channel-1".parse().unwrap();
+        assert!(prefixed.remove_prefix(&port_id, &other).is_none());
+    }
+}
@@ -1,7 +1,10 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::application::ics20_fungible_token_transfer::denom::Coin;
+use crate::ics04_channel::channel::Order;
 use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::signer::Signer;
 
 pub type Error = anomaly::Error<Kind>;
 
@@ -35,6 +38,39 @@ pub enum Kind {
 
     #[error("invalid packet timeout timestamp value")]
     InvalidPacketTimeoutTimestamp(u64),
+
+    #[error("the transfer message does not specify a token")]
+    MissingToken,
+
+    #[error("invalid denomination {0}")]
+    InvalidDenomination(String),
+
+    #[error("invalid token amount {0}")]
+    InvalidAmount(String),
+
+    #[error("no denomination trace found for hash {0}")]
+    DenomTraceNotFound(String),
+
+    #[error("insufficient funds: account {0} does not hold {1}")]
+    InsufficientFunds(Signer, Coin),
+
+    #[error("invalid ICS20 packet data")]
+    InvalidPacketData,
+
+    #[error("invalid ICS20 acknowledgement")]
+    InvalidAcknowledgement,
+
+    #[error("ICS20 channels must be unordered, got ordering {0}")]
+    InvalidChannelOrder(Order),
+
+    #[error("ICS20 channels must have version ics20-1, got version {0}")]
+    InvalidChannelVersion(String),
+
+    #[error("the counterparty of an ICS20 channel must have version ics20-1, got version {0}")]
+    InvalidCounterpartyVersion(String),
+
+    #[error("the transfer module does not own the channel end ({0}, {1})")]
+    ChannelNotOwned(PortId, ChannelId),
 }
 
 impl Kind {
@@ -1,5 +1,8 @@
 //! ICS 20: IBC Transfer implementation
 pub mod context;
+pub mod denom;
 pub mod error;
+pub mod module;
 pub mod msgs;
+pub mod packet;
 pub mod relay_application_logic;
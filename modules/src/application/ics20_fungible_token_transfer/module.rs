@@ -0,0 +1,294 @@
+//! The ICS26 callbacks of the ICS20 fungible token transfer module.
+
+use anomaly::BoxError;
+
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::error::Kind;
+use crate::application::ics20_fungible_token_transfer::packet::Acknowledgement;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_acknowledgement_packet::on_acknowledgement_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::on_timeout_packet;
+use crate::ics04_channel::channel::{ChannelEnd, Order};
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::{Capability, CapabilityName};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::module::Module;
+use crate::signer::Signer;
+
+/// The version of the ICS20 channels, on which both channel ends must agree.
+pub const VERSION: &str = "ics20-1";
+
+/// The ICS20 module, to be bound to the `transfer` port of the host.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ics20Module;
+
+impl Ics20Module {
+    /// Checks the channel parameters and claims the capability of the new channel, unless the
+    /// module already owns the channel.
+    fn open_channel<Ctx>(
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_cap: Option<&Capability>,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), BoxError>
+    where
+        Ctx: Ics20Context,
+    {
+        if channel_end.ordering() != &Order::Unordered {
+            return Err(Kind::InvalidChannelOrder(*channel_end.ordering()).into());
+        }
+
+        if channel_end.version() != VERSION {
+            return Err(Kind::InvalidChannelVersion(channel_end.version()).into());
+        }
+
+        let name = CapabilityName::channel(port_id, channel_id);
+        match channel_cap {
+            Some(channel_cap) => ctx
+                .capability_keeper_mut()
+                .claim_capability(name, channel_cap)?,
+            None if ctx.capability_keeper().get_capability(&name).is_none() => {
+                return Err(Kind::ChannelNotOwned(port_id.clone(), channel_id.clone()).into());
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the counterparty channel end has the ICS20 version.
+    fn check_counterparty_version(counterparty_version: &str) -> Result<(), BoxError> {
+        if counterparty_version != VERSION {
+            return Err(Kind::InvalidCounterpartyVersion(counterparty_version.to_string()).into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<Ctx> Module<Ctx> for Ics20Module
+where
+    Ctx: Ics20Context,
+{
+    fn on_chan_open_init(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_cap: &Capability,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), BoxError> {
+        Self::open_channel(ctx, port_id, channel_id, Some(channel_cap), channel_end)
+    }
+
+    fn on_chan_open_try(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_cap: Option<&Capability>,
+        channel_end: &ChannelEnd,
+        counterparty_version: &str,
+    ) -> Result<(), BoxError> {
+        Self::check_counterparty_version(counterparty_version)?;
+        Self::open_channel(ctx, port_id, channel_id, channel_cap, channel_end)
+    }
+
+    fn on_chan_open_ack(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty_version: &str,
+    ) -> Result<(), BoxError> {
+        Self::check_counterparty_version(counterparty_version)
+    }
+
+    fn on_chan_open_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), BoxError> {
+        Ok(())
+    }
+
+    fn on_chan_close_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), BoxError> {
+        Ok(())
+    }
+
+    fn on_chan_close_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), BoxError> {
+        Ok(())
+    }
+
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet, _relayer: &Signer) -> Option<Vec<u8>> {
+        // Failures are not fatal to the reception of the packet, they are reported in the
+        // acknowledgement so that the sending chain refunds the tokens.
+        let ack = match on_recv_packet(ctx, packet) {
+            Ok(result) => match ctx.store_transfer_result(result) {
+                Ok(()) => Acknowledgement::success(),
+                Err(e) => Acknowledgement::from_error(e),
+            },
+            Err(e) => Acknowledgement::from_error(e),
+        };
+
+        Some(ack.to_json())
+    }
+
+    fn on_acknowledgement_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> Result<(), BoxError> {
+        if let Some(result) = on_acknowledgement_packet(ctx, packet, acknowledgement)? {
+            ctx.store_transfer_result(result)?;
+        }
+
+        Ok(())
+    }
+
+    fn on_timeout_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), BoxError> {
+        let result = on_timeout_packet(ctx, packet)?;
+        ctx.store_transfer_result(result)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use anomaly::BoxError;
+
+    use crate::application::ics20_fungible_token_transfer::error::Kind;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use crate::ics26_routing::module::Module;
+    use crate::mock::context::MockContext;
+
+    use super::{Ics20Module, VERSION};
+
+    fn channel_end(version: &str) -> ChannelEnd {
+        ChannelEnd::new(
+            State::Init,
+            Order::Unordered,
+            Counterparty::new(PortId::default(), None),
+            vec![ConnectionId::default()],
+            version.to_string(),
+        )
+    }
+
+    fn kind(result: Result<(), BoxError>) -> Kind {
+        result
+            .expect_err("unexpected success (expected error)")
+            .downcast_ref::<Kind>()
+            .expect("unexpected error kind")
+            .clone()
+    }
+
+    #[test]
+    fn chan_open_init_rejects_other_version() {
+        let mut ctx = MockContext::default();
+        let (port_id, channel_id) = (PortId::default(), ChannelId::default());
+        let channel_cap = get_dummy_capability();
+
+        let res = Ics20Module.on_chan_open_init(
+            &mut ctx,
+            &port_id,
+            &channel_id,
+            &channel_cap,
+            &channel_end("ics20-2"),
+        );
+        assert_eq!(
+            kind(res),
+            Kind::InvalidChannelVersion("ics20-2".to_string())
+        );
+
+        let res = Ics20Module.on_chan_open_init(
+            &mut ctx,
+            &port_id,
+            &channel_id,
+            &channel_cap,
+            &channel_end(VERSION),
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn chan_open_try_rejects_other_version() {
+        let mut ctx = MockContext::default();
+        let (port_id, channel_id) = (PortId::default(), ChannelId::default());
+        let channel_cap = get_dummy_capability();
+
+        let res = Ics20Module.on_chan_open_try(
+            &mut ctx,
+            &port_id,
+            &channel_id,
+            Some(&channel_cap),
+            &channel_end("ics20-2"),
+            VERSION,
+        );
+        assert_eq!(
+            kind(res),
+            Kind::InvalidChannelVersion("ics20-2".to_string())
+        );
+
+        let res = Ics20Module.on_chan_open_try(
+            &mut ctx,
+            &port_id,
+            &channel_id,
+            Some(&channel_cap),
+            &channel_end(VERSION),
+            "ics20-2",
+        );
+        assert_eq!(
+            kind(res),
+            Kind::InvalidCounterpartyVersion("ics20-2".to_string())
+        );
+
+        let res = Ics20Module.on_chan_open_try(
+            &mut ctx,
+            &port_id,
+            &channel_id,
+            Some(&channel_cap),
+            &channel_end(VERSION),
+            VERSION,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn chan_open_ack_rejects_other_counterparty_version() {
+        let mut ctx = MockContext::default();
+        let (port_id, channel_id) = (PortId::default(), ChannelId::default());
+
+        let res = Ics20Module.on_chan_open_ack(&mut ctx, &port_id, &channel_id, "ics20-2");
+        assert_eq!(
+            kind(res),
+            Kind::InvalidCounterpartyVersion("ics20-2".to_string())
+        );
+
+        let res = Ics20Module.on_chan_open_ack(&mut ctx, &port_id, &channel_id, VERSION);
+        assert!(res.is_ok());
+    }
+}
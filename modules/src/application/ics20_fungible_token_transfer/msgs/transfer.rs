@@ -107,6 +107,8 @@ pub mod test_util {
         Height,
     };
 
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+
     use super::MsgTransfer;
     use crate::timestamp::Timestamp;
 
@@ -117,7 +119,10 @@ pub mod test_util {
         MsgTransfer {
             source_port: PortId::default(),
             source_channel: ChannelId::default(),
-            token: None,
+            token: Some(Coin {
+                denom: "uatom".to_string(),
+                amount: "10".to_string(),
+            }),
             sender: id.clone(),
             receiver: id,
             timeout_timestamp: Timestamp::from_nanoseconds(1).unwrap(),
@@ -0,0 +1,156 @@
+//! The ICS20 packet data and acknowledgement, both exchanged as JSON between the two chains.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_proto::ibc::apps::transfer::v1::FungibleTokenPacketData as RawFungibleTokenPacketData;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::signer::Signer;
+
+/// The successful acknowledgement result, i.e., the base64 encoding of the byte `0x01`.
+const ACK_SUCCESS_B64: &str = "AQ==";
+
+/// The data carried by an ICS20 packet.
+/// The fields are declared in alphabetical order, so that the JSON encoding matches the sorted
+/// JSON which the Cosmos SDK commits to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FungibleTokenPacketData {
+    /// The amount is encoded as a string, as the protobuf JSON mapping does for `uint64`.
+    #[serde(serialize_with = "ser_amount", deserialize_with = "de_amount")]
+    pub amount: u64,
+    /// The full denomination path of the token, e.g. `transfer/channel-0/uatom`.
+    pub denom: String,
+    pub receiver: Signer,
+    pub sender: Signer,
+}
+
+impl FungibleTokenPacketData {
+    /// Decodes the packet data from its JSON representation.
+    pub fn from_json(data: &[u8]) -> Result<Self, Error> {
+        let packet_data: Self =
+            serde_json::from_slice(data).map_err(|e| Kind::InvalidPacketData.context(e))?;
+
+        if packet_data.amount == 0 {
+            return Err(Kind::InvalidAmount(packet_data.amount.to_string()).into());
+        }
+
+        Ok(packet_data)
+    }
+
+    /// Encodes the packet data into its JSON representation.
+    pub fn to_json(&self) -> Vec<u8> {
+        // Safety note: serialization of a struct with string fields is infallible.
+        serde_json::to_vec(self).unwrap()
+    }
+}
+
+impl From<RawFungibleTokenPacketData> for FungibleTokenPacketData {
+    fn from(raw: RawFungibleTokenPacketData) -> Self {
+        Self {
+            amount: raw.amount,
+            denom: raw.denom,
+            receiver: raw.receiver.into(),
+            sender: raw.sender.into(),
+        }
+    }
+}
+
+impl From<FungibleTokenPacketData> for RawFungibleTokenPacketData {
+    fn from(data: FungibleTokenPacketData) -> Self {
+        Self {
+            denom: data.denom,
+            amount: data.amount,
+            sender: data.sender.to_string(),
+            receiver: data.receiver.to_string(),
+        }
+    }
+}
+
+fn ser_amount<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    amount.to_string().serialize(serializer)
+}
+
+fn de_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// The acknowledgement written by the receiving chain, following the ICS04 acknowledgement
+/// envelope: either `{"result": "AQ=="}` or `{"error": "<reason>"}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Acknowledgement {
+    Result(String),
+    Error(String),
+}
+
+impl Acknowledgement {
+    pub fn success() -> Self {
+        Self::Result(ACK_SUCCESS_B64.to_string())
+    }
+
+    pub fn from_error(e: impl ToString) -> Self {
+        Self::Error(e.to_string())
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Result(_))
+    }
+
+    /// Decodes the acknowledgement from its JSON representation.
+    pub fn from_json(data: &[u8]) -> Result<Self, Error> {
+        serde_json::from_slice(data).map_err(|e| Kind::InvalidAcknowledgement.context(e).into())
+    }
+
+    /// Encodes the acknowledgement into its JSON representation.
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use super::{Acknowledgement, FungibleTokenPacketData};
+    use crate::signer::Signer;
+
+    #[test]
+    fn packet_data_json() {
+        let data = FungibleTokenPacketData {
+            amount: 100,
+            denom: "transfer/channel-0/uatom".to_string(),
+            receiver: Signer::new("cosmos1receiver"),
+            sender: Signer::new("cosmos1sender"),
+        };
+
+        let json = data.to_json();
+        assert_eq!(
+            String::from_utf8(json.clone()).unwrap(),
+            r#"{"amount":"100","denom":"transfer/channel-0/uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#
+        );
+        assert_eq!(FungibleTokenPacketData::from_json(&json).unwrap(), data);
+
+        let zero = r#"{"amount":"0","denom":"uatom","receiver":"a","sender":"b"}"#;
+        assert!(FungibleTokenPacketData::from_json(zero.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn acknowledgement_json() {
+        let success = Acknowledgement::success();
+        assert_eq!(success.to_json(), br#"{"result":"AQ=="}"#.to_vec());
+        assert!(Acknowledgement::from_json(&success.to_json())
+            .unwrap()
+            .is_success());
+
+        let error = Acknowledgement::from_error("insufficient funds");
+        assert_eq!(
+            error.to_json(),
+            br#"{"error":"insufficient funds"}"#.to_vec()
+        );
+        assert!(!Acknowledgement::from_json(&error.to_json())
+            .unwrap()
+            .is_success());
+    }
+}
@@ -1,3 +1,37 @@
 //! This module implements the processing logic for ICS20 (token transfer) message.
 
+use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+use crate::ics04_channel::packet::PacketResult;
+use crate::signer::Signer;
+
+pub mod on_acknowledgement_packet;
+pub mod on_recv_packet;
+pub mod on_timeout_packet;
 pub mod send_transfer;
+
+/// Describes the token movements which the ICS20 logic requires to be applied to the host chain.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferResult {
+    /// Move `coin` between two accounts, e.g., to or from an escrow account.
+    Send {
+        from: Signer,
+        to: Signer,
+        coin: Coin,
+    },
+    /// Mint the voucher `coin`, tracing back to `denom_trace`, into `account`.
+    Mint {
+        account: Signer,
+        coin: Coin,
+        denom_trace: DenomTrace,
+    },
+    /// Burn the voucher `coin` held by `account`.
+    Burn { account: Signer, coin: Coin },
+}
+
+/// The result of processing a `MsgTransfer`: the packet to be sent, together with the escrowing
+/// or burning of the transferred tokens.
+#[derive(Clone, Debug)]
+pub struct SendTransferResult {
+    pub packet_result: PacketResult,
+    pub transfer_result: TransferResult,
+}
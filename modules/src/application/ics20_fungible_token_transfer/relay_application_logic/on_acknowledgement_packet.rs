@@ -0,0 +1,25 @@
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::Acknowledgement;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::refund_packet_token;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::TransferResult;
+use crate::ics04_channel::packet::Packet;
+
+/// Processes the acknowledgement of a sent ICS20 packet. If the receiving chain failed to
+/// process the packet, the tokens are refunded to the sender.
+pub fn on_acknowledgement_packet<Ctx>(
+    ctx: &Ctx,
+    packet: &Packet,
+    acknowledgement: &[u8],
+) -> Result<Option<TransferResult>, Error>
+where
+    Ctx: Ics20Context,
+{
+    let ack = Acknowledgement::from_json(acknowledgement)?;
+
+    if ack.is_success() {
+        return Ok(None);
+    }
+
+    refund_packet_token(ctx, packet).map(Some)
+}
@@ -0,0 +1,115 @@
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::TransferResult;
+use crate::ics04_channel::packet::Packet;
+
+/// Processes the ICS20 data of a received packet, returning the token movements which credit
+/// the receiver.
+pub fn on_recv_packet<Ctx>(ctx: &Ctx, packet: &Packet) -> Result<TransferResult, Error>
+where
+    Ctx: Ics20Context,
+{
+    let data = FungibleTokenPacketData::from_json(&packet.data)?;
+    let denom_trace: DenomTrace = data.denom.parse()?;
+
+    match denom_trace.remove_prefix(&packet.source_port, &packet.source_channel) {
+        // The token was sent from this chain over this channel before, i.e., this chain is the
+        // source: release the tokens from escrow.
+        Some(unprefixed) => Ok(TransferResult::Send {
+            from: ctx
+                .get_channel_escrow_address(&packet.destination_port, &packet.destination_channel),
+            to: data.receiver,
+            coin: Coin::new(unprefixed.ibc_denom(), data.amount),
+        }),
+        // Otherwise, mint a voucher tracing back to the sending channel end.
+        None => {
+            let prefixed =
+                denom_trace.add_prefix(&packet.destination_port, &packet.destination_channel);
+
+            Ok(TransferResult::Mint {
+                account: data.receiver,
+                coin: Coin::new(prefixed.ibc_denom(), data.amount),
+                denom_trace: prefixed,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use test_env_log::test;
+
+    use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+    use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::TransferResult;
+    use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::ics04_channel::packet::Packet;
+    use crate::mock::context::MockContext;
+    use crate::signer::Signer;
+
+    fn packet_with_denom(denom: &str) -> Packet {
+        let mut packet = Packet::try_from(get_dummy_raw_packet(1, 1)).unwrap();
+        packet.data = FungibleTokenPacketData {
+            amount: 100,
+            denom: denom.to_string(),
+            receiver: Signer::new("receiver"),
+            sender: Signer::new("sender"),
+        }
+        .to_json();
+        packet
+    }
+
+    #[test]
+    fn recv_packet_mints_voucher() {
+        let ctx = MockContext::default();
+        let packet = packet_with_denom("uatom");
+
+        let expected_trace = DenomTrace::parse(&format!(
+            "{}/{}/uatom",
+            packet.destination_port, packet.destination_channel
+        ));
+
+        let result = on_recv_packet(&ctx, &packet).unwrap();
+        assert_eq!(
+            result,
+            TransferResult::Mint {
+                account: Signer::new("receiver"),
+                coin: Coin::new(expected_trace.ibc_denom(), 100),
+                denom_trace: expected_trace,
+            }
+        );
+    }
+
+    #[test]
+    fn recv_packet_unescrows_returning_token() {
+        let ctx = MockContext::default();
+        let packet = packet_with_denom("transfer/channel-0/transfer/channel-1/uatom");
+        let packet = Packet {
+            source_port: "transfer".parse().unwrap(),
+            source_channel: "channel-0".parse().unwrap(),
+            ..packet
+        };
+
+        let result = on_recv_packet(&ctx, &packet).unwrap();
+        assert_eq!(
+            result,
+            TransferResult::Send {
+                from: ctx.get_channel_escrow_address(
+                    &packet.destination_port,
+                    &packet.destination_channel
+                ),
+                to: Signer::new("receiver"),
+                coin: Coin::new(
+                    DenomTrace::parse("transfer/channel-1/uatom").ibc_denom(),
+                    100
+                ),
+            }
+        );
+    }
+}
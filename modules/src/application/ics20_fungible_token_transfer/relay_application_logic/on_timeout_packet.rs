@@ -0,0 +1,39 @@
+use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::TransferResult;
+use crate::ics04_channel::packet::Packet;
+
+/// Processes the timeout of a sent ICS20 packet by refunding the tokens to the sender.
+pub fn on_timeout_packet<Ctx>(ctx: &Ctx, packet: &Packet) -> Result<TransferResult, Error>
+where
+    Ctx: Ics20Context,
+{
+    refund_packet_token(ctx, packet)
+}
+
+/// Reverts the effects of `send_transfer`: escrowed tokens are released back to the sender,
+/// burned vouchers are minted again.
+pub(crate) fn refund_packet_token<Ctx>(ctx: &Ctx, packet: &Packet) -> Result<TransferResult, Error>
+where
+    Ctx: Ics20Context,
+{
+    let data = FungibleTokenPacketData::from_json(&packet.data)?;
+    let denom_trace: DenomTrace = data.denom.parse()?;
+    let coin = Coin::new(denom_trace.ibc_denom(), data.amount);
+
+    if denom_trace.has_prefix(&packet.source_port, &packet.source_channel) {
+        Ok(TransferResult::Mint {
+            account: data.sender,
+            coin,
+            denom_trace,
+        })
+    } else {
+        Ok(TransferResult::Send {
+            from: ctx.get_channel_escrow_address(&packet.source_port, &packet.source_channel),
+            to: data.sender,
+            coin,
+        })
+    }
+}
@@ -1,15 +1,22 @@
+use std::convert::TryFrom;
+
 use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{voucher_hash, Coin, DenomTrace};
 use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
 use crate::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::{
+    SendTransferResult, TransferResult,
+};
 use crate::handler::HandlerOutput;
 use crate::ics04_channel::handler::send_packet::send_packet;
 use crate::ics04_channel::packet::Packet;
-use crate::ics04_channel::packet::PacketResult;
+use crate::ics05_port::capabilities::CapabilityName;
 
 pub(crate) fn send_transfer<Ctx>(
     ctx: &Ctx,
     msg: MsgTransfer,
-) -> Result<HandlerOutput<PacketResult>, Error>
+) -> Result<HandlerOutput<SendTransferResult>, Error>
 where
     Ctx: Ics20Context,
 {
@@ -34,7 +41,47 @@ where
             Kind::SequenceSendNotFound(msg.source_port.clone(), msg.source_channel.clone())
         })?;
 
-    //TODO: Application LOGIC.
+    let coin = Coin::try_from(msg.token.clone().ok_or(Kind::MissingToken)?)?;
+    if coin.amount == 0 {
+        return Err(Kind::InvalidAmount(coin.amount.to_string()).into());
+    }
+
+    // Vouchers are held under their `ibc/{hash}` denomination, the packet carries the full trace.
+    let denom_trace = match voucher_hash(&coin.denom) {
+        Some(hash) => ctx
+            .get_denom_trace(hash)
+            .ok_or_else(|| Kind::DenomTraceNotFound(hash.to_string()))?,
+        None => DenomTrace {
+            path: String::new(),
+            base_denom: coin.denom.clone(),
+        },
+    };
+
+    if ctx.balance(&msg.sender, &coin.denom) < coin.amount {
+        return Err(Kind::InsufficientFunds(msg.sender, coin).into());
+    }
+
+    // If the token was last received over the source channel, this chain is the sink and
+    // the voucher is burned. Otherwise, this chain is the source and the token is escrowed.
+    let transfer_result = if denom_trace.has_prefix(&msg.source_port, &msg.source_channel) {
+        TransferResult::Burn {
+            account: msg.sender.clone(),
+            coin: coin.clone(),
+        }
+    } else {
+        TransferResult::Send {
+            from: msg.sender.clone(),
+            to: ctx.get_channel_escrow_address(&msg.source_port, &msg.source_channel),
+            coin: coin.clone(),
+        }
+    };
+
+    let packet_data = FungibleTokenPacketData {
+        amount: coin.amount,
+        denom: denom_trace.full_path(),
+        receiver: msg.receiver,
+        sender: msg.sender,
+    };
 
     let packet = Packet {
         sequence,
@@ -42,14 +89,108 @@ where
         source_channel: msg.source_channel,
         destination_port,
         destination_channel: destination_channel.clone(),
-        data: vec![0],
+        data: packet_data.to_json(),
         timeout_height: msg.timeout_height,
         timeout_timestamp: msg.timeout_timestamp,
     };
 
+    // The transfer module owns the source channel, it sends the packet with its capability.
+    let channel_cap = ctx
+        .capability_keeper()
+        .get_capability(&CapabilityName::channel(
+            &packet.source_port,
+            &packet.source_channel,
+        ))
+        .ok_or_else(|| {
+            Kind::ChannelNotOwned(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+
     let handler_output =
-        send_packet(ctx, packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+        send_packet(ctx, channel_cap, packet).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+    let mut output = HandlerOutput::builder();
+    output.log(format!(
+        "transfer of {} to {} in progress",
+        coin, packet_data.receiver
+    ));
+
+    let result = SendTransferResult {
+        packet_result: handler_output.result,
+        transfer_result,
+    };
+
+    Ok(output
+        .with_log(handler_output.log)
+        .with_events(handler_output.events)
+        .with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use crate::application::ics20_fungible_token_transfer::error::Kind;
+    use crate::application::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_msg_transfer;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::send_transfer::send_transfer;
+    use crate::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics05_port::capabilities::CapabilityName;
+    use crate::ics05_port::context::CapabilityKeeper;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::timestamp::ZERO_DURATION;
+    use crate::Height;
+
+    #[test]
+    fn send_transfer_requires_channel_ownership() {
+        let msg = get_dummy_msg_transfer(35);
+
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::default(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            "ics20-1".to_string(),
+        );
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        );
+        let context = MockContext::default()
+            .with_client(&ClientId::default(), Height::default())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_channel(PortId::default(), ChannelId::default(), channel_end)
+            .with_send_sequence(PortId::default(), ChannelId::default(), 1.into())
+            .with_balance(msg.sender.clone(), "uatom", 10);
+
+        // The host created the capability of the channel, but the transfer module never claimed
+        // it, hence does not own the channel.
+        let mut ctx = context.clone();
+        ctx.new_capability(CapabilityName::channel(
+            &PortId::default(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+        let res = send_transfer(&ctx, msg.clone());
+        match res {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(e) => assert_eq!(
+                e.kind(),
+                &Kind::ChannelNotOwned(PortId::default(), ChannelId::default())
+            ),
+        }
 
-    //TODO:  add event/atributes and writes to the store issued by the application logic for packet sending.
-    Ok(handler_output)
+        let ctx = context.with_channel_capability(PortId::default(), ChannelId::default());
+        assert!(send_transfer(&ctx, msg).is_ok());
+    }
 }
@@ -4,12 +4,18 @@ use crate::downcast;
 use crate::ics02_client::client_consensus::{AnyConsensusState, ConsensusState};
 use crate::ics02_client::client_state::{AnyClientState, ClientState};
 use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::Kind;
 use crate::ics02_client::header::{AnyHeader, Header};
+use crate::ics02_client::misbehaviour::{AnyMisbehaviour, Misbehaviour};
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_def::SoloMachineClient;
 use crate::ics07_tendermint::client_def::TendermintClient;
+use crate::ics09_localhost::client_def::LocalhostClient;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::Height;
@@ -21,14 +27,30 @@ pub trait ClientDef: Clone {
     type Header: Header;
     type ClientState: ClientState;
     type ConsensusState: ConsensusState;
+    type Misbehaviour: Misbehaviour;
 
-    /// TODO
+    /// Validates `header` against `client_state`. Returns the updated client state, along with
+    /// the consensus state derived from the header. Clients which track the host chain itself
+    /// read the host consensus states from `ctx`.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
 
+    /// Verifies that `misbehaviour` is valid evidence of the counterparty chain misbehaving,
+    /// i.e., that its two headers are conflicting and both descend from consensus states that
+    /// this client trusts, as read from `ctx`. Returns the client state frozen at the height of
+    /// the misbehaviour.
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>>;
+
     fn verify_upgrade_and_update_state(
         &self,
         client_state: &Self::ClientState,
@@ -43,26 +65,36 @@ pub trait ClientDef: Clone {
     /// Verify a `proof` that the consensus state of a given client (at height `consensus_height`)
     /// matches the input `consensus_state`. The parameter `counterparty_height` represent the
     /// height of the counterparty chain that this proof assumes (i.e., the height at which this
-    /// proof was computed).
+    /// proof was computed). The `root` is the commitment root of the consensus state that this
+    /// client stores for that height, all proofs are verified against it. The `ctx` provides read
+    /// access to the host store, which clients of the host chain itself (i.e., localhost clients)
+    /// read directly instead of verifying a proof. Verification may update `client_state`, e.g.,
+    /// solo machine clients consume a sequence with each proof, hence the caller must store it
+    /// once the message carrying the proof has been processed.
     #[allow(clippy::too_many_arguments)]
     fn verify_client_consensus_state(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a connection state matches that of the input `connection_end`.
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: Option<&ConnectionId>,
         expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>>;
@@ -71,10 +103,12 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_channel_state(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
@@ -84,7 +118,8 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
-        _client_state: &Self::ClientState,
+        ctx: &dyn ConnectionReader,
+        _client_state: &mut Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
         prefix: &CommitmentPrefix,
@@ -97,22 +132,28 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_data(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
-        commitment: String,
+        commitment: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a packet has been commited.
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_acknowledgement(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -123,9 +164,12 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_next_sequence_recv(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -135,9 +179,12 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_packet_receipt_absence(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -147,6 +194,8 @@ pub trait ClientDef: Clone {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyClient {
     Tendermint(TendermintClient),
+    SoloMachine(SoloMachineClient),
+    Localhost(LocalhostClient),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClient),
@@ -156,6 +205,8 @@ impl AnyClient {
     pub fn from_client_type(client_type: ClientType) -> AnyClient {
         match client_type {
             ClientType::Tendermint => Self::Tendermint(TendermintClient),
+            ClientType::SoloMachine => Self::SoloMachine(SoloMachineClient),
+            ClientType::Localhost => Self::Localhost(LocalhostClient),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => Self::Mock(MockClient),
@@ -168,10 +219,12 @@ impl ClientDef for AnyClient {
     type Header = AnyHeader;
     type ClientState = AnyClientState;
     type ConsensusState = AnyConsensusState;
+    type Misbehaviour = AnyMisbehaviour;
 
     /// Validates an incoming `header` against the latest consensus state of this client.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
         client_state: AnyClientState,
         header: AnyHeader,
     ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
@@ -184,7 +237,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_state, header)?;
 
                 Ok((
                     AnyClientState::Tendermint(new_state),
@@ -192,6 +245,32 @@ impl ClientDef for AnyClient {
                 ))
             }
 
+            Self::SoloMachine(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    header => AnyHeader::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_state, header)?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_state, header)?;
+
+                Ok((AnyClientState::Localhost(new_state), new_consensus))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let (client_state, header) = downcast!(
@@ -201,7 +280,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_state, header)?;
 
                 Ok((
                     AnyClientState::Mock(new_state),
@@ -211,12 +290,90 @@ impl ClientDef for AnyClient {
         }
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        misbehaviour: AnyMisbehaviour,
+    ) -> Result<AnyClientState, Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    misbehaviour => AnyMisbehaviour::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Tendermint(new_state))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    misbehaviour => AnyMisbehaviour::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::SoloMachine(new_state))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Localhost(new_state))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    misbehaviour => AnyMisbehaviour::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Mock(new_state))
+            }
+        }
+    }
+
     fn verify_client_consensus_state(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
@@ -229,10 +386,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_client_consensus_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_consensus_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_consensus_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     client_id,
                     consensus_height,
                     expected_consensus_state,
@@ -247,10 +444,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_client_consensus_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     client_id,
                     consensus_height,
                     expected_consensus_state,
@@ -261,10 +460,12 @@ impl ClientDef for AnyClient {
 
     fn verify_connection_state(
         &self,
-        client_state: &AnyClientState,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: Option<&ConnectionId>,
         expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -274,10 +475,44 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_connection_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_connection_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_connection_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     connection_id,
                     expected_connection_end,
                 )
@@ -289,10 +524,12 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_connection_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     connection_id,
                     expected_connection_end,
                 )
@@ -302,10 +539,12 @@ impl ClientDef for AnyClient {
 
     fn verify_channel_state(
         &self,
-        client_state: &AnyClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
@@ -316,10 +555,46 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_channel_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_channel_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_channel_state(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     expected_channel_end,
@@ -332,10 +607,12 @@ impl ClientDef for AnyClient {
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_channel_state(
+                    ctx,
                     client_state,
                     height,
                     prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     expected_channel_end,
@@ -346,7 +623,8 @@ impl ClientDef for AnyClient {
 
     fn verify_client_full_state(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
         prefix: &CommitmentPrefix,
@@ -362,6 +640,43 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_client_full_state(
+                    ctx,
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_full_state(
+                    ctx,
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_full_state(
+                    ctx,
                     client_state,
                     height,
                     root,
@@ -380,6 +695,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_client_full_state(
+                    ctx,
                     client_state,
                     height,
                     root,
@@ -393,13 +709,16 @@ impl ClientDef for AnyClient {
     }
     fn verify_packet_data(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
-        commitment: String,
+        commitment: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         match self {
             Self::Tendermint(client) => {
@@ -409,9 +728,52 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_packet_data(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                    commitment,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_data(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                    commitment,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_data(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -427,9 +789,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_packet_data(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -441,9 +806,12 @@ impl ClientDef for AnyClient {
 
     fn verify_packet_acknowledgement(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -457,9 +825,52 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_packet_acknowledgement(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                    ack,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_acknowledgement(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                    ack,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_acknowledgement(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -475,9 +886,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_packet_acknowledgement(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -489,9 +903,12 @@ impl ClientDef for AnyClient {
 
     fn verify_next_sequence_recv(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -504,9 +921,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_next_sequence_recv(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_next_sequence_recv(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_next_sequence_recv(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -521,9 +979,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_next_sequence_recv(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -533,9 +994,12 @@ impl ClientDef for AnyClient {
     }
     fn verify_packet_receipt_absence(
         &self,
-        client_state: &Self::ClientState,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
         height: Height,
+        prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         seq: &Sequence,
@@ -548,9 +1012,50 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 client.verify_packet_receipt_absence(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_receipt_absence(
+                    ctx,
+                    client_state,
+                    height,
+                    prefix,
+                    proof,
+                    root,
+                    port_id,
+                    channel_id,
+                    seq,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_receipt_absence(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -565,9 +1070,12 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 client.verify_packet_receipt_absence(
+                    ctx,
                     client_state,
                     height,
+                    prefix,
                     proof,
+                    root,
                     port_id,
                     channel_id,
                     seq,
@@ -604,6 +1112,40 @@ impl ClientDef for AnyClient {
                 ))
             }
 
+            Self::SoloMachine(client) => {
+                let (client_state, consensus_state) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    consensus_state => AnyConsensusState::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    client_state,
+                    consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((AnyClientState::Localhost(new_state), new_consensus))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let (client_state, consensus_state) = downcast!(
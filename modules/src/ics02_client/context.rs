@@ -6,7 +6,7 @@ use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error::Error;
-use crate::ics02_client::handler::ClientResult::{self, Create, Update, Upgrade};
+use crate::ics02_client::handler::ClientResult::{self, Create, Misbehaviour, Update, Upgrade};
 use crate::ics24_host::identifier::ClientId;
 use crate::Height;
 
@@ -16,6 +16,9 @@ pub trait ClientReader {
     fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
     fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState>;
 
+    /// Returns the consensus state of the host chain at height `height`, if the host still keeps it.
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState>;
+
     /// Returns a natural number, counting how many clients have been created thus far.
     /// The value of this counter should increase only via method `ClientKeeper::increase_client_counter`.
     fn client_counter(&self) -> u64;
@@ -56,6 +59,10 @@ pub trait ClientKeeper {
                 )?;
                 Ok(())
             }
+            Misbehaviour(res) => {
+                // Only the client state changes, it is now frozen.
+                self.store_client_state(res.client_id, res.client_state)
+            }
         }
     }
 
@@ -66,7 +73,7 @@ pub trait ClientKeeper {
         client_type: ClientType,
     ) -> Result<(), Error>;
 
-    /// Called upon successful client creation and update
+    /// Called upon successful client creation, update and misbehaviour submission
     fn store_client_state(
         &mut self,
         client_id: ClientId,
@@ -37,6 +37,12 @@ pub enum Kind {
     #[error("header verification failed")]
     HeaderVerificationFailure,
 
+    #[error("misbehaviour verification failed")]
+    MisbehaviourVerificationFailure,
+
+    #[error("misbehaviour of client {1} submitted for client {0}")]
+    MismatchedMisbehaviourClientId(ClientId, ClientId),
+
     #[error("unknown client state type: {0}")]
     UnknownClientStateType(String),
 
@@ -272,6 +272,12 @@ impl ClientMisbehaviour {
     }
 }
 
+impl From<Attributes> for ClientMisbehaviour {
+    fn from(attrs: Attributes) -> Self {
+        ClientMisbehaviour(attrs)
+    }
+}
+
 impl TryFrom<RawObject> for ClientMisbehaviour {
     type Error = BoxError;
     fn try_from(obj: RawObject) -> Result<Self, Self::Error> {
@@ -0,0 +1,252 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgSubmitAnyMisbehaviour`.
+
+use crate::events::IbcEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::client_state::{AnyClientState, ClientState};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::events::Attributes;
+use crate::ics02_client::handler::ClientResult;
+use crate::ics02_client::misbehaviour::Misbehaviour;
+use crate::ics02_client::msgs::misbehavior::MsgSubmitAnyMisbehaviour;
+use crate::ics24_host::identifier::ClientId;
+
+/// The result following the successful processing of a `MsgSubmitAnyMisbehaviour` message.
+/// Preferably this data type should be used with a qualified name `misbehaviour::Result` to avoid
+/// ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitAnyMisbehaviour,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitAnyMisbehaviour {
+        client_id,
+        misbehaviour,
+        signer: _,
+    } = msg;
+
+    // The evidence must be about the client it is submitted for, lest it freezes another client
+    // of the same chain.
+    if misbehaviour.client_id() != &client_id {
+        return Err(Kind::MismatchedMisbehaviourClientId(
+            client_id,
+            misbehaviour.client_id().clone(),
+        )
+        .into());
+    }
+
+    // Read client type from the host chain store. The client should already exist.
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_type(client_type);
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    let misbehaviour_height = misbehaviour.height();
+
+    // Verify the misbehaviour against the consensus states trusted by the client. On success,
+    // this returns the frozen client state, which will be later persisted by the keeper.
+    let new_client_state = client_def
+        .check_misbehaviour_and_update_state(ctx, client_id.clone(), client_state, misbehaviour)
+        .map_err(|e| Kind::MisbehaviourVerificationFailure.context(e.to_string()))?;
+
+    let result = ClientResult::Misbehaviour(Result {
+        client_id: client_id.clone(),
+        client_state: new_client_state,
+    });
+
+    let event_attributes = Attributes {
+        client_id,
+        client_type,
+        consensus_height: misbehaviour_height,
+        ..Default::default()
+    };
+    output.emit(IbcEvent::ClientMisbehaviour(event_attributes.into()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use crate::events::IbcEvent;
+    use crate::handler::HandlerOutput;
+    use crate::ics02_client::client_state::{AnyClientState, ClientState};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics02_client::error::Kind;
+    use crate::ics02_client::handler::dispatch;
+    use crate::ics02_client::handler::ClientResult::Misbehaviour;
+    use crate::ics02_client::msgs::misbehavior::MsgSubmitAnyMisbehaviour;
+    use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::MockClientState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    fn conflicting_misbehaviour(client_id: &ClientId, height: Height) -> MockMisbehaviour {
+        MockMisbehaviour {
+            client_id: client_id.clone(),
+            header1: MockHeader::new(height),
+            header2: MockHeader {
+                height,
+                timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+                root: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_freezes_client() {
+        let client_id = ClientId::default();
+        let signer = get_dummy_account_id();
+        let misbehaviour_height = Height::new(0, 46);
+
+        let mut ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: conflicting_misbehaviour(&client_id, misbehaviour_height).into(),
+            signer: signer.clone(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg));
+
+        match output {
+            Ok(HandlerOutput {
+                result,
+                mut events,
+                log,
+            }) => {
+                assert_eq!(events.len(), 1);
+                let event = events.pop().unwrap();
+                assert!(
+                    matches!(event, IbcEvent::ClientMisbehaviour(e) if e.client_id() == &client_id)
+                );
+                assert!(log.is_empty());
+
+                match result {
+                    Misbehaviour(ref res) => {
+                        assert_eq!(res.client_id, client_id);
+                        assert!(res.client_state.is_frozen());
+                        assert_eq!(
+                            res.client_state,
+                            AnyClientState::Mock(
+                                MockClientState::new(MockHeader::new(Height::new(0, 42)))
+                                    .with_frozen_height(misbehaviour_height)
+                            )
+                        );
+                    }
+                    _ => panic!("misbehaviour handler result has incorrect type"),
+                }
+
+                ctx.store_client_result(result).unwrap();
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+
+        // A frozen client can no longer be updated.
+        let msg = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: MockHeader::new(Height::new(0, 50)).into(),
+            signer,
+        };
+
+        let output = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+        match output {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(err) => assert_eq!(err.kind(), &Kind::ClientFrozen(client_id)),
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_non_conflicting_headers() {
+        let client_id = ClientId::default();
+        let height = Height::new(0, 46);
+
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 42));
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: MockMisbehaviour {
+                client_id,
+                header1: MockHeader::new(height),
+                header2: MockHeader::new(height),
+            }
+            .into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg));
+        match output {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(err) => assert_eq!(err.kind(), &Kind::MisbehaviourVerificationFailure),
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_of_other_client() {
+        let client_id = ClientId::default();
+        let other_client_id = ClientId::new(ClientType::Mock, 1).unwrap();
+
+        // Both clients track the same chain, but the evidence is about the other client.
+        let ctx = MockContext::default()
+            .with_client(&client_id, Height::new(0, 42))
+            .with_client(&other_client_id, Height::new(0, 42));
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: conflicting_misbehaviour(&other_client_id, Height::new(0, 46)).into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg));
+        match output {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(err) => assert_eq!(
+                err.kind(),
+                &Kind::MismatchedMisbehaviourClientId(client_id, other_client_id)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_nonexisting_client() {
+        let client_id = ClientId::default();
+        let ctx = MockContext::default();
+
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: client_id.clone(),
+            misbehaviour: conflicting_misbehaviour(&client_id, Height::new(0, 46)).into(),
+            signer: get_dummy_account_id(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg));
+        match output {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(err) => assert_eq!(err.kind(), &Kind::ClientNotFound(client_id)),
+        }
+    }
+}
@@ -8,6 +8,7 @@ use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
 
 use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error;
 use crate::ics02_client::error::{Error, Kind};
 use crate::signer::Signer;
@@ -29,7 +30,10 @@ impl MsgCreateAnyClient {
         consensus_state: AnyConsensusState,
         signer: Signer,
     ) -> Result<Self, Error> {
-        if client_state.client_type() != consensus_state.client_type() {
+        // A localhost client keeps the consensus states of the host, whatever their type.
+        if client_state.client_type() != ClientType::Localhost
+            && client_state.client_type() != consensus_state.client_type()
+        {
             return Err(error::Kind::RawClientAndConsensusStateTypesMismatch {
                 state_type: client_state.client_type(),
                 consensus_type: consensus_state.client_type(),
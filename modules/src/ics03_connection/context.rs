@@ -51,7 +51,7 @@ pub trait ConnectionReader {
         &self,
         supported_versions: Vec<Version>,
         counterparty_candidate_versions: Vec<Version>,
-    ) -> Option<Version> {
+    ) -> Result<Version, Error> {
         pick_version(supported_versions, counterparty_candidate_versions)
     }
 
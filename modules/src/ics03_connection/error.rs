@@ -41,6 +41,12 @@ pub enum Kind {
     #[error("no common version")]
     NoCommonVersion,
 
+    #[error("no common features for version {0}")]
+    NoCommonFeatures(String),
+
+    #[error("version is not supported by the local chain")]
+    VersionNotSupported,
+
     #[error("invalid address")]
     InvalidAddress,
 
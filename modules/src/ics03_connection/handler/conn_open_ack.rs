@@ -9,6 +9,7 @@ use crate::ics03_connection::events::Attributes;
 use crate::ics03_connection::handler::verify::{check_client_consensus_height, verify_proofs};
 use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::ics03_connection::version::is_supported_version;
 
 pub(crate) fn process(
     ctx: &dyn ConnectionReader,
@@ -26,7 +27,7 @@ pub(crate) fn process(
             // Check if the connection state is either Init or TryOpen and message version
             // is compatible.
             let state_is_consistent = old_conn_end.state_matches(&State::Init)
-                && old_conn_end.versions().contains(msg.version())
+                && is_supported_version(&old_conn_end.versions(), msg.version())
                 || old_conn_end.state_matches(&State::TryOpen)
                     && old_conn_end.versions().get(0).eq(&Some(msg.version()));
 
@@ -57,6 +58,13 @@ pub(crate) fn process(
         }
     }?;
 
+    // Record the identifier of the counterparty connection end, against which proofs are verified.
+    new_conn_end.set_counterparty(Counterparty::new(
+        new_conn_end.counterparty().client_id().clone(),
+        Some(msg.counterparty_connection_id().clone()),
+        new_conn_end.counterparty().prefix().clone(),
+    ));
+
     // Proof verification.
     let expected_conn = ConnectionEnd::new(
         State::TryOpen,
@@ -64,15 +72,15 @@ pub(crate) fn process(
         Counterparty::new(
             // The counterparty is the local chain.
             new_conn_end.client_id().clone(), // The local client identifier.
-            Some(msg.counterparty_connection_id().clone()), // This chain's connection id as known on counterparty.
-            ctx.commitment_prefix(),                        // Local commitment prefix.
+            Some(msg.connection_id().clone()), // Local connection id.
+            ctx.commitment_prefix(),          // Local commitment prefix.
         ),
         vec![msg.version().clone()],
         new_conn_end.delay_period(),
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(
+    let client_state = verify_proofs(
         ctx,
         msg.client_state(),
         &new_conn_end,
@@ -88,6 +96,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_id: msg.connection_id().clone(),
         connection_id_state: ConnectionIdState::Reused,
+        client_state: Some((new_conn_end.client_id().clone(), client_state)),
         connection_end: new_conn_end,
     };
 
@@ -8,6 +8,7 @@ use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::events::Attributes;
 use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::ics03_connection::version::is_supported_version;
 use crate::ics24_host::identifier::ConnectionId;
 
 pub(crate) fn process(
@@ -21,11 +22,18 @@ pub(crate) fn process(
         return Err(Kind::MissingClient(msg.client_id().clone()).into());
     }
 
+    // The proposed version must be one that the local chain supports.
+    if !is_supported_version(&ctx.get_compatible_versions(), &msg.version) {
+        return Err(Kind::VersionNotSupported
+            .context(format!("{:?}", msg.version))
+            .into());
+    }
+
     let new_connection_end = ConnectionEnd::new(
         State::Init,
         msg.client_id().clone(),
         msg.counterparty().clone(),
-        ctx.get_compatible_versions(),
+        vec![msg.version.clone()],
         msg.delay_period,
     );
 
@@ -42,6 +50,7 @@ pub(crate) fn process(
         connection_id: conn_id.clone(),
         connection_id_state: ConnectionIdState::Generated,
         connection_end: new_connection_end,
+        client_state: None,
     };
 
     let event_attributes = Attributes {
@@ -64,6 +73,7 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_raw_msg_conn_open_init;
     use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
     use crate::ics03_connection::msgs::ConnectionMsg;
+    use crate::ics03_connection::version::Version;
     use crate::mock::context::MockContext;
     use crate::Height;
 
@@ -79,6 +89,10 @@ mod tests {
         let msg_conn_init =
             MsgConnectionOpenInit::try_from(get_dummy_raw_msg_conn_open_init()).unwrap();
         let context = MockContext::default();
+        let msg_unsupported_version = MsgConnectionOpenInit {
+            version: Version::new("2".to_string(), vec!["ORDER_UNORDERED".to_string()]),
+            ..msg_conn_init.clone()
+        };
 
         let tests: Vec<Test> = vec![
             Test {
@@ -89,10 +103,18 @@ mod tests {
             },
             Test {
                 name: "Good parameters".to_string(),
-                ctx: context.with_client(msg_conn_init.client_id(), Height::new(0, 10)),
+                ctx: context
+                    .clone()
+                    .with_client(msg_conn_init.client_id(), Height::new(0, 10)),
                 msg: ConnectionMsg::ConnectionOpenInit(msg_conn_init.clone()),
                 want_pass: true,
             },
+            Test {
+                name: "Processing fails because the proposed version is not supported".to_string(),
+                ctx: context.with_client(msg_conn_init.client_id(), Height::new(0, 10)),
+                msg: ConnectionMsg::ConnectionOpenInit(msg_unsupported_version),
+                want_pass: false,
+            },
         ]
         .into_iter()
         .collect();
@@ -116,6 +138,11 @@ mod tests {
                     let res: ConnectionResult = proto_output.result;
                     assert_eq!(res.connection_end.state().clone(), State::Init);
 
+                    // The connection end records the version proposed in the message.
+                    if let ConnectionMsg::ConnectionOpenInit(msg) = &test.msg {
+                        assert_eq!(res.connection_end.versions(), vec![msg.version.clone()]);
+                    }
+
                     for e in proto_output.events.iter() {
                         assert!(matches!(e, &IbcEvent::OpenInitConnection(_)));
                     }
@@ -79,7 +79,7 @@ pub(crate) fn process(
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(
+    let client_state = verify_proofs(
         ctx,
         msg.client_state(),
         &new_connection_end,
@@ -91,10 +91,8 @@ pub(crate) fn process(
     new_connection_end.set_state(State::TryOpen);
 
     // Pick the version.
-    new_connection_end.set_version(
-        ctx.pick_version(ctx.get_compatible_versions(), msg.counterparty_versions())
-            .ok_or(Kind::NoCommonVersion)?,
-    );
+    new_connection_end
+        .set_version(ctx.pick_version(ctx.get_compatible_versions(), msg.counterparty_versions())?);
 
     assert_eq!(new_connection_end.versions().len(), 1);
 
@@ -107,6 +105,7 @@ pub(crate) fn process(
         } else {
             ConnectionIdState::Reused
         },
+        client_state: Some((new_connection_end.client_id().clone(), client_state)),
         connection_end: new_connection_end,
     };
 
@@ -10,16 +10,28 @@ use crate::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::proofs::{ConsensusProof, Proofs};
 use crate::Height;
 
-/// Entry point for verifying all proofs bundled in any ICS3 message.
+/// Entry point for verifying all proofs bundled in any ICS3 message. Returns the state of the
+/// client which verified the proofs, as updated by the verification.
 pub fn verify_proofs(
     ctx: &dyn ConnectionReader,
-    client_state: Option<AnyClientState>,
+    expected_client_state: Option<AnyClientState>,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
+) -> Result<AnyClientState, Error> {
+    // Fetch the client state (IBC client on the local/host chain).
+    let mut client_state = ctx
+        .client_state(connection_end.client_id())
+        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
+
+    // The client must not be frozen.
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
+    }
+
     verify_connection_proof(
         ctx,
+        &mut client_state,
         connection_end,
         expected_conn,
         proofs.height(),
@@ -27,9 +39,10 @@ pub fn verify_proofs(
     )?;
 
     // If the message includes a client state, then verify the proof for that state.
-    if let Some(expected_client_state) = client_state {
+    if let Some(expected_client_state) = expected_client_state {
         verify_client_proof(
             ctx,
+            &mut client_state,
             connection_end,
             expected_client_state,
             proofs.height(),
@@ -42,15 +55,16 @@ pub fn verify_proofs(
 
     // If a consensus proof is attached to the message, then verify it.
     if let Some(proof) = proofs.consensus_proof() {
-        Ok(verify_consensus_proof(
+        verify_consensus_proof(
             ctx,
+            &mut client_state,
             connection_end,
             proofs.height(),
             &proof,
-        )?)
-    } else {
-        Ok(())
+        )?;
     }
+
+    Ok(client_state)
 }
 
 /// Verifies the authenticity and semantic correctness of a commitment `proof`. The commitment
@@ -58,32 +72,18 @@ pub fn verify_proofs(
 /// which created this proof). This object must match the state of `expected_conn`.
 pub fn verify_connection_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &mut AnyClientState,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proof_height: Height,
     proof: &CommitmentProofBytes,
 ) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local/host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
     // The client must have the consensus state for the height where this proof was created.
-    if ctx
+    let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(
-            proof_height,
-            connection_end.client_id().clone(),
-        )
-        .into());
-    }
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState(proof_height, connection_end.client_id().clone())
+        })?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
@@ -92,10 +92,12 @@ pub fn verify_connection_proof(
     // error as this is the connection id on the counterparty chain that must always be present.
     Ok(client_def
         .verify_connection_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof,
+            consensus_state.root(),
             connection_end.counterparty().connection_id(),
             expected_conn,
         )
@@ -111,20 +113,12 @@ pub fn verify_connection_proof(
 /// `proof` is correct.
 pub fn verify_client_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &mut AnyClientState,
     connection_end: &ConnectionEnd,
     expected_client_state: AnyClientState,
     proof_height: Height,
     proof: &CommitmentProofBytes,
 ) -> Result<(), Error> {
-    // Fetch the local client state (IBC client running on the host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
     let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
         .ok_or_else(|| {
@@ -135,7 +129,8 @@ pub fn verify_client_proof(
 
     Ok(client_def
         .verify_client_full_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             consensus_state.root(),
             connection_end.counterparty().prefix(),
@@ -151,18 +146,17 @@ pub fn verify_client_proof(
 
 pub fn verify_consensus_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &mut AnyClientState,
     connection_end: &ConnectionEnd,
     proof_height: Height,
     proof: &ConsensusProof,
 ) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
+    // The client must have the consensus state for the height where this proof was created.
+    let consensus_state = ctx
+        .client_consensus_state(connection_end.client_id(), proof_height)
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState(proof_height, connection_end.client_id().clone())
+        })?;
 
     // Fetch the expected consensus state from the historical (local) header data.
     let expected_consensus = ctx
@@ -173,10 +167,12 @@ pub fn verify_consensus_proof(
 
     Ok(client
         .verify_client_consensus_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof.proof(),
+            consensus_state.root(),
             connection_end.counterparty().client_id(),
             proof.height(),
             &expected_consensus,
@@ -5,7 +5,7 @@ use tendermint_proto::Protobuf;
 
 use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
 
-use crate::ics03_connection::error::Kind;
+use crate::ics03_connection::error::{Error, Kind};
 
 /// Stores the identifier and the features supported by a version
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -17,10 +17,54 @@ pub struct Version {
 }
 
 impl Version {
+    pub fn new(identifier: String, features: Vec<String>) -> Self {
+        Self {
+            identifier,
+            features,
+        }
+    }
+
+    /// Getter for the version identifier.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Getter for the list of features supported by this version.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
     /// Checks whether or not the given feature is supported in this versin
     pub fn is_supported_feature(&self, feature: String) -> bool {
         self.features.contains(&feature)
     }
+
+    /// Checks that `proposed` is a valid instance of this version, i.e., it has the same
+    /// identifier, a non-empty list of features, and every proposed feature is supported.
+    pub fn verify_proposed_version(&self, proposed: &Version) -> Result<(), Error> {
+        if self.identifier != proposed.identifier {
+            return Err(Kind::InvalidVersion
+                .context(format!(
+                    "proposed version identifier {} does not match supported identifier {}",
+                    proposed.identifier, self.identifier
+                ))
+                .into());
+        }
+        if proposed.features.is_empty() {
+            return Err(Kind::NoCommonFeatures(proposed.identifier.clone()).into());
+        }
+        for feature in proposed.features.iter() {
+            if !self.is_supported_feature(feature.clone()) {
+                return Err(Kind::InvalidVersion
+                    .context(format!(
+                        "feature {} is not supported by version {}",
+                        feature, self.identifier
+                    ))
+                    .into());
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Protobuf<RawVersion> for Version {}
@@ -70,26 +114,48 @@ pub fn get_compatible_versions() -> Vec<Version> {
     vec![Version::default()]
 }
 
+/// Checks whether `version` is an instance of one of the `supported_versions`, i.e., a supported
+/// version with the same identifier exists and supports all the features of `version`.
+pub fn is_supported_version(supported_versions: &[Version], version: &Version) -> bool {
+    supported_versions
+        .iter()
+        .find(|s| s.identifier == version.identifier)
+        .map_or(false, |s| s.verify_proposed_version(version).is_ok())
+}
+
 /// Selects a version from the intersection of locally supported and counterparty versions.
+/// The version with the lowest common identifier is picked, restricted to the features that
+/// both sides support. Fails if there is no common identifier, or if the picked version has no
+/// common features.
 pub fn pick_version(
     supported_versions: Vec<Version>,
     counterparty_versions: Vec<Version>,
-) -> Option<Version> {
+) -> Result<Version, Error> {
     let mut intersection: Vec<Version> = vec![];
     for s in supported_versions.iter() {
         for c in counterparty_versions.iter() {
             if c.identifier != s.identifier {
                 continue;
             }
-            // TODO - perform feature intersection and error if empty
-            intersection.append(&mut vec![s.clone()]);
+            let features = s
+                .features
+                .iter()
+                .filter(|f| c.features.contains(f))
+                .cloned()
+                .collect();
+            intersection.push(Version::new(s.identifier.clone(), features));
         }
     }
     intersection.sort_by(|a, b| a.identifier.cmp(&b.identifier));
-    if intersection.is_empty() {
-        return None;
+
+    let picked = intersection
+        .into_iter()
+        .next()
+        .ok_or(Kind::NoCommonVersion)?;
+    if picked.features.is_empty() {
+        return Err(Kind::NoCommonFeatures(picked.identifier).into());
     }
-    Some(intersection[0].clone())
+    Ok(picked)
 }
 
 #[cfg(test)]
@@ -99,7 +165,9 @@ mod tests {
 
     use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
 
-    use crate::ics03_connection::version::{get_compatible_versions, pick_version, Version};
+    use crate::ics03_connection::version::{
+        get_compatible_versions, is_supported_version, pick_version, Version,
+    };
 
     fn good_versions() -> Vec<RawVersion> {
         vec![
@@ -137,11 +205,11 @@ mod tests {
                 Version::default(),
                 Version {
                     identifier: "3".to_string(),
-                    features: vec![],
+                    features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
                 },
                 Version {
                     identifier: "4".to_string(),
-                    features: vec![],
+                    features: vec!["ORDER_UNORDERED".to_string()],
                 },
             ]
             .into_iter()
@@ -149,27 +217,45 @@ mod tests {
             vec![
                 Version {
                     identifier: "2".to_string(),
-                    features: vec![],
+                    features: vec!["ORDER_UNORDERED".to_string()],
                 },
                 Version {
                     identifier: "4".to_string(),
-                    features: vec![],
+                    features: vec!["ORDER_UNORDERED".to_string()],
                 },
                 Version {
                     identifier: "3".to_string(),
-                    features: vec![],
+                    features: vec!["ORDER_UNORDERED".to_string(), "ORDER_RANDOM".to_string()],
                 },
             ]
             .into_iter()
             .collect(),
-            // Should pick version 3 as it's the lowest of the intersection {3, 4}
+            // Should pick version 3 as it's the lowest of the intersection {3, 4}, restricted
+            // to the features supported by both sides.
             Version {
                 identifier: "3".to_string(),
-                features: vec![],
+                features: vec!["ORDER_UNORDERED".to_string()],
             },
         )
     }
 
+    fn no_common_features() -> (Vec<Version>, Vec<Version>) {
+        (
+            vec![Version {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_ORDERED".to_string()],
+            }]
+            .into_iter()
+            .collect(),
+            vec![Version {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_UNORDERED".to_string()],
+            }]
+            .into_iter()
+            .collect(),
+        )
+    }
+
     fn disjoint() -> (Vec<Version>, Vec<Version>) {
         (
             vec![Version {
@@ -269,6 +355,13 @@ mod tests {
                 picked: None,
                 want_pass: false,
             },
+            Test {
+                name: "No common features".to_string(),
+                supported: no_common_features().0,
+                counterparty: no_common_features().1,
+                picked: None,
+                want_pass: false,
+            },
         ];
 
         for test in tests {
@@ -276,16 +369,34 @@ mod tests {
 
             assert_eq!(
                 test.want_pass,
-                version.is_some(),
+                version.is_ok(),
                 "Validate versions failed for test {}",
                 test.name,
             );
 
             if test.want_pass {
-                assert_eq!(version, test.picked);
+                assert_eq!(version.ok(), test.picked);
             }
         }
     }
+    #[test]
+    fn supported() {
+        let supported = get_compatible_versions();
+
+        let subset = Version::new("1".to_string(), vec!["ORDER_UNORDERED".to_string()]);
+        assert!(is_supported_version(&supported, &subset));
+        assert!(is_supported_version(&supported, &Version::default()));
+
+        let no_features = Version::new("1".to_string(), vec![]);
+        assert!(!is_supported_version(&supported, &no_features));
+
+        let unknown_feature = Version::new("1".to_string(), vec!["ORDER_RANDOM".to_string()]);
+        assert!(!is_supported_version(&supported, &unknown_feature));
+
+        let unknown_identifier = Version::new("2".to_string(), vec!["ORDER_ORDERED".to_string()]);
+        assert!(!is_supported_version(&supported, &unknown_identifier));
+    }
+
     #[test]
     fn serialize() {
         let def = Version::default();
@@ -444,6 +444,20 @@ pub struct QueryPacketEventDataRequest {
     pub height: Height,
 }
 
+/// Used to query all the packet events, identified by `event_id`, for a specific channel and
+/// emitted in the range of heights (`from_height`, `to_height`], i.e., above `from_height` and up
+/// to `to_height`.
+#[derive(Clone, Debug)]
+pub struct QueryPacketEventRangeRequest {
+    pub event_id: IbcEventType,
+    pub source_channel_id: ChannelId,
+    pub source_port_id: PortId,
+    pub destination_channel_id: ChannelId,
+    pub destination_port_id: PortId,
+    pub from_height: Height,
+    pub to_height: Height,
+}
+
 /// Version validation, specific for channel (ICS4) opening handshake protocol.
 /// This field is supposed to be opaque to the core IBC protocol. No explicit validation necessary,
 /// and empty version is currently allowed by the specification (cf. ICS 004, v1).
@@ -6,9 +6,11 @@ use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_state::AnyClientState;
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
-use crate::ics04_channel::{error::Error, packet::Receipt};
-use crate::ics05_port::capabilities::Capability;
+use crate::ics04_channel::packet::Receipt;
+use crate::ics05_port::capabilities::{Capability, CapabilityName};
+use crate::ics05_port::context::{CapabilityKeeper, CapabilityReader};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::timestamp::Timestamp;
 use crate::Height;
@@ -16,7 +18,7 @@ use crate::Height;
 use super::packet::{PacketResult, Sequence};
 
 /// A context supplying all the necessary read-only dependencies for processing any `ChannelMsg`.
-pub trait ChannelReader {
+pub trait ChannelReader: CapabilityReader {
     /// Returns the ChannelEnd for the given `port_id` and `chan_id`.
     fn channel_end(&self, port_channel_id: &(PortId, ChannelId)) -> Option<ChannelEnd>;
 
@@ -35,7 +37,34 @@ pub trait ChannelReader {
         height: Height,
     ) -> Option<AnyConsensusState>;
 
-    fn authenticated_capability(&self, port_id: &PortId) -> Result<Capability, Error>;
+    /// Checks that `capability` is the capability of the module bound to port `port_id`.
+    fn authenticate_port_capability(
+        &self,
+        port_id: &PortId,
+        capability: &Capability,
+    ) -> Result<(), Error> {
+        if !self.authenticate_capability(&CapabilityName::port(port_id), capability) {
+            return Err(Kind::InvalidPortCapability.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `capability` is the capability of the channel end (`port_id`, `channel_id`),
+    /// i.e., that the module presenting it owns the channel.
+    fn authenticate_channel_capability(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        capability: &Capability,
+    ) -> Result<(), Error> {
+        if !self.authenticate_capability(&CapabilityName::channel(port_id, channel_id), capability)
+        {
+            return Err(Kind::InvalidChannelCapability(port_id.clone(), channel_id.clone()).into());
+        }
+
+        Ok(())
+    }
 
     fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
 
@@ -43,14 +72,35 @@ pub trait ChannelReader {
 
     fn get_next_sequence_ack(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
 
-    fn get_packet_commitment(&self, key: &(PortId, ChannelId, Sequence)) -> Option<String>;
+    fn get_packet_commitment(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Vec<u8>>;
 
     fn get_packet_receipt(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Receipt>;
 
-    fn get_packet_acknowledgement(&self, key: &(PortId, ChannelId, Sequence)) -> Option<String>;
+    fn get_packet_acknowledgement(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Vec<u8>>;
+
+    /// A hashing function for packet commitments, i.e., SHA-256 as specified by ICS 4.
+    fn hash(&self, value: Vec<u8>) -> Vec<u8>;
+
+    /// Computes the commitment to a packet, i.e., the hash of its timeout timestamp, the revision
+    /// number and height of its timeout height (all big-endian encoded), and the hash of its data.
+    fn packet_commitment(
+        &self,
+        data: Vec<u8>,
+        timeout_height: Height,
+        timeout_timestamp: Timestamp,
+    ) -> Vec<u8> {
+        let mut input = timeout_timestamp.as_nanoseconds().to_be_bytes().to_vec();
+        input.extend_from_slice(&timeout_height.revision_number.to_be_bytes());
+        input.extend_from_slice(&timeout_height.revision_height.to_be_bytes());
+        input.extend(self.hash(data));
+
+        self.hash(input)
+    }
 
-    /// A hashing function for packet commitments
-    fn hash(&self, value: String) -> String;
+    /// Computes the commitment to a packet acknowledgement, i.e., the hash of `ack`.
+    fn ack_commitment(&self, ack: Vec<u8>) -> Vec<u8> {
+        self.hash(ack)
+    }
 
     /// Returns the current height of the local chain.
     fn host_height(&self) -> Height;
@@ -66,7 +116,7 @@ pub trait ChannelReader {
 
 /// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
 /// for processing any `ChannelMsg`.
-pub trait ChannelKeeper {
+pub trait ChannelKeeper: CapabilityKeeper {
     fn store_channel_result(&mut self, result: ChannelResult) -> Result<(), Error> {
         // The handler processed this channel & some modifications occurred, store the new end.
         self.store_channel(
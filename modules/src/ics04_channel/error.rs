@@ -67,6 +67,9 @@ pub enum Kind {
     #[error("no commong version")]
     NoCommonVersion,
 
+    #[error("no common features for version {0}")]
+    NoCommonFeatures(String),
+
     #[error("missing channel end")]
     MissingChannel,
 
@@ -79,6 +82,15 @@ pub enum Kind {
     #[error("the module associated with the port does not have the capability it needs")]
     InvalidPortCapability,
 
+    #[error("the channel end ({0}, {1}) has no capability associated")]
+    NoChannelCapability(PortId, ChannelId),
+
+    #[error("the caller does not own the capability of channel end ({0}, {1})")]
+    InvalidChannelCapability(PortId, ChannelId),
+
+    #[error("failed to create the capability of channel end ({0}, {1})")]
+    ChannelCapabilityCreationFailure(PortId, ChannelId),
+
     #[error("single version must be negociated on connection before opening channel")]
     InvalidVersionLengthConnection,
 
@@ -1,13 +1,14 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 
 use crate::handler::HandlerOutput;
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::Error;
 use crate::ics04_channel::msgs::ChannelMsg;
 use crate::ics04_channel::{msgs::PacketMsg, packet::PacketResult};
 use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 pub mod acknowledgement;
 pub mod chan_close_confirm;
@@ -39,38 +40,49 @@ pub struct ChannelResult {
     pub port_id: PortId,
     pub channel_id: ChannelId,
     pub channel_id_state: ChannelIdState,
-    pub channel_cap: Capability,
     pub channel_end: ChannelEnd,
+
+    /// The state of the client which verified the proofs of the message, as updated by the
+    /// verification (e.g., solo machine clients consume a sequence with each proof).
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 /// General entry point for processing any type of message related to the ICS4 channel open and
-/// channel close handshake protocols.
+/// channel close handshake protocols. The caller presents `capability`, i.e., the capability of
+/// the port for the opening messages `MsgChannelOpenInit` and `MsgChannelOpenTry`, and that of
+/// the channel for the others.
 pub fn channel_dispatch<Ctx>(
     ctx: &Ctx,
+    capability: &Capability,
     msg: ChannelMsg,
 ) -> Result<HandlerOutput<ChannelResult>, Error>
 where
     Ctx: ChannelReader,
 {
     match msg {
-        ChannelMsg::ChannelOpenInit(msg) => chan_open_init::process(ctx, msg),
-        ChannelMsg::ChannelOpenTry(msg) => chan_open_try::process(ctx, msg),
-        ChannelMsg::ChannelOpenAck(msg) => chan_open_ack::process(ctx, msg),
-        ChannelMsg::ChannelOpenConfirm(msg) => chan_open_confirm::process(ctx, msg),
-        ChannelMsg::ChannelCloseInit(msg) => chan_close_init::process(ctx, msg),
-        ChannelMsg::ChannelCloseConfirm(msg) => chan_close_confirm::process(ctx, msg),
+        ChannelMsg::ChannelOpenInit(msg) => chan_open_init::process(ctx, capability, msg),
+        ChannelMsg::ChannelOpenTry(msg) => chan_open_try::process(ctx, capability, msg),
+        ChannelMsg::ChannelOpenAck(msg) => chan_open_ack::process(ctx, capability, msg),
+        ChannelMsg::ChannelOpenConfirm(msg) => chan_open_confirm::process(ctx, capability, msg),
+        ChannelMsg::ChannelCloseInit(msg) => chan_close_init::process(ctx, capability, msg),
+        ChannelMsg::ChannelCloseConfirm(msg) => chan_close_confirm::process(ctx, capability, msg),
     }
 }
 
-/// Dispatcher for processing any type of message related to the ICS4 packet protocols.
-pub fn packet_dispatch<Ctx>(ctx: &Ctx, msg: PacketMsg) -> Result<HandlerOutput<PacketResult>, Error>
+/// Dispatcher for processing any type of message related to the ICS4 packet protocols. The caller
+/// presents `channel_cap`, the capability of the channel end on this chain.
+pub fn packet_dispatch<Ctx>(
+    ctx: &Ctx,
+    channel_cap: &Capability,
+    msg: PacketMsg,
+) -> Result<HandlerOutput<PacketResult>, Error>
 where
     Ctx: ChannelReader,
 {
     match msg {
-        PacketMsg::RecvPacket(msg) => recv_packet::process(ctx, msg),
-        PacketMsg::AckPacket(msg) => acknowledgement::process(ctx, msg),
-        PacketMsg::ToPacket(msg) => timeout::process(ctx, msg),
-        PacketMsg::ToClosePacket(msg) => timeout_on_close::process(ctx, msg),
+        PacketMsg::RecvPacket(msg) => recv_packet::process(ctx, channel_cap, msg),
+        PacketMsg::AckPacket(msg) => acknowledgement::process(ctx, channel_cap, msg),
+        PacketMsg::ToPacket(msg) => timeout::process(ctx, channel_cap, msg),
+        PacketMsg::ToClosePacket(msg) => timeout_on_close::process(ctx, channel_cap, msg),
     }
 }
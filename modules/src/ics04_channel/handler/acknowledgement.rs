@@ -1,15 +1,19 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::height::Height;
 use crate::ics03_connection::connection::State as ConnectionState;
 use crate::ics04_channel::channel::State;
 use crate::ics04_channel::channel::{Counterparty, Order};
 use crate::ics04_channel::events::AcknowledgePacket;
-use crate::ics04_channel::handler::verify::verify_packet_acknowledgement_proofs;
+use crate::ics04_channel::handler::verify::{
+    verify_packet_acknowledgement_proofs, verifying_client_state,
+};
 use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
 use crate::ics04_channel::packet::{PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 #[derive(Clone, Debug)]
 pub struct AckPacketResult {
@@ -17,10 +21,14 @@ pub struct AckPacketResult {
     pub channel_id: ChannelId,
     pub seq: Sequence,
     pub seq_number: Option<Sequence>,
+    /// The state of the client which verified the proofs of the packet message, as updated by the
+    /// verification (e.g., solo machine clients consume a sequence with each proof).
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
 pub fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
     msg: MsgAcknowledgement,
 ) -> HandlerResult<PacketResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -38,7 +46,8 @@ pub fn process(
         return Err(Kind::ChannelClosed(packet.source_channel.clone()).into());
     }
 
-    let _channel_cap = ctx.authenticated_capability(&packet.source_port)?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(&packet.source_port, &packet.source_channel, channel_cap)?;
 
     let counterparty = Counterparty::new(
         packet.destination_port.clone(),
@@ -63,8 +72,6 @@ pub fn process(
         );
     }
 
-    let client_id = connection_end.client_id().clone();
-
     // Verify packet commitment
     let packet_commitment = ctx
         .get_packet_commitment(&(
@@ -74,23 +81,27 @@ pub fn process(
         ))
         .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data,
-    );
-
-    if packet_commitment != ctx.hash(input) {
+    if packet_commitment
+        != ctx.packet_commitment(
+            packet.data.clone(),
+            packet.timeout_height,
+            packet.timeout_timestamp,
+        )
+    {
         return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
     }
 
     // Verify the acknowledgement proof
+    let mut client_state = verifying_client_state(ctx, &connection_end)?;
     verify_packet_acknowledgement_proofs(
         ctx,
+        &mut client_state,
+        &connection_end,
         packet,
         msg.acknowledgement().clone(),
-        client_id,
         msg.proofs(),
     )?;
+    let client_state = Some((connection_end.client_id().clone(), client_state));
 
     let result = if source_channel_end.order_matches(&Order::Ordered) {
         let next_seq_ack = ctx
@@ -106,6 +117,7 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             seq_number: Some(next_seq_ack.increment()),
+            client_state,
         })
     } else {
         PacketResult::Ack(AckPacketResult {
@@ -113,6 +125,7 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             seq_number: None,
+            client_state,
         })
     };
 
@@ -140,6 +153,8 @@ mod tests {
     use crate::ics04_channel::handler::acknowledgement::process;
     use crate::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
     use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
@@ -166,13 +181,12 @@ mod tests {
         .unwrap();
         let packet = msg.packet.clone();
 
-        let input = format!(
-            "{:?},{:?},{:?}",
+        let data = ChannelReader::packet_commitment(
+            &context,
+            packet.data.clone(),
+            packet.timeout_height,
             packet.timeout_timestamp,
-            packet.timeout_height.clone(),
-            packet.data.clone()
         );
-        let data = ChannelReader::hash(&context, input);
 
         let source_channel_end = ChannelEnd::new(
             State::Open,
@@ -205,7 +219,7 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Processing fails because the port does not have a capability associated"
+                name: "Processing fails because the channel does not have a capability associated"
                     .to_string(),
                 ctx: context.clone().with_channel(
                     PortId::default(),
@@ -220,7 +234,10 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(
+                        packet.source_port.clone(),
+                        packet.source_channel.clone(),
+                    )
                     .with_channel(
                         packet.source_port.clone(),
                         packet.source_channel.clone(),
@@ -244,8 +261,17 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = process(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(
+                    &test.msg.packet.source_port,
+                    &test.msg.packet.source_channel,
+                )
+                .unwrap_or(&unissued_cap);
+            let res = process(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
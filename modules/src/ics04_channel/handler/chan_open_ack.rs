@@ -6,12 +6,14 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_channel_proofs;
+use crate::ics04_channel::handler::verify::{verify_channel_proofs, verifying_client_state};
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics05_port::capabilities::Capability;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
     msg: MsgChannelOpenAck,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -26,8 +28,8 @@ pub(crate) fn process(
         return Err(Kind::InvalidChannelState(msg.channel_id().clone(), channel_end.state).into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(msg.port_id(), msg.channel_id(), channel_cap)?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
 
@@ -66,8 +68,11 @@ pub(crate) fn process(
         msg.counterparty_version().clone(),
     );
     //2. Verify proofs
+    let mut client_state = verifying_client_state(ctx, &conn)
+        .map_err(|e| Kind::ChanOpenAckProofVerification.context(e))?;
     verify_channel_proofs(
         ctx,
+        &mut client_state,
         &channel_end,
         &conn,
         &expected_channel_end,
@@ -86,8 +91,8 @@ pub(crate) fn process(
         port_id: msg.port_id().clone(),
         channel_id: msg.channel_id().clone(),
         channel_id_state: ChannelIdState::Reused,
-        channel_cap,
         channel_end,
+        client_state: Some((conn.client_id().clone(), client_state)),
     };
 
     let event_attributes = Attributes {
@@ -121,6 +126,8 @@ mod tests {
     use crate::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
     use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
     use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::ConnectionId;
     use crate::mock::context::MockContext;
     use crate::Height;
@@ -205,6 +212,9 @@ mod tests {
             msg_chan_try.channel.version(),
         );
 
+        let port_id = msg_chan_ack.port_id().clone();
+        let channel_id = msg_chan_ack.channel_id().clone();
+
         let tests: Vec<Test> = vec![
             Test {
                 name: "Processing fails because no channel exists in the context".to_string(),
@@ -220,7 +230,10 @@ mod tests {
                         msg_conn_try.client_id(),
                         Height::new(0, client_consensus_state_height),
                     )
-                    .with_port_capability(msg_chan_ack.port_id().clone())
+                    .with_channel_capability(
+                        msg_chan_ack.port_id().clone(),
+                        msg_chan_ack.channel_id().clone(),
+                    )
                     .with_channel(
                         msg_chan_ack.port_id().clone(),
                         msg_chan_ack.channel_id().clone(),
@@ -230,7 +243,7 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Processing fails because port does not have a capability associate"
+                name: "Processing fails because the channel does not have a capability associated"
                     .to_string(),
                 ctx: context
                     .clone()
@@ -255,7 +268,10 @@ mod tests {
                         msg_conn_try.client_id(),
                         Height::new(0, client_consensus_state_height),
                     )
-                    .with_port_capability(msg_chan_ack.port_id().clone())
+                    .with_channel_capability(
+                        msg_chan_ack.port_id().clone(),
+                        msg_chan_ack.channel_id().clone(),
+                    )
                     .with_channel(
                         msg_chan_ack.port_id().clone(),
                         msg_chan_ack.channel_id().clone(),
@@ -269,7 +285,10 @@ mod tests {
                 ctx: context
                     .clone()
                     .with_connection(cid.clone(), conn_end.clone())
-                    .with_port_capability(msg_chan_ack.port_id().clone())
+                    .with_channel_capability(
+                        msg_chan_ack.port_id().clone(),
+                        msg_chan_ack.channel_id().clone(),
+                    )
                     .with_channel(
                         msg_chan_ack.port_id().clone(),
                         msg_chan_ack.channel_id().clone(),
@@ -286,7 +305,10 @@ mod tests {
                         Height::new(0, client_consensus_state_height),
                     )
                     .with_connection(cid, conn_end)
-                    .with_port_capability(msg_chan_ack.port_id().clone())
+                    .with_channel_capability(
+                        msg_chan_ack.port_id().clone(),
+                        msg_chan_ack.channel_id().clone(),
+                    )
                     .with_channel(
                         msg_chan_ack.port_id().clone(),
                         msg_chan_ack.channel_id().clone(),
@@ -299,8 +321,14 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = channel_dispatch(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(&port_id, &channel_id)
+                .unwrap_or(&unissued_cap);
+            let res = channel_dispatch(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -6,12 +6,14 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_channel_proofs;
+use crate::ics04_channel::handler::verify::{verify_channel_proofs, verifying_client_state};
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::ics05_port::capabilities::Capability;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
     msg: MsgChannelOpenConfirm,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -26,8 +28,8 @@ pub(crate) fn process(
         return Err(Kind::InvalidChannelState(msg.channel_id().clone(), channel_end.state).into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(msg.port_id(), msg.channel_id(), channel_cap)?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
     if channel_end.connection_hops().len() != 1 {
@@ -65,8 +67,11 @@ pub(crate) fn process(
         channel_end.version(),
     );
     //2. Verify proofs
+    let mut client_state = verifying_client_state(ctx, &conn)
+        .map_err(|e| Kind::ChanOpenConfirmProofVerification.context(e))?;
     verify_channel_proofs(
         ctx,
+        &mut client_state,
         &channel_end,
         &conn,
         &expected_channel_end,
@@ -83,8 +88,8 @@ pub(crate) fn process(
         port_id: msg.port_id().clone(),
         channel_id: msg.channel_id().clone(),
         channel_id_state: ChannelIdState::Reused,
-        channel_cap,
         channel_end,
+        client_state: Some((conn.client_id().clone(), client_state)),
     };
 
     let event_attributes = Attributes {
@@ -114,6 +119,8 @@ mod tests {
     use crate::ics04_channel::msgs::chan_open_confirm::test_util::get_dummy_raw_msg_chan_open_confirm;
     use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
     use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ClientId, ConnectionId};
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
@@ -158,12 +165,18 @@ mod tests {
             "".to_string(),
         );
 
+        let port_id = msg_chan_confirm.port_id().clone();
+        let channel_id = msg_chan_confirm.channel_id().clone();
+
         let tests: Vec<Test> = vec![Test {
             name: "Good parameters".to_string(),
             ctx: context
                 .with_client(&client_id, Height::new(0, client_consensus_state_height))
                 .with_connection(conn_id, conn_end)
-                .with_port_capability(msg_chan_confirm.port_id().clone())
+                .with_channel_capability(
+                    msg_chan_confirm.port_id().clone(),
+                    msg_chan_confirm.channel_id().clone(),
+                )
                 .with_channel(
                     msg_chan_confirm.port_id().clone(),
                     msg_chan_confirm.channel_id().clone(),
@@ -175,8 +188,14 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = channel_dispatch(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(&port_id, &channel_id)
+                .unwrap_or(&unissued_cap);
+            let res = channel_dispatch(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -8,16 +8,18 @@ use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics05_port::capabilities::Capability;
 use crate::ics24_host::identifier::ChannelId;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    port_cap: &Capability,
     msg: MsgChannelOpenInit,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // A module must be bound to the port, it will own the capability of the new channel.
+    ctx.authenticate_port_capability(msg.port_id(), port_cap)?;
 
     if msg.channel().connection_hops().len() != 1 {
         return Err(
@@ -71,7 +73,7 @@ pub(crate) fn process(
         channel_id: chan_id.clone(),
         channel_end: new_channel_end,
         channel_id_state: ChannelIdState::Generated,
-        channel_cap,
+        client_state: None,
     };
 
     let event_attributes = Attributes {
@@ -93,12 +95,14 @@ mod tests {
     use crate::ics03_connection::connection::State as ConnectionState;
     use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_raw_msg_conn_open_init;
     use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
-    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics03_connection::version::{get_compatible_versions, Version};
     use crate::ics04_channel::channel::State;
     use crate::ics04_channel::handler::{channel_dispatch, ChannelResult};
     use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init;
     use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
     use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::ConnectionId;
     use crate::mock::context::MockContext;
 
@@ -113,6 +117,7 @@ mod tests {
 
         let msg_chan_init =
             MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+        let port_id = msg_chan_init.port_id().clone();
 
         let context = MockContext::default();
 
@@ -127,6 +132,18 @@ mod tests {
             msg_conn_init.delay_period,
         );
 
+        // A connection which negotiated only the ordered channel feature.
+        let ordered_conn_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            msg_conn_init.client_id().clone(),
+            msg_conn_init.counterparty().clone(),
+            vec![Version::new(
+                "1".to_string(),
+                vec!["ORDER_ORDERED".to_string()],
+            )],
+            msg_conn_init.delay_period,
+        );
+
         let cid = ConnectionId::default();
 
         let tests: Vec<Test> = vec![
@@ -145,6 +162,16 @@ mod tests {
                 msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
                 want_pass: false,
             },
+            Test {
+                name: "Processing fails because the connection does not support unordered channels"
+                    .to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(cid.clone(), ordered_conn_end)
+                    .with_port_capability(msg_chan_init.port_id().clone()),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
             Test {
                 name: "Good parameters".to_string(),
                 ctx: context
@@ -162,8 +189,14 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = channel_dispatch(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the port, if the port is bound.
+            let port_cap = test
+                .ctx
+                .lookup_module_by_port(&port_id)
+                .unwrap_or(&unissued_cap);
+            let res = channel_dispatch(&test.ctx, port_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -7,13 +7,15 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_channel_proofs;
+use crate::ics04_channel::handler::verify::{verify_channel_proofs, verifying_client_state};
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics05_port::capabilities::Capability;
 use crate::ics24_host::identifier::ChannelId;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    port_cap: &Capability,
     msg: MsgChannelOpenTry,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -89,8 +91,8 @@ pub(crate) fn process(
         return Err(Kind::ChannelFeatureNotSuportedByConnection.into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // A module must be bound to the port, it will own the capability of the new channel.
+    ctx.authenticate_port_capability(msg.port_id(), port_cap)?;
 
     if msg.channel().version().is_empty() {
         return Err(Kind::InvalidVersion.into());
@@ -117,8 +119,11 @@ pub(crate) fn process(
     );
 
     // 2. Actual proofs are verified now.
+    let mut client_state = verifying_client_state(ctx, &conn)
+        .map_err(|e| Kind::FailedChanneOpenTryVerification.context(e))?;
     verify_channel_proofs(
         ctx,
+        &mut client_state,
         &new_channel_end,
         &conn,
         &expected_channel_end,
@@ -133,7 +138,6 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_cap,
         channel_id_state: if matches!(msg.previous_channel_id, None) {
             ChannelIdState::Generated
         } else {
@@ -141,6 +145,7 @@ pub(crate) fn process(
         },
         channel_id: channel_id.clone(),
         channel_end: new_channel_end,
+        client_state: Some((conn.client_id().clone(), client_state)),
     };
 
     let event_attributes = Attributes {
@@ -170,6 +175,8 @@ mod tests {
     use crate::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
     use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
     use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
@@ -277,7 +284,7 @@ mod tests {
                     .with_connection(conn_id.clone(), conn_end.clone()),
                 msg: ChannelMsg::ChannelOpenTry(msg_vanilla.clone()),
                 want_pass: false,
-                expect_error_kind: Some(Kind::NoPortCapability(msg.port_id.clone())),
+                expect_error_kind: Some(Kind::InvalidPortCapability),
             },
             Test {
                 name: "Processing fails because of inconsistent version with preexisting channel"
@@ -348,8 +355,14 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = channel_dispatch(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the port, if the port is bound.
+            let port_cap = test
+                .ctx
+                .lookup_module_by_port(&msg.port_id)
+                .unwrap_or(&unissued_cap);
+            let res = channel_dispatch(&test.ctx, port_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(handler_output) => {
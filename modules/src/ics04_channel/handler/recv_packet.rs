@@ -1,15 +1,17 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics02_client::height::Height;
 use crate::ics03_connection::connection::State as ConnectionState;
 use crate::ics04_channel::channel::{Counterparty, Order, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::ReceivePacket;
-use crate::ics04_channel::handler::verify::verify_packet_recv_proofs;
+use crate::ics04_channel::handler::verify::{verify_packet_recv_proofs, verifying_client_state};
 use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use crate::ics04_channel::packet::{PacketResult, Receipt, Sequence};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::Expiry;
 
 #[derive(Clone, Debug)]
@@ -19,9 +21,16 @@ pub struct RecvPacketResult {
     pub seq: Sequence,
     pub seq_number: Sequence,
     pub receipt: Option<Receipt>,
+    /// The state of the client which verified the proofs of the packet message, as updated by the
+    /// verification (e.g., solo machine clients consume a sequence with each proof).
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
-pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<PacketResult, Error> {
+pub fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
+    msg: MsgRecvPacket,
+) -> HandlerResult<PacketResult, Error> {
     let mut output = HandlerOutput::builder();
 
     let packet = &msg.packet;
@@ -46,7 +55,12 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
         .into());
     }
 
-    let _channel_cap = ctx.authenticated_capability(&packet.destination_port)?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(
+        &packet.destination_port,
+        &packet.destination_channel,
+        channel_cap,
+    )?;
 
     let counterparty = Counterparty::new(
         packet.source_port.clone(),
@@ -69,8 +83,6 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
         return Err(Kind::ConnectionNotOpen(dest_channel_end.connection_hops()[0].clone()).into());
     }
 
-    let client_id = connection_end.client_id().clone();
-
     // Check if packet height is newer than the height of the local host chain
     let latest_height = ctx.host_height();
     if (!packet.timeout_height.is_zero()) && (packet.timeout_height <= latest_height) {
@@ -83,7 +95,9 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
         return Err(Kind::LowPacketTimestamp.into());
     }
 
-    verify_packet_recv_proofs(ctx, packet, client_id, &msg.proofs)?;
+    let mut client_state = verifying_client_state(ctx, &connection_end)?;
+    verify_packet_recv_proofs(ctx, &mut client_state, &connection_end, packet, &msg.proofs)?;
+    let client_state = Some((connection_end.client_id().clone(), client_state));
 
     let result = if dest_channel_end.order_matches(&Order::Ordered) {
         let next_seq_recv = ctx
@@ -100,6 +114,7 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
             seq: packet.sequence,
             seq_number: next_seq_recv.increment(),
             receipt: None,
+            client_state,
         })
     } else {
         let packet_rec = ctx.get_packet_receipt(&(
@@ -118,6 +133,7 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgRecvPacket) -> HandlerResult<Pac
                     seq: packet.sequence,
                     seq_number: 1.into(),
                     receipt: Some(Receipt::Ok),
+                    client_state,
                 })
             }
         }
@@ -138,17 +154,26 @@ mod tests {
     use std::convert::TryFrom;
     use test_env_log::test;
 
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics02_client::header::AnyHeader;
     use crate::ics03_connection::connection::ConnectionEnd;
     use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
     use crate::ics03_connection::connection::State as ConnectionState;
     use crate::ics03_connection::version::get_compatible_versions;
     use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::context::ChannelKeeper;
     use crate::ics04_channel::handler::recv_packet::process;
     use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
     use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics18_relayer::context::Ics18Context;
+    use crate::ics23_commitment::commitment::CommitmentPrefix;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::ics24_host::Path;
+    use crate::mock::client_state::MockConsensusState;
     use crate::mock::context::MockContext;
+    use crate::proofs::Proofs;
     use crate::test_utils::get_dummy_account_id;
     use crate::timestamp::Timestamp;
     use crate::timestamp::ZERO_DURATION;
@@ -220,7 +245,7 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Processing fails because the port does not have a capability associated"
+                name: "Processing fails because the channel does not have a capability associated"
                     .to_string(),
                 ctx: context.clone().with_channel(
                     PortId::default(),
@@ -236,7 +261,10 @@ mod tests {
                     .clone()
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end.clone())
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                    )
                     .with_channel(
                         packet.destination_port.clone(),
                         packet.destination_channel.clone(),
@@ -263,7 +291,7 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(PortId::default())
+                    .with_channel_capability(PortId::default(), ChannelId::default())
                     .with_channel(PortId::default(), ChannelId::default(), dest_channel_end)
                     .with_send_sequence(PortId::default(), ChannelId::default(), 1.into())
                     .with_height(host_height)
@@ -275,8 +303,17 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = process(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(
+                    &test.msg.packet.destination_port,
+                    &test.msg.packet.destination_channel,
+                )
+                .unwrap_or(&unissued_cap);
+            let res = process(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -307,4 +344,115 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn recv_packet_proof_verification() {
+        let context = MockContext::default();
+        let host_height = context.query_latest_height().increment();
+        let client_height = host_height.increment();
+
+        let packet =
+            MsgRecvPacket::try_from(get_dummy_raw_msg_recv_packet(client_height.revision_height))
+                .unwrap()
+                .packet;
+
+        // The source chain commits to the packet in its store.
+        let mut source = MockContext::default();
+        source
+            .store_packet_commitment(
+                (
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                ),
+                packet.timeout_timestamp,
+                packet.timeout_height,
+                packet.data.clone(),
+            )
+            .unwrap();
+        let source = source.with_merkle_store();
+
+        let proof_height = source.query_latest_height();
+        let source_header = match source.query_latest_header() {
+            Some(AnyHeader::Mock(header)) => header,
+            _ => panic!("the source chain has no mock header"),
+        };
+        let proof = source
+            .query_proof(
+                &Path::Commitments {
+                    port_id: packet.source_port.clone(),
+                    channel_id: packet.source_channel.clone(),
+                    sequence: packet.sequence,
+                },
+                proof_height,
+            )
+            .unwrap();
+
+        let dest_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                CommitmentPrefix::from(b"ibc".to_vec()),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        );
+
+        // The destination chain has a client of the source chain, which trusts the header of the
+        // source chain at the proof height.
+        let mut dest = context
+            .with_client(&ClientId::default(), client_height)
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_channel_capability(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            )
+            .with_channel(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                dest_channel_end,
+            )
+            .with_height(host_height)
+            .with_timestamp(Timestamp::from_nanoseconds(1).unwrap())
+            .with_recv_sequence(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                packet.sequence,
+            );
+        dest.store_consensus_state(
+            ClientId::default(),
+            proof_height,
+            MockConsensusState::new(source_header).into(),
+        )
+        .unwrap();
+
+        let channel_cap = dest
+            .lookup_module_by_channel(&packet.destination_port, &packet.destination_channel)
+            .unwrap();
+
+        let proofs = Proofs::new(proof, None, None, None, proof_height).unwrap();
+        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), get_dummy_account_id());
+        assert!(process(&dest, channel_cap, msg).is_ok());
+
+        // The proof does not verify a packet whose data differs from the committed one.
+        let forged_packet = Packet {
+            data: vec![1, 2, 3],
+            ..packet
+        };
+        let msg = MsgRecvPacket::new(forged_packet, proofs, get_dummy_account_id());
+        assert!(process(&dest, channel_cap, msg).is_err());
+    }
 }
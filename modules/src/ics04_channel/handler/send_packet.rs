@@ -6,6 +6,7 @@ use crate::ics04_channel::channel::State;
 use crate::ics04_channel::events::SendPacket;
 use crate::ics04_channel::packet::{PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind, packet::Packet};
+use crate::ics05_port::capabilities::Capability;
 use crate::ics24_host::identifier::{ChannelId, PortId};
 use crate::timestamp::{Expiry, Timestamp};
 use crate::Height;
@@ -21,7 +22,13 @@ pub struct SendPacketResult {
     pub data: Vec<u8>,
 }
 
-pub fn send_packet(ctx: &dyn ChannelReader, packet: Packet) -> HandlerResult<PacketResult, Error> {
+/// Called by the module owning the source channel of `packet`, which must present the capability
+/// `channel_cap` of that channel.
+pub fn send_packet(
+    ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
+    packet: Packet,
+) -> HandlerResult<PacketResult, Error> {
     let mut output = HandlerOutput::builder();
 
     let source_channel_end = ctx
@@ -35,7 +42,7 @@ pub fn send_packet(ctx: &dyn ChannelReader, packet: Packet) -> HandlerResult<Pac
         return Err(Kind::ChannelClosed(packet.source_channel).into());
     }
 
-    let _channel_cap = ctx.authenticated_capability(&packet.source_port)?;
+    ctx.authenticate_channel_capability(&packet.source_port, &packet.source_channel, channel_cap)?;
 
     let counterparty = Counterparty::new(
         packet.destination_port.clone(),
@@ -126,9 +133,12 @@ mod tests {
     use crate::ics03_connection::connection::State as ConnectionState;
     use crate::ics03_connection::version::get_compatible_versions;
     use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::error::Kind;
     use crate::ics04_channel::handler::send_packet::send_packet;
     use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
     use crate::ics04_channel::packet::Packet;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
@@ -182,7 +192,7 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Processing fails because the port does not have a capability associated"
+                name: "Processing fails because the channel does not have a capability associated"
                     .to_string(),
                 ctx: context.clone().with_channel(
                     PortId::default(),
@@ -198,7 +208,7 @@ mod tests {
                     .clone()
                     .with_client(&ClientId::default(), Height::default())
                     .with_connection(ConnectionId::default(), connection_end.clone())
-                    .with_port_capability(PortId::default())
+                    .with_channel_capability(PortId::default(), ChannelId::default())
                     .with_channel(PortId::default(), ChannelId::default(), channel_end.clone())
                     .with_send_sequence(PortId::default(), ChannelId::default(), 1.into()),
                 packet,
@@ -209,7 +219,7 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(PortId::default())
+                    .with_channel_capability(PortId::default(), ChannelId::default())
                     .with_channel(PortId::default(), ChannelId::default(), channel_end)
                     .with_send_sequence(PortId::default(), ChannelId::default(), 1.into()),
                 packet: packet_old,
@@ -219,8 +229,16 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = send_packet(&test.ctx, test.packet.clone());
+            // The module owning the channel presents its capability. Where there is none, it
+            // presents a capability the host never registered for this channel.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(&test.packet.source_port, &test.packet.source_channel)
+                .unwrap_or(&unissued_cap);
+
+            let res = send_packet(&test.ctx, channel_cap, test.packet.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -252,4 +270,53 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn send_packet_on_foreign_channel() {
+        let mut packet: Packet = get_dummy_raw_packet(1, 6).try_into().unwrap();
+        packet.data = vec![0];
+
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(PortId::default(), Some(ChannelId::default())),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        );
+
+        let other_channel_id = ChannelId::new(1);
+        let ctx = MockContext::default()
+            .with_client(&ClientId::default(), Height::default())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_channel_capability(PortId::default(), ChannelId::default())
+            .with_channel(PortId::default(), ChannelId::default(), channel_end)
+            .with_send_sequence(PortId::default(), ChannelId::default(), 1.into())
+            .with_channel_capability(PortId::default(), other_channel_id.clone());
+
+        // A module owning another channel cannot send on this one.
+        let foreign_cap = ctx
+            .lookup_module_by_channel(&PortId::default(), &other_channel_id)
+            .unwrap();
+
+        let res = send_packet(&ctx, foreign_cap, packet);
+        match res {
+            Ok(_) => panic!("unexpected success (expected error)"),
+            Err(e) => assert_eq!(
+                e.kind(),
+                &Kind::InvalidChannelCapability(PortId::default(), ChannelId::default())
+            ),
+        }
+    }
 }
@@ -1,15 +1,17 @@
 use crate::events::IbcEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::channel::State;
 use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order};
 use crate::ics04_channel::events::TimeoutPacket;
 use crate::ics04_channel::handler::verify::{
-    verify_next_sequence_recv, verify_packet_receipt_absence,
+    verify_next_sequence_recv, verify_packet_receipt_absence, verifying_client_state,
 };
 use crate::ics04_channel::msgs::timeout::MsgTimeout;
 use crate::ics04_channel::packet::{PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::Expiry;
 
 #[derive(Clone, Debug)]
@@ -18,9 +20,16 @@ pub struct TimeoutPacketResult {
     pub channel_id: ChannelId,
     pub seq: Sequence,
     pub channel: Option<ChannelEnd>,
+    /// The state of the client which verified the proofs of the packet message, as updated by the
+    /// verification (e.g., solo machine clients consume a sequence with each proof).
+    pub client_state: Option<(ClientId, AnyClientState)>,
 }
 
-pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<PacketResult, Error> {
+pub fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
+    msg: MsgTimeout,
+) -> HandlerResult<PacketResult, Error> {
     let mut output = HandlerOutput::builder();
 
     let packet = &msg.packet;
@@ -36,7 +45,8 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
         return Err(Kind::ChannelClosed(packet.source_channel.clone()).into());
     }
 
-    let _channel_cap = ctx.authenticated_capability(&packet.source_port)?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(&packet.source_port, &packet.source_channel, channel_cap)?;
 
     let counterparty = Counterparty::new(
         packet.destination_port.clone(),
@@ -89,15 +99,17 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
         ))
         .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data,
-    );
-
-    if packet_commitment != ChannelReader::hash(ctx, input) {
+    if packet_commitment
+        != ctx.packet_commitment(
+            packet.data.clone(),
+            packet.timeout_height,
+            packet.timeout_timestamp,
+        )
+    {
         return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
     }
 
+    let mut client_state = verifying_client_state(ctx, &connection_end)?;
     let result = if source_channel_end.order_matches(&Order::Ordered) {
         if packet.sequence < msg.next_sequence_recv {
             return Err(
@@ -106,7 +118,8 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
         }
         verify_next_sequence_recv(
             ctx,
-            client_id,
+            &mut client_state,
+            &connection_end,
             packet.clone(),
             msg.next_sequence_recv,
             &msg.proofs.clone(),
@@ -118,15 +131,23 @@ pub fn process(ctx: &dyn ChannelReader, msg: MsgTimeout) -> HandlerResult<Packet
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: Some(source_channel_end),
+            client_state: Some((connection_end.client_id().clone(), client_state)),
         })
     } else {
-        verify_packet_receipt_absence(ctx, client_id, packet.clone(), &msg.proofs.clone())?;
+        verify_packet_receipt_absence(
+            ctx,
+            &mut client_state,
+            &connection_end,
+            packet.clone(),
+            &msg.proofs.clone(),
+        )?;
 
         PacketResult::Timeout(TimeoutPacketResult {
             port_id: packet.source_port.clone(),
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: None,
+            client_state: Some((connection_end.client_id().clone(), client_state)),
         })
     };
 
@@ -154,6 +175,8 @@ mod tests {
     use crate::ics04_channel::handler::timeout::process;
     use crate::ics04_channel::msgs::timeout::test_util::get_dummy_raw_msg_timeout;
     use crate::ics04_channel::msgs::timeout::MsgTimeout;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::timestamp::ZERO_DURATION;
 
@@ -185,13 +208,12 @@ mod tests {
         let mut msg_ok = msg.clone();
         msg_ok.packet.timeout_timestamp = Default::default();
 
-        let input = format!(
-            "{:?},{:?},{:?}",
+        let data = ChannelReader::packet_commitment(
+            &context,
+            msg_ok.packet.data.clone(),
+            msg_ok.packet.timeout_height,
             msg_ok.packet.timeout_timestamp,
-            msg_ok.packet.timeout_height.clone(),
-            msg_ok.packet.data.clone()
         );
-        let data = ChannelReader::hash(&context, input);
 
         let source_channel_end = ChannelEnd::new(
             State::Open,
@@ -234,7 +256,7 @@ mod tests {
                     ChannelId::default(),
                     source_channel_end.clone(),
                 )
-                .with_port_capability(packet.destination_port.clone())
+                .with_channel_capability(PortId::default(), ChannelId::default())
                 .with_connection(ConnectionId::default(), connection_end.clone()),
                 msg: msg.clone(),
                 want_pass: false,
@@ -248,7 +270,7 @@ mod tests {
                     source_channel_end.clone(),
                 )
                 .with_client(&ClientId::default(), client_height)
-                .with_port_capability(packet.destination_port.clone())
+                .with_channel_capability(PortId::default(), ChannelId::default())
                 .with_connection(ConnectionId::default(), connection_end.clone()),
                 msg,
                 want_pass: false,
@@ -258,7 +280,7 @@ mod tests {
                 ctx: context.clone()
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end.clone())
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(packet.source_port.clone(), packet.source_channel.clone())
                     .with_channel(
                         packet.source_port.clone(),
                         packet.source_channel.clone(),
@@ -278,7 +300,7 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(packet.source_port.clone(), packet.source_channel.clone())
                     .with_channel(
                         packet.source_port.clone(),
                         packet.source_channel,
@@ -302,8 +324,17 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = process(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(
+                    &test.msg.packet.source_port,
+                    &test.msg.packet.source_channel,
+                )
+                .unwrap_or(&unissued_cap);
+            let res = process(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
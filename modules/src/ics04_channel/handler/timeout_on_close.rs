@@ -3,18 +3,20 @@ use crate::handler::{HandlerOutput, HandlerResult};
 use crate::ics04_channel::channel::State;
 use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order};
 use crate::ics04_channel::events::TimeoutOnClosePacket;
-use crate::ics04_channel::handler::verify::verify_channel_proofs;
 use crate::ics04_channel::handler::verify::{
-    verify_next_sequence_recv, verify_packet_receipt_absence,
+    verify_channel_proofs, verify_next_sequence_recv, verify_packet_receipt_absence,
+    verifying_client_state,
 };
 use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
 use crate::ics04_channel::packet::PacketResult;
 use crate::ics04_channel::{
     context::ChannelReader, error::Error, error::Kind, handler::timeout::TimeoutPacketResult,
 };
+use crate::ics05_port::capabilities::Capability;
 
 pub fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
     msg: MsgTimeoutOnClose,
 ) -> HandlerResult<PacketResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -28,7 +30,8 @@ pub fn process(
                 .context(packet.source_channel.to_string())
         })?;
 
-    let _channel_cap = ctx.authenticated_capability(&packet.source_port)?;
+    // The caller must own the channel, via the capability created when it was opened.
+    ctx.authenticate_channel_capability(&packet.source_port, &packet.source_channel, channel_cap)?;
 
     let counterparty = Counterparty::new(
         packet.destination_port.clone(),
@@ -47,8 +50,6 @@ pub fn process(
         .connection_end(&source_channel_end.connection_hops()[0])
         .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
 
-    let client_id = connection_end.client_id().clone();
-
     //verify the packet was sent, check the store
     let packet_commitment = ctx
         .get_packet_commitment(&(
@@ -58,12 +59,13 @@ pub fn process(
         ))
         .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data,
-    );
-
-    if packet_commitment != ChannelReader::hash(ctx, input) {
+    if packet_commitment
+        != ctx.packet_commitment(
+            packet.data.clone(),
+            packet.timeout_height,
+            packet.timeout_timestamp,
+        )
+    {
         return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
     }
 
@@ -87,8 +89,10 @@ pub fn process(
         source_channel_end.version(),
     );
 
+    let mut client_state = verifying_client_state(ctx, &connection_end)?;
     verify_channel_proofs(
         ctx,
+        &mut client_state,
         &source_channel_end,
         &connection_end,
         &expected_channel_end,
@@ -103,7 +107,8 @@ pub fn process(
         }
         verify_next_sequence_recv(
             ctx,
-            client_id,
+            &mut client_state,
+            &connection_end,
             packet.clone(),
             msg.next_sequence_recv,
             &msg.proofs.clone(),
@@ -114,15 +119,23 @@ pub fn process(
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: Some(source_channel_end),
+            client_state: Some((connection_end.client_id().clone(), client_state)),
         })
     } else {
-        verify_packet_receipt_absence(ctx, client_id, packet.clone(), &msg.proofs.clone())?;
+        verify_packet_receipt_absence(
+            ctx,
+            &mut client_state,
+            &connection_end,
+            packet.clone(),
+            &msg.proofs.clone(),
+        )?;
 
         PacketResult::Timeout(TimeoutPacketResult {
             port_id: packet.source_port.clone(),
             channel_id: packet.source_channel.clone(),
             seq: packet.sequence,
             channel: None,
+            client_state: Some((connection_end.client_id().clone(), client_state)),
         })
     };
 
@@ -150,6 +163,8 @@ mod tests {
     use crate::ics04_channel::handler::timeout_on_close::process;
     use crate::ics04_channel::msgs::timeout_on_close::test_util::get_dummy_raw_msg_timeout_on_close;
     use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::timestamp::ZERO_DURATION;
 
@@ -181,13 +196,12 @@ mod tests {
         .unwrap();
         let packet = msg.packet.clone();
 
-        let input = format!(
-            "{:?},{:?},{:?}",
+        let data = ChannelReader::packet_commitment(
+            &context,
+            msg.packet.data.clone(),
+            msg.packet.timeout_height,
             msg.packet.timeout_timestamp,
-            msg.packet.timeout_height.clone(),
-            msg.packet.data.clone()
         );
-        let data = ChannelReader::hash(&context, input);
 
         let source_channel_end = ChannelEnd::new(
             State::Open,
@@ -228,7 +242,7 @@ mod tests {
                         ChannelId::default(),
                         source_channel_end.clone(),
                     )
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(PortId::default(), ChannelId::default())
                     .with_connection(ConnectionId::default(), connection_end.clone()),
                 msg: msg.clone(),
                 want_pass: false,
@@ -238,7 +252,10 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(
+                        packet.source_port.clone(),
+                        packet.source_channel.clone(),
+                    )
                     .with_channel(
                         packet.source_port.clone(),
                         packet.source_channel,
@@ -257,8 +274,17 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = process(&test.ctx, test.msg.clone());
+            // The routing module presents the capability of the channel, if any.
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(
+                    &test.msg.packet.source_port,
+                    &test.msg.packet.source_channel,
+                )
+                .unwrap_or(&unissued_cap);
+            let res = process(&test.ctx, channel_cap, test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -1,24 +1,22 @@
-use crate::ics02_client::client_state::ClientState;
+use crate::ics02_client::client_consensus::ConsensusState;
+use crate::ics02_client::client_state::{AnyClientState, ClientState};
 use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::packet::{Packet, Sequence};
-use crate::ics24_host::identifier::ClientId;
 use crate::proofs::Proofs;
 
-/// Entry point for verifying all proofs bundled in any ICS4 message for channel protocols.
-pub fn verify_channel_proofs(
+/// Fetches the state of the client which verifies the proofs of the messages received over
+/// `connection_end`, checking that it is not frozen. Verification may update this state (e.g.,
+/// solo machine clients consume a sequence with each proof), so the same state must be passed to
+/// all the verification functions of a message, and stored once the message is processed.
+pub fn verifying_client_state(
     ctx: &dyn ChannelReader,
-    channel_end: &ChannelEnd,
     connection_end: &ConnectionEnd,
-    expected_chan: &ChannelEnd,
-    proofs: &Proofs,
-) -> Result<(), Error> {
-    // This is the client which will perform proof verification.
+) -> Result<AnyClientState, Error> {
     let client_id = connection_end.client_id().clone();
-
     let client_state = ctx
         .client_state(&client_id)
         .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
@@ -28,12 +26,24 @@ pub fn verify_channel_proofs(
         return Err(Kind::FrozenClient(client_id).into());
     }
 
-    if ctx
+    Ok(client_state)
+}
+
+/// Entry point for verifying all proofs bundled in any ICS4 message for channel protocols.
+pub fn verify_channel_proofs(
+    ctx: &dyn ChannelReader,
+    client_state: &mut AnyClientState,
+    channel_end: &ChannelEnd,
+    connection_end: &ConnectionEnd,
+    expected_chan: &ChannelEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    // This is the client which will perform proof verification.
+    let client_id = connection_end.client_id().clone();
+
+    let consensus_state = ctx
         .client_consensus_state(&client_id, proofs.height())
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
-    }
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
@@ -41,10 +51,12 @@ pub fn verify_channel_proofs(
     // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
     Ok(client_def
         .verify_channel_state(
-            &client_state,
+            ctx,
+            client_state,
             proofs.height(),
             connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             channel_end.counterparty().port_id(),
             channel_end.counterparty().channel_id().unwrap(),
             expected_chan,
@@ -55,40 +67,33 @@ pub fn verify_channel_proofs(
 /// Entry point for verifying all proofs bundled in a ICS4 packet recv. message.
 pub fn verify_packet_recv_proofs(
     ctx: &dyn ChannelReader,
+    client_state: &mut AnyClientState,
+    connection_end: &ConnectionEnd,
     packet: &Packet,
-    client_id: ClientId,
     proofs: &Proofs,
 ) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
-
-    if ctx
+    let client_id = connection_end.client_id().clone();
+    let consensus_state = ctx
         .client_consensus_state(&client_id, proofs.height())
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
-    }
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data
+    let commitment = ctx.packet_commitment(
+        packet.data.clone(),
+        packet.timeout_height,
+        packet.timeout_timestamp,
     );
-    let commitment = ctx.hash(input);
 
     // Verify the proof for the packet against the chain store.
     Ok(client_def
         .verify_packet_data(
-            &client_state,
+            ctx,
+            client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &packet.source_port,
             &packet.source_channel,
             &packet.sequence,
@@ -100,30 +105,30 @@ pub fn verify_packet_recv_proofs(
 /// Entry point for verifying all proofs bundled in an ICS4 packet ack message.
 pub fn verify_packet_acknowledgement_proofs(
     ctx: &dyn ChannelReader,
+    client_state: &mut AnyClientState,
+    connection_end: &ConnectionEnd,
     packet: &Packet,
     acknowledgement: Vec<u8>,
-    client_id: ClientId,
     proofs: &Proofs,
 ) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_id = connection_end.client_id().clone();
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
     Ok(client_def
         .verify_packet_acknowledgement(
-            &client_state,
+            ctx,
+            client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
-            &packet.source_port,
-            &packet.source_channel,
+            consensus_state.root(),
+            &packet.destination_port,
+            &packet.destination_channel,
             &packet.sequence,
             acknowledgement,
         )
@@ -133,28 +138,28 @@ pub fn verify_packet_acknowledgement_proofs(
 /// Entry point for verifying all timeout proofs.
 pub fn verify_next_sequence_recv(
     ctx: &dyn ChannelReader,
-    client_id: ClientId,
+    client_state: &mut AnyClientState,
+    connection_end: &ConnectionEnd,
     packet: Packet,
     seq: Sequence,
     proofs: &Proofs,
 ) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_id = connection_end.client_id().clone();
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
     Ok(client_def
         .verify_next_sequence_recv(
-            &client_state,
+            ctx,
+            client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &packet.destination_port,
             &packet.destination_channel,
             &seq,
@@ -164,27 +169,27 @@ pub fn verify_next_sequence_recv(
 
 pub fn verify_packet_receipt_absence(
     ctx: &dyn ChannelReader,
-    client_id: ClientId,
+    client_state: &mut AnyClientState,
+    connection_end: &ConnectionEnd,
     packet: Packet,
     proofs: &Proofs,
 ) -> Result<(), Error> {
-    let client_state = ctx
-        .client_state(&client_id)
-        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(client_id).into());
-    }
+    let client_id = connection_end.client_id().clone();
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proofs.height()))?;
 
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the packet against the chain store.
     Ok(client_def
         .verify_packet_receipt_absence(
-            &client_state,
+            ctx,
+            client_state,
             proofs.height(),
+            connection_end.counterparty().prefix(),
             proofs.object_proof(),
+            consensus_state.root(),
             &packet.destination_port,
             &packet.destination_channel,
             &packet.sequence,
@@ -2,6 +2,7 @@ use crate::ics04_channel::channel::State;
 use crate::ics04_channel::events::WriteAcknowledgement;
 use crate::ics04_channel::packet::{Packet, PacketResult, Sequence};
 use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
+use crate::ics05_port::capabilities::Capability;
 use crate::ics24_host::identifier::{ChannelId, PortId};
 use crate::{
     events::IbcEvent,
@@ -16,8 +17,11 @@ pub struct WriteAckPacketResult {
     pub ack: Vec<u8>,
 }
 
+/// Called by the module owning the destination channel of `packet`, which must present the
+/// capability `channel_cap` of that channel.
 pub fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
     packet: Packet,
     ack: Vec<u8>,
 ) -> HandlerResult<PacketResult, Error> {
@@ -41,7 +45,11 @@ pub fn process(
         );
     }
 
-    let _channel_cap = ctx.authenticated_capability(&packet.destination_port)?;
+    ctx.authenticate_channel_capability(
+        &packet.destination_port,
+        &packet.destination_channel,
+        channel_cap,
+    )?;
 
     // NOTE: IBC app modules might have written the acknowledgement synchronously on
     // the OnRecvPacket callback so we need to check if the acknowledgement is already
@@ -62,8 +70,8 @@ pub fn process(
     }
 
     let result = PacketResult::WriteAck(WriteAckPacketResult {
-        port_id: packet.source_port.clone(),
-        channel_id: packet.source_channel.clone(),
+        port_id: packet.destination_port.clone(),
+        channel_id: packet.destination_channel.clone(),
         seq: packet.sequence,
         ack: ack.clone(),
     });
@@ -92,6 +100,8 @@ mod tests {
     use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
     use crate::ics04_channel::handler::write_acknowledgement::process;
     use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::ics05_port::capabilities::test_util::get_dummy_capability;
+    use crate::ics05_port::context::PortReader;
     use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
     use crate::mock::context::MockContext;
     use crate::timestamp::ZERO_DURATION;
@@ -150,7 +160,7 @@ mod tests {
                 want_pass: false,
             },
             Test {
-                name: "Processing fails because the port does not have a capability associated"
+                name: "Processing fails because the channel does not have a capability associated"
                     .to_string(),
                 ctx: context.clone().with_channel(
                     PortId::default(),
@@ -167,7 +177,10 @@ mod tests {
                     .clone()
                     .with_client(&ClientId::default(), client_height)
                     .with_connection(ConnectionId::default(), connection_end.clone())
-                    .with_port_capability(packet.destination_port.clone())
+                    .with_channel_capability(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                    )
                     .with_channel(
                         packet.destination_port.clone(),
                         packet.destination_channel.clone(),
@@ -182,7 +195,7 @@ mod tests {
                 ctx: context
                     .with_client(&ClientId::default(), Height::default())
                     .with_connection(ConnectionId::default(), connection_end)
-                    .with_port_capability(PortId::default())
+                    .with_channel_capability(PortId::default(), ChannelId::default())
                     .with_channel(PortId::default(), ChannelId::default(), dest_channel_end),
                 packet,
                 ack: ack_null,
@@ -192,8 +205,17 @@ mod tests {
         .into_iter()
         .collect();
 
+        let unissued_cap = get_dummy_capability();
         for test in tests {
-            let res = process(&test.ctx, test.packet.clone(), test.ack);
+            let channel_cap = test
+                .ctx
+                .lookup_module_by_channel(
+                    &test.packet.destination_port,
+                    &test.packet.destination_channel,
+                )
+                .unwrap_or(&unissued_cap);
+
+            let res = process(&test.ctx, channel_cap, test.packet.clone(), test.ack);
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
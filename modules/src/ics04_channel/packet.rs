@@ -5,8 +5,9 @@ use serde_derive::{Deserialize, Serialize};
 
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
+use crate::ics02_client::client_state::AnyClientState;
 use crate::ics04_channel::error::Kind;
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 use crate::timestamp::{Expiry::Expired, Timestamp};
 use crate::Height;
 
@@ -23,6 +24,7 @@ pub enum PacketMsgType {
     TimeoutUnordered,
     TimeoutOrdered,
     TimeoutOnClose,
+    TimeoutOnCloseOrdered,
 }
 
 #[derive(Clone, Debug)]
@@ -34,6 +36,18 @@ pub enum PacketResult {
     Timeout(TimeoutPacketResult),
 }
 
+impl PacketResult {
+    /// The state of the client which verified the proofs of the packet message, if any.
+    pub fn client_state(&self) -> Option<&(ClientId, AnyClientState)> {
+        match self {
+            PacketResult::Recv(result) => result.client_state.as_ref(),
+            PacketResult::Ack(result) => result.client_state.as_ref(),
+            PacketResult::Timeout(result) => result.client_state.as_ref(),
+            PacketResult::Send(_) | PacketResult::WriteAck(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Receipt {
     Ok,
@@ -47,6 +61,9 @@ impl std::fmt::Display for PacketMsgType {
             PacketMsgType::TimeoutUnordered => write!(f, "(PacketMsgType::TimeoutUnordered)"),
             PacketMsgType::TimeoutOrdered => write!(f, "(PacketMsgType::TimeoutOrdered)"),
             PacketMsgType::TimeoutOnClose => write!(f, "(PacketMsgType::TimeoutOnClose)"),
+            PacketMsgType::TimeoutOnCloseOrdered => {
+                write!(f, "(PacketMsgType::TimeoutOnCloseOrdered)")
+            }
         }
     }
 }
@@ -106,7 +123,10 @@ pub struct Packet {
     pub source_channel: ChannelId,
     pub destination_port: PortId,
     pub destination_channel: ChannelId,
-    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    #[serde(
+        serialize_with = "crate::serializers::ser_hex_upper",
+        deserialize_with = "crate::serializers::deser_hex_upper"
+    )]
     pub data: Vec<u8>,
     pub timeout_height: Height,
     pub timeout_timestamp: Timestamp,
@@ -83,15 +83,28 @@ pub fn pick_version(
             if supported_version.identifier != counterparty_version.identifier {
                 continue;
             }
-            // TODO - perform feature intersection and error if empty
-            intersection.append(&mut vec![supported_version.clone()]);
+            let features = supported_version
+                .features
+                .iter()
+                .filter(|f| counterparty_version.features.contains(f))
+                .cloned()
+                .collect();
+            intersection.push(Version {
+                identifier: supported_version.identifier.clone(),
+                features,
+            });
         }
     }
     intersection.sort_by(|a, b| a.identifier.cmp(&b.identifier));
-    if intersection.is_empty() {
-        return Err(Kind::NoCommonVersion.into());
+
+    let picked = intersection
+        .into_iter()
+        .next()
+        .ok_or(Kind::NoCommonVersion)?;
+    if picked.features.is_empty() {
+        return Err(Kind::NoCommonFeatures(picked.identifier).into());
     }
-    Ok(intersection[0].to_string())
+    Ok(picked.to_string())
 }
 
 pub fn validate_versions(versions: Vec<String>) -> Result<Vec<String>, Error> {
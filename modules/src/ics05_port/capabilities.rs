@@ -1,18 +1,284 @@
-//! Capabilities: this is a placeholder.
+//! Object capabilities, as specified in ICS5. A capability is an opaque key created by the host
+//! and handed to the module which binds a port or opens a channel. Only the holder of the
+//! capability registered under a given name is allowed to use the port or channel behind it.
 
-#[derive(Clone, Debug, PartialEq)]
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::ics05_port::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// Source of the identifiers which tell the capability stores of a process apart.
+static NEXT_STORE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// An object capability. Capabilities are created by a `CapabilityStore` only, and can neither
+/// be constructed nor cloned elsewhere: the store hands each new capability to the module owning
+/// it, which keeps it in its `ScopedCapabilityKeeper`.
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Capability {
+    store: u64,
     index: u64,
 }
 
 impl Capability {
-    pub fn new() -> Capability {
-        Self { index: 0x0 }
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    fn duplicate(&self) -> Capability {
+        Self {
+            store: self.store,
+            index: self.index,
+        }
+    }
+}
+
+/// Clones a map of capabilities. Only meant for cloning whole stores and keepers, e.g., along
+/// with the context of the host.
+fn duplicate_all(
+    capabilities: &BTreeMap<CapabilityName, Capability>,
+) -> BTreeMap<CapabilityName, Capability> {
+    capabilities
+        .iter()
+        .map(|(name, capability)| (name.clone(), capability.duplicate()))
+        .collect()
+}
+
+/// The capability store of the host. It creates the capabilities and keeps its own copy of each
+/// of them, i.e., the one owned by the IBC handlers, against which the capabilities presented by
+/// the modules are authenticated.
+#[derive(Debug)]
+pub struct CapabilityStore {
+    id: u64,
+    next_index: u64,
+    capabilities: BTreeMap<CapabilityName, Capability>,
+}
+
+impl CapabilityStore {
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_STORE_ID.fetch_add(1, Ordering::Relaxed),
+            next_index: 0,
+            capabilities: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a fresh capability and registers it under `name`. Returns the copy of the caller,
+    /// which is to hand it over to the module owning it. Fails if `name` is taken.
+    pub fn new_capability(&mut self, name: CapabilityName) -> Result<Capability, Error> {
+        if self.capabilities.contains_key(&name) {
+            return Err(Kind::CapabilityTaken(name).into());
+        }
+
+        let capability = Capability {
+            store: self.id,
+            index: self.next_index,
+        };
+        self.next_index += 1;
+        self.capabilities.insert(name, capability.duplicate());
+
+        Ok(capability)
+    }
+
+    /// Returns the capability registered under `name`, if any.
+    pub fn get_capability(&self, name: &CapabilityName) -> Option<&Capability> {
+        self.capabilities.get(name)
+    }
+
+    /// Returns true if `capability` is the capability registered under `name`.
+    pub fn authenticate_capability(&self, name: &CapabilityName, capability: &Capability) -> bool {
+        self.get_capability(name) == Some(capability)
     }
 }
 
-impl Default for Capability {
+impl Default for CapabilityStore {
     fn default() -> Self {
         Self::new()
     }
 }
+
+impl Clone for CapabilityStore {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            next_index: self.next_index,
+            capabilities: duplicate_all(&self.capabilities),
+        }
+    }
+}
+
+/// The capabilities owned by a module, under the names it claimed them with. Each module has its
+/// own keeper, and uses the capabilities in there to prove that it owns a port or channel.
+#[derive(Debug, Default)]
+pub struct ScopedCapabilityKeeper {
+    capabilities: BTreeMap<CapabilityName, Capability>,
+}
+
+impl ScopedCapabilityKeeper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes ownership of `capability`, which was handed to the module, under `name`. Claiming
+    /// a capability under the name it is already claimed with is a no-op. Fails if `name` is
+    /// taken by a different capability.
+    pub fn claim_capability(
+        &mut self,
+        name: CapabilityName,
+        capability: &Capability,
+    ) -> Result<(), Error> {
+        match self.capabilities.get(&name) {
+            Some(existing) if existing != capability => Err(Kind::CapabilityTaken(name).into()),
+            Some(_) => Ok(()),
+            None => {
+                self.capabilities.insert(name, capability.duplicate());
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the capability claimed under `name`, if any.
+    pub fn get_capability(&self, name: &CapabilityName) -> Option<&Capability> {
+        self.capabilities.get(name)
+    }
+
+    /// Returns true if `capability` is the capability claimed under `name`.
+    pub fn authenticate_capability(&self, name: &CapabilityName, capability: &Capability) -> bool {
+        self.get_capability(name) == Some(capability)
+    }
+}
+
+impl Clone for ScopedCapabilityKeeper {
+    fn clone(&self) -> Self {
+        Self {
+            capabilities: duplicate_all(&self.capabilities),
+        }
+    }
+}
+
+/// The name under which a capability is registered in the capability store.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CapabilityName(String);
+
+impl CapabilityName {
+    pub fn new(name: String) -> Self {
+        Self(name)
+    }
+
+    /// The name of the capability for binding port `port_id`, i.e., `ports/{port_id}`.
+    pub fn port(port_id: &PortId) -> Self {
+        Self(format!("ports/{}", port_id))
+    }
+
+    /// The name of the capability for channel `channel_id` on port `port_id`, i.e.,
+    /// `capabilities/ports/{port_id}/channels/{channel_id}`.
+    pub fn channel(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self(format!(
+            "capabilities/ports/{}/channels/{}",
+            port_id, channel_id
+        ))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CapabilityName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use super::{Capability, CapabilityName, CapabilityStore};
+
+    /// Returns a capability created by a throwaway store, hence one which no host authenticates.
+    pub fn get_dummy_capability() -> Capability {
+        CapabilityStore::new()
+            .new_capability(CapabilityName::new("dummy".to_string()))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use super::{CapabilityName, CapabilityStore, ScopedCapabilityKeeper};
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+
+    #[test]
+    fn capability_names() {
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+
+        assert_eq!(
+            CapabilityName::port(&port_id).as_str(),
+            format!("ports/{}", port_id)
+        );
+        assert_eq!(
+            CapabilityName::channel(&port_id, &channel_id).as_str(),
+            format!("capabilities/ports/{}/channels/{}", port_id, channel_id)
+        );
+    }
+
+    #[test]
+    fn capability_store() {
+        let mut store = CapabilityStore::new();
+        let port_name = CapabilityName::port(&PortId::default());
+        let channel_name = CapabilityName::channel(&PortId::default(), &ChannelId::default());
+
+        let port_cap = store.new_capability(port_name.clone()).unwrap();
+        let channel_cap = store.new_capability(channel_name.clone()).unwrap();
+        assert_ne!(port_cap, channel_cap);
+
+        // Names are unique.
+        assert!(store.new_capability(port_name.clone()).is_err());
+
+        assert_eq!(store.get_capability(&port_name), Some(&port_cap));
+        assert!(store.authenticate_capability(&channel_name, &channel_cap));
+        assert!(!store.authenticate_capability(&channel_name, &port_cap));
+
+        // Clones of the store authenticate the same capabilities.
+        assert!(store
+            .clone()
+            .authenticate_capability(&channel_name, &channel_cap));
+
+        // A capability created by another store is not accepted, even for the same name.
+        let mut other_store = CapabilityStore::new();
+        let forged = other_store.new_capability(port_name.clone()).unwrap();
+        assert_eq!(forged.index(), port_cap.index());
+        assert!(!store.authenticate_capability(&port_name, &forged));
+    }
+
+    #[test]
+    fn scoped_capability_keeper() {
+        let mut store = CapabilityStore::new();
+        let channel_name = CapabilityName::channel(&PortId::default(), &ChannelId::default());
+        let other_name = CapabilityName::channel(&PortId::default(), &ChannelId::new(1));
+        let channel_cap = store.new_capability(channel_name.clone()).unwrap();
+        let other_cap = store.new_capability(other_name.clone()).unwrap();
+
+        let mut keeper = ScopedCapabilityKeeper::new();
+        assert!(keeper.get_capability(&channel_name).is_none());
+
+        // A capability can be claimed under a fresh name, or again under the name it has.
+        keeper
+            .claim_capability(channel_name.clone(), &channel_cap)
+            .unwrap();
+        keeper
+            .claim_capability(channel_name.clone(), &channel_cap)
+            .unwrap();
+        assert!(keeper
+            .claim_capability(channel_name.clone(), &other_cap)
+            .is_err());
+
+        // The claimed capability is the one the store authenticates for its name.
+        let claimed = keeper.get_capability(&channel_name).unwrap();
+        assert!(store.authenticate_capability(&channel_name, claimed));
+        assert!(!store.authenticate_capability(&other_name, claimed));
+    }
+}
@@ -1,10 +1,44 @@
-use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::PortId;
+use crate::ics05_port::capabilities::{Capability, CapabilityName};
+use crate::ics05_port::error::Error;
+use crate::ics24_host::identifier::{ChannelId, PortId};
 
-// A context supplying all the necessary read-only dependencies for processing any information regarding a port.
-pub trait PortReader {
-    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability>;
-    fn authenticate(&self, key: &Capability, port_id: &PortId) -> bool;
+/// A context supplying the authentication of the capabilities presented to the host.
+pub trait CapabilityReader {
+    /// Returns true if `capability` is the capability registered under `name`.
+    fn authenticate_capability(&self, name: &CapabilityName, capability: &Capability) -> bool;
 }
 
-//  Result<Capability, Error>//return Ok(Capability::new());
+/// A context supplying the write access to the capability store.
+pub trait CapabilityKeeper {
+    /// Creates a fresh capability and registers it under `name`. The returned copy is to be
+    /// handed over to the module owning it. Fails if `name` is taken.
+    fn new_capability(&mut self, name: CapabilityName) -> Result<Capability, Error>;
+}
+
+/// A context supplying all the necessary read-only dependencies for processing any information
+/// regarding a port. It gives access to the copies of the capabilities owned by the IBC handlers,
+/// which the routing module presents when processing messages on behalf of a module. Modules
+/// instead use the capabilities they claimed in their own `ScopedCapabilityKeeper`.
+pub trait PortReader: CapabilityReader {
+    /// Returns the capability registered under `name`, if any.
+    fn lookup_capability(&self, name: &CapabilityName) -> Option<&Capability>;
+
+    /// Returns the capability of the module bound to `port_id`, if the port is bound.
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<&Capability> {
+        self.lookup_capability(&CapabilityName::port(port_id))
+    }
+
+    /// Returns the capability of the module owning the channel end (`port_id`, `channel_id`).
+    fn lookup_module_by_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Option<&Capability> {
+        self.lookup_capability(&CapabilityName::channel(port_id, channel_id))
+    }
+
+    /// Returns true if `key` is the capability of the module bound to `port_id`.
+    fn authenticate(&self, key: &Capability, port_id: &PortId) -> bool {
+        self.authenticate_capability(&CapabilityName::port(port_id), key)
+    }
+}
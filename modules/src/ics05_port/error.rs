@@ -1,12 +1,17 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics05_port::capabilities::CapabilityName;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error)]
 pub enum Kind {
     #[error("port unknown")]
     UnknownPort,
+
+    #[error("capability name {0} is already taken")]
+    CapabilityTaken(CapabilityName),
 }
 
 impl Kind {
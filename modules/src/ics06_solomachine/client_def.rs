@@ -0,0 +1,659 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    ChannelStateData, ClientStateData, ConnectionStateData, ConsensusStateData, DataType,
+    HeaderData, NextSequenceRecvData, PacketAcknowledgementData, PacketCommitmentData,
+    PacketReceiptAbsenceData, SignBytes, TimestampedSignatureData,
+};
+
+use crate::ics02_client::client_consensus::AnyConsensusState;
+use crate::ics02_client::client_def::ClientDef;
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_state::ClientState;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::header::Header;
+use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+use crate::ics06_solomachine::public_key::decode_signature;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// The solo machine client. Instead of Merkle proofs, a solo machine proves its state by signing
+/// the value stored under each path. Every signature is bound to the current sequence of the
+/// client, which advances with each header and each verified proof, so that no signature can be
+/// replayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoloMachineClient;
+
+impl ClientDef for SoloMachineClient {
+    type Header = Header;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+    type Misbehaviour = Misbehaviour;
+
+    fn check_header_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.frozen_sequence != 0 {
+            return Err(Error::from(Kind::ClientFrozen(client_state.frozen_sequence)).into());
+        }
+
+        if header.sequence != client_state.sequence {
+            return Err(Error::from(Kind::SequenceMismatch(
+                client_state.sequence,
+                header.sequence,
+            ))
+            .into());
+        }
+
+        let consensus_state = &client_state.consensus_state;
+        if header.timestamp < consensus_state.timestamp {
+            return Err(Error::from(Kind::InvalidTimestamp(
+                consensus_state.timestamp,
+                header.timestamp,
+            ))
+            .into());
+        }
+
+        // The header is signed by the current key, over the new key and diversifier.
+        let data = encode(&HeaderData {
+            new_pub_key: Some(header.new_public_key.clone().into()),
+            new_diversifier: header.new_diversifier.clone(),
+        });
+        verify_signature(
+            consensus_state,
+            header.sequence,
+            header.timestamp,
+            DataType::Header,
+            data,
+            &header.signature,
+        )?;
+
+        let new_consensus_state = ConsensusState::new(
+            header.new_public_key,
+            header.new_diversifier,
+            header.timestamp,
+        );
+
+        Ok((
+            ClientState {
+                sequence: header.sequence + 1,
+                consensus_state: new_consensus_state.clone(),
+                ..client_state
+            },
+            new_consensus_state,
+        ))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        if client_state.frozen_sequence != 0 {
+            return Err(Error::from(Kind::ClientFrozen(client_state.frozen_sequence)).into());
+        }
+
+        let (one, two) = (&misbehaviour.signature_one, &misbehaviour.signature_two);
+        if one.signature == two.signature || one.data == two.data {
+            return Err(Error::from(Kind::MisbehaviourSignaturesNotConflicting).into());
+        }
+
+        // Both signatures must have been produced by the current key for the same sequence.
+        let consensus_state = &client_state.consensus_state;
+        verify_signature_and_data(consensus_state, misbehaviour.sequence, one)?;
+        verify_signature_and_data(consensus_state, misbehaviour.sequence, two)?;
+
+        Ok(client_state.with_frozen_sequence(misbehaviour.sequence))
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let data = encode(&ConsensusStateData {
+            path: path_bytes(prefix, path)?,
+            consensus_state: Some(expected_consensus_state.clone().into()),
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::ConsensusState,
+            data,
+        )?)
+    }
+
+    fn verify_connection_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let connection_id = connection_id.ok_or_else(|| Error::from(Kind::MissingConnectionId))?;
+
+        let path = Path::Connections(connection_id.clone());
+        let data = encode(&ConnectionStateData {
+            path: path_bytes(prefix, path)?,
+            connection: Some(expected_connection_end.clone().into()),
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::ConnectionState,
+            data,
+        )?)
+    }
+
+    fn verify_channel_state(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let data = encode(&ChannelStateData {
+            path: path_bytes(prefix, path)?,
+            channel: Some(expected_channel_end.clone().into()),
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::ChannelState,
+            data,
+        )?)
+    }
+
+    fn verify_client_full_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let data = encode(&ClientStateData {
+            path: path_bytes(prefix, path)?,
+            client_state: Some(expected_client_state.clone().into()),
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::ClientState,
+            data,
+        )?)
+    }
+
+    fn verify_packet_data(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = encode(&PacketCommitmentData {
+            path: path_bytes(prefix, path)?,
+            commitment,
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::PacketCommitment,
+            data,
+        )?)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = encode(&PacketAcknowledgementData {
+            path: path_bytes(prefix, path)?,
+            acknowledgement: ack,
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::PacketAcknowledgement,
+            data,
+        )?)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let data = encode(&NextSequenceRecvData {
+            path: path_bytes(prefix, path)?,
+            next_seq_recv: u64::from(*seq),
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::NextSequenceRecv,
+            data,
+        )?)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = encode(&PacketReceiptAbsenceData {
+            path: path_bytes(prefix, path)?,
+        });
+
+        Ok(verify_proof(
+            client_state,
+            height,
+            proof,
+            DataType::PacketReceiptAbsence,
+            data,
+        )?)
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _proof_upgrade_client: RawMerkleProof,
+        _proof_upgrade_consensus_state: RawMerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Error::from(Kind::UpgradeNotSupported).into())
+    }
+}
+
+/// Verifies a proof of the solo machine, i.e., an encoded `TimestampedSignatureData`, over the
+/// given `data` at the current sequence of the client. The proof consumes that sequence.
+fn verify_proof(
+    client_state: &mut ClientState,
+    height: Height,
+    proof: &CommitmentProofBytes,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Result<(), Error> {
+    let sequence = client_state.verify_height(height)?;
+
+    let proof: Vec<u8> = proof.clone().into();
+    let timestamped: TimestampedSignatureData = prost::Message::decode(proof.as_ref())
+        .map_err(|e| Kind::InvalidCommitmentProof.context(e))?;
+
+    let consensus_state = &client_state.consensus_state;
+    if timestamped.timestamp < consensus_state.timestamp {
+        return Err(
+            Kind::InvalidTimestamp(consensus_state.timestamp, timestamped.timestamp).into(),
+        );
+    }
+
+    verify_signature(
+        consensus_state,
+        sequence,
+        timestamped.timestamp,
+        data_type,
+        data,
+        &timestamped.signature_data,
+    )?;
+
+    client_state.sequence = sequence + 1;
+
+    Ok(())
+}
+
+/// Verifies one of the two signatures of a misbehaviour.
+fn verify_signature_and_data(
+    consensus_state: &ConsensusState,
+    sequence: u64,
+    signature_and_data: &SignatureAndData,
+) -> Result<(), Error> {
+    if signature_and_data.timestamp < consensus_state.timestamp {
+        return Err(Kind::InvalidTimestamp(
+            consensus_state.timestamp,
+            signature_and_data.timestamp,
+        )
+        .into());
+    }
+
+    verify_signature(
+        consensus_state,
+        sequence,
+        signature_and_data.timestamp,
+        signature_and_data.data_type,
+        signature_and_data.data.clone(),
+        &signature_and_data.signature,
+    )
+}
+
+/// Verifies that the key of `consensus_state` signed `data`, bound to the given sequence and
+/// timestamp, as well as to the diversifier of the solo machine.
+fn verify_signature(
+    consensus_state: &ConsensusState,
+    sequence: u64,
+    timestamp: u64,
+    data_type: DataType,
+    data: Vec<u8>,
+    signature_data: &[u8],
+) -> Result<(), Error> {
+    let sign_bytes = encode(&SignBytes {
+        sequence,
+        timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        data_type: data_type as i32,
+        data,
+    });
+    let signature = decode_signature(signature_data)?;
+
+    consensus_state.public_key.verify(&sign_bytes, &signature)
+}
+
+/// Encodes a path as signed by solo machines, i.e., the string representation of the Merkle path
+/// made of the commitment prefix and the path, with each key escaped.
+fn path_bytes(prefix: &CommitmentPrefix, path: Path) -> Result<Vec<u8>, Error> {
+    let prefix = std::str::from_utf8(prefix.as_bytes())
+        .map_err(|e| Kind::InvalidCommitmentPrefix.context(e))?;
+
+    Ok(format!("/{}/{}", escape_key(prefix), escape_key(&path.to_string())).into_bytes())
+}
+
+/// Escapes the separators within a key of a Merkle path.
+fn escape_key(key: &str) -> String {
+    key.replace('/', "%2F")
+}
+
+fn encode<M: prost::Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::new();
+    // Safety note: encoding into a `Vec` cannot run out of capacity.
+    prost::Message::encode(message, &mut buf).unwrap();
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use k256::ecdsa::signature::Signer;
+    use k256::ecdsa::{Signature, SigningKey};
+    use test_env_log::test;
+
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{
+        Single as RawSingleSignature, Sum as RawSignatureDataSum,
+    };
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+    use ibc_proto::ibc::lightclients::solomachine::v1::{
+        DataType, HeaderData, PacketCommitmentData, SignBytes, TimestampedSignatureData,
+    };
+
+    use super::{encode, path_bytes, SoloMachineClient};
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::client_state::ClientState as _;
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::ics06_solomachine::header::Header;
+    use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+    use crate::ics06_solomachine::public_key::PublicKey;
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+    use crate::ics24_host::Path;
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    const DIVERSIFIER: &str = "solo";
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32]).unwrap()
+    }
+
+    fn public_key(key: &SigningKey) -> PublicKey {
+        PublicKey::from_sec1_bytes(&key.verifying_key().to_bytes()).unwrap()
+    }
+
+    /// Signs the sign bytes of `data`, returning an encoded `SignatureDescriptor.Data`.
+    fn sign(
+        key: &SigningKey,
+        sequence: u64,
+        timestamp: u64,
+        data_type: DataType,
+        data: Vec<u8>,
+    ) -> Vec<u8> {
+        let sign_bytes = encode(&SignBytes {
+            sequence,
+            timestamp,
+            diversifier: DIVERSIFIER.to_string(),
+            data_type: data_type as i32,
+            data,
+        });
+        let signature: Signature = key.sign(&sign_bytes);
+
+        encode(&RawSignatureData {
+            sum: Some(RawSignatureDataSum::Single(RawSingleSignature {
+                mode: 1,
+                signature: signature.as_ref().to_vec(),
+            })),
+        })
+    }
+
+    fn client_state(key: &SigningKey) -> ClientState {
+        ClientState::new(
+            1,
+            ConsensusState::new(public_key(key), DIVERSIFIER.to_string(), 10),
+            false,
+        )
+    }
+
+    #[test]
+    fn update_rotates_public_key() {
+        let ctx = MockContext::default();
+        let key = signing_key(1);
+        let new_key = signing_key(2);
+        let client_state = client_state(&key);
+
+        let data = encode(&HeaderData {
+            new_pub_key: Some(public_key(&new_key).into()),
+            new_diversifier: DIVERSIFIER.to_string(),
+        });
+        let header = Header {
+            sequence: 1,
+            timestamp: 20,
+            signature: sign(&key, 1, 20, DataType::Header, data),
+            new_public_key: public_key(&new_key),
+            new_diversifier: DIVERSIFIER.to_string(),
+        };
+
+        let (new_client_state, new_consensus_state) = SoloMachineClient
+            .check_header_and_update_state(&ctx, client_state.clone(), header.clone())
+            .unwrap();
+        assert_eq!(new_client_state.latest_height(), Height::new(0, 2));
+        assert_eq!(new_consensus_state.public_key, public_key(&new_key));
+
+        // A header signed by another key is rejected.
+        let forged = Header {
+            signature: sign(&new_key, 1, 20, DataType::Header, vec![]),
+            ..header
+        };
+        assert!(SoloMachineClient
+            .check_header_and_update_state(&ctx, client_state, forged)
+            .is_err());
+    }
+
+    #[test]
+    fn verify_packet_commitment_signature() {
+        let ctx = MockContext::default();
+        let key = signing_key(1);
+        let mut client_state = client_state(&key);
+
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let sequence = Sequence::from(1);
+        let commitment = "commitment".to_string();
+
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence,
+        };
+        let data = encode(&PacketCommitmentData {
+            path: path_bytes(&prefix, path).unwrap(),
+            commitment: commitment.clone().into_bytes(),
+        });
+        let proof = encode(&TimestampedSignatureData {
+            signature_data: sign(&key, 1, 15, DataType::PacketCommitment, data),
+            timestamp: 15,
+        });
+
+        let verify = |client_state: &mut ClientState, height, commitment: &str| {
+            SoloMachineClient.verify_packet_data(
+                &ctx,
+                client_state,
+                height,
+                &prefix,
+                &proof.clone().into(),
+                &CommitmentRoot::from(vec![]),
+                &port_id,
+                &channel_id,
+                &sequence,
+                commitment.as_bytes().to_vec(),
+            )
+        };
+
+        assert!(verify(&mut client_state, Height::new(0, 1), "other commitment").is_err());
+        // The proof cannot be given at a sequence the client has not reached.
+        assert!(verify(&mut client_state, Height::new(0, 2), &commitment).is_err());
+        assert_eq!(client_state.sequence, 1);
+
+        assert!(verify(&mut client_state, Height::new(0, 1), &commitment).is_ok());
+        // The proof consumed the sequence it is bound to, hence it cannot be replayed.
+        assert_eq!(client_state.sequence, 2);
+        assert!(verify(&mut client_state, Height::new(0, 1), &commitment).is_err());
+    }
+
+    #[test]
+    fn misbehaviour_freezes_client() {
+        let ctx = MockContext::default();
+        let key = signing_key(1);
+        let client_state = client_state(&key);
+
+        let signature_and_data = |data: Vec<u8>| SignatureAndData {
+            signature: sign(&key, 3, 15, DataType::PacketCommitment, data.clone()),
+            data_type: DataType::PacketCommitment,
+            data,
+            timestamp: 15,
+        };
+        let misbehaviour = Misbehaviour {
+            client_id: ClientId::default(),
+            sequence: 3,
+            signature_one: signature_and_data(b"one".to_vec()),
+            signature_two: signature_and_data(b"two".to_vec()),
+        };
+
+        let frozen = SoloMachineClient
+            .check_misbehaviour_and_update_state(
+                &ctx,
+                ClientId::default(),
+                client_state,
+                misbehaviour,
+            )
+            .unwrap();
+
+        assert!(frozen.is_frozen());
+        assert_eq!(frozen.frozen_sequence, 3);
+    }
+}
@@ -0,0 +1,149 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics24_host::identifier::ChainId;
+use crate::Height;
+
+/// The client state of a solo machine. Solo machines have no notion of height: every signature
+/// is bound to a sequence, which increases with each header. The sequence is exposed as the
+/// revision height of a height with revision number 0.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub sequence: u64,
+    /// Sequence at which misbehaviour was detected, 0 if the client is not frozen.
+    pub frozen_sequence: u64,
+    pub consensus_state: ConsensusState,
+    pub allow_update_after_proposal: bool,
+}
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        consensus_state: ConsensusState,
+        allow_update_after_proposal: bool,
+    ) -> Self {
+        Self {
+            sequence,
+            frozen_sequence: 0,
+            consensus_state,
+            allow_update_after_proposal,
+        }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    /// Returns a copy of this client state, frozen at sequence `sequence`.
+    pub fn with_frozen_sequence(self, sequence: u64) -> Self {
+        Self {
+            frozen_sequence: sequence,
+            ..self
+        }
+    }
+
+    /// Checks that proofs can be verified at `height` with this client, i.e., the client is not
+    /// frozen and `height` does not exceed its current sequence. Returns that sequence.
+    ///
+    /// Each proof consumes a sequence: the proofs of a message are all given at the sequence of
+    /// the client when the first of them was signed, the following ones being signed at the next
+    /// sequences.
+    pub fn verify_height(&self, height: Height) -> Result<u64, Error> {
+        if self.frozen_sequence != 0 {
+            return Err(Kind::ClientFrozen(self.frozen_sequence).into());
+        }
+
+        if height.revision_number != 0 {
+            return Err(Kind::InvalidRevisionNumber(height).into());
+        }
+
+        if height.revision_height > self.sequence {
+            return Err(Kind::SequenceMismatch(self.sequence, height.revision_height).into());
+        }
+
+        Ok(self.sequence)
+    }
+}
+
+impl crate::ics02_client::client_state::ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        // A solo machine is not a chain. Its diversifier is the closest analogue to an identifier.
+        // Safety note: parsing a chain identifier from a string is infallible.
+        self.consensus_state.diversifier.parse().unwrap()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen_sequence != 0
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            frozen_sequence: raw.frozen_sequence,
+            consensus_state: raw
+                .consensus_state
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing consensus state"))?
+                .try_into()?,
+            allow_update_after_proposal: raw.allow_update_after_proposal,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            frozen_sequence: value.frozen_sequence,
+            consensus_state: Some(value.consensus_state.into()),
+            allow_update_after_proposal: value.allow_update_after_proposal,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+pub mod test_util {
+    use k256::ecdsa::SigningKey;
+
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::ics06_solomachine::public_key::PublicKey;
+
+    /// Returns the state of a solo machine client at `sequence`, whose key is derived from a
+    /// fixed seed.
+    pub fn get_dummy_solomachine_client_state(sequence: u64) -> ClientState {
+        let key = SigningKey::from_bytes(&[1; 32]).unwrap();
+        let public_key = PublicKey::from_sec1_bytes(&key.verifying_key().to_bytes()).unwrap();
+
+        ClientState::new(
+            sequence,
+            ConsensusState::new(public_key, "solo".to_string(), 1),
+            false,
+        )
+    }
+}
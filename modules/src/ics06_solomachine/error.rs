@@ -0,0 +1,69 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw client consensus state")]
+    InvalidRawConsensusState,
+
+    #[error("invalid raw header")]
+    InvalidRawHeader,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid public key")]
+    InvalidPublicKey,
+
+    #[error("unsupported public key type {0}")]
+    UnsupportedPublicKeyType(String),
+
+    #[error("invalid signature data")]
+    InvalidSignatureData,
+
+    #[error("signature verification failed")]
+    SignatureVerificationFailure,
+
+    #[error("client is frozen at sequence {0}")]
+    ClientFrozen(u64),
+
+    #[error("invalid height {0}: the revision number of a solo machine height must be 0")]
+    InvalidRevisionNumber(Height),
+
+    #[error("sequence mismatch: client is at sequence {0}, got {1}")]
+    SequenceMismatch(u64, u64),
+
+    #[error("timestamp {1} is lower than the consensus state timestamp {0}")]
+    InvalidTimestamp(u64, u64),
+
+    #[error("invalid commitment proof bytes")]
+    InvalidCommitmentProof,
+
+    #[error("invalid commitment prefix")]
+    InvalidCommitmentPrefix,
+
+    #[error("failed to encode the expected value")]
+    InvalidExpectedValue,
+
+    #[error("missing counterparty connection identifier")]
+    MissingConnectionId,
+
+    #[error("the signatures of the misbehaviour are not conflicting")]
+    MisbehaviourSignaturesNotConflicting,
+
+    #[error("solo machine clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
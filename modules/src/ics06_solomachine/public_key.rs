@@ -0,0 +1,105 @@
+//! The public key of a solo machine, which signs over every state transition and proof.
+//! Only secp256k1 keys, encoded as in the Cosmos SDK, are supported at the moment.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use k256::ecdsa::signature::Verifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+use prost_types::Any;
+use serde::{Deserialize, Serialize};
+use subtle_encoding::{Encoding, Hex};
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::Sum as RawSignatureDataSum;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+
+use crate::ics06_solomachine::error::{Error, Kind};
+
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// Mirror of the Cosmos SDK `cosmos.crypto.secp256k1.PubKey` message.
+#[derive(Clone, PartialEq, prost::Message)]
+struct RawSecp256k1PublicKey {
+    #[prost(bytes, tag = "1")]
+    key: Vec<u8>,
+}
+
+/// A secp256k1 public key, kept in its compressed SEC1 encoding.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKey {
+    bytes: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Builds a public key from its SEC1 encoding, checking that it is a valid curve point.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        VerifyingKey::from_sec1_bytes(bytes).map_err(|e| Kind::InvalidPublicKey.context(e))?;
+
+        Ok(Self {
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Verifies an ECDSA `signature`, in its 64-byte `r || s` encoding, over the SHA256 digest
+    /// of `message`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        // Keys deserialized with serde bypass the validation of `from_sec1_bytes`.
+        let key = VerifyingKey::from_sec1_bytes(&self.bytes)
+            .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+        let signature =
+            Signature::try_from(signature).map_err(|e| Kind::InvalidSignatureData.context(e))?;
+
+        key.verify(message, &signature)
+            .map_err(|e| Kind::SignatureVerificationFailure.context(e).into())
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case().encode_to_string(&self.bytes).unwrap();
+        f.debug_tuple("PublicKey").field(&hex).finish()
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if raw.type_url != SECP256K1_PUBLIC_KEY_TYPE_URL {
+            return Err(Kind::UnsupportedPublicKeyType(raw.type_url).into());
+        }
+
+        let raw_key: RawSecp256k1PublicKey = prost::Message::decode(raw.value.as_ref())
+            .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+
+        Self::from_sec1_bytes(&raw_key.key)
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(key: PublicKey) -> Self {
+        let mut value = Vec::new();
+        prost::Message::encode(&RawSecp256k1PublicKey { key: key.bytes }, &mut value).unwrap();
+
+        Any {
+            type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+            value,
+        }
+    }
+}
+
+/// Extracts the raw signature out of the encoded `SignatureDescriptor.Data` which solo machines
+/// attach to headers and proofs. Only single signatures are supported.
+pub fn decode_signature(signature_data: &[u8]) -> Result<Vec<u8>, Error> {
+    let data: RawSignatureData = prost::Message::decode(signature_data)
+        .map_err(|e| Kind::InvalidSignatureData.context(e))?;
+
+    match data.sum {
+        Some(RawSignatureDataSum::Single(single)) => Ok(single.signature),
+        _ => Err(Kind::InvalidSignatureData.into()),
+    }
+}
@@ -1,17 +1,32 @@
-use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+use std::convert::TryFrom;
+
+use tendermint_light_client::operations::voting_power::{
+    ProdVotingPowerCalculator, VotingPowerCalculator,
+};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 
 use crate::ics02_client::client_consensus::AnyConsensusState;
 use crate::ics02_client::client_def::ClientDef;
 use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::packet::Sequence;
 use crate::ics07_tendermint::client_state::ClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState;
+use crate::ics07_tendermint::error::{Error, Kind};
 use crate::ics07_tendermint::header::Header;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use crate::ics23_commitment::specs::ProofSpecs;
 use crate::ics24_host::identifier::ConnectionId;
 use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+use crate::ics24_host::Path;
 use crate::Height;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -21,9 +36,11 @@ impl ClientDef for TendermintClient {
     type Header = Header;
     type ClientState = ClientState;
     type ConsensusState = ConsensusState;
+    type Misbehaviour = Misbehaviour;
 
     fn check_header_and_update_state(
         &self,
+        _ctx: &dyn ClientReader,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -42,114 +59,622 @@ impl ClientDef for TendermintClient {
         ))
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        // Two headers are evidence of misbehaviour if they are for the same height but commit to
+        // different blocks, or if their timestamps violate BFT time monotonicity.
+        if misbehaviour.header1.compatible_with(&misbehaviour.header2) {
+            return Err(Error::from(Kind::MisbehaviourHeadersNotConflicting).into());
+        }
+
+        check_misbehaviour_header(ctx, &client_id, &client_state, &misbehaviour.header1)?;
+        check_misbehaviour_header(ctx, &client_id, &client_state, &misbehaviour.header2)?;
+
+        Ok(client_state.with_frozen_height(misbehaviour.header1.height()))
+    }
+
     fn verify_client_consensus_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _client_id: &ClientId,
-        _consensus_height: Height,
-        _expected_consensus_state: &AnyConsensusState,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let value = expected_consensus_state
+            .encode_vec()
+            .map_err(|e| Error::from(Kind::InvalidExpectedValue.context(e)))?;
+
+        Ok(verify_membership(prefix, proof, root, path, value)?)
     }
 
     fn verify_connection_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _connection_id: Option<&ConnectionId>,
-        _expected_connection_end: &ConnectionEnd,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let connection_id = connection_id.ok_or(Kind::MissingConnectionId)?;
+
+        let path = Path::Connections(connection_id.clone());
+        let value = expected_connection_end
+            .encode_vec()
+            .map_err(|e| Error::from(Kind::InvalidExpectedValue.context(e)))?;
+
+        Ok(verify_membership(prefix, proof, root, path, value)?)
     }
 
     fn verify_channel_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _expected_channel_end: &ChannelEnd,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let value = expected_channel_end
+            .encode_vec()
+            .map_err(|e| Error::from(Kind::InvalidExpectedValue.context(e)))?;
+
+        Ok(verify_membership(prefix, proof, root, path, value)?)
     }
 
     fn verify_client_full_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _root: &CommitmentRoot,
-        _prefix: &CommitmentPrefix,
-        _client_id: &ClientId,
-        _proof: &CommitmentProofBytes,
-        _expected_client_state: &AnyClientState,
+        _ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        unimplemented!()
+        client_state.verify_height(height)?;
+
+        let path = Path::ClientState(client_id.clone());
+        let value = expected_client_state
+            .encode_vec()
+            .map_err(|e| Error::from(Kind::InvalidExpectedValue.context(e)))?;
+
+        Ok(verify_membership(prefix, proof, root, path, value)?)
     }
 
     fn verify_packet_data(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
-        _data: String,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        Ok(verify_membership(prefix, proof, root, path, commitment)?)
     }
 
     fn verify_packet_acknowledgement(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
-        _data: Vec<u8>,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        // Chains store a commitment to the acknowledgement, not the acknowledgement itself.
+        Ok(verify_membership(
+            prefix,
+            proof,
+            root,
+            path,
+            ctx.ack_commitment(ack),
+        )?)
     }
 
     fn verify_next_sequence_recv(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        // The next sequence receive is stored as a big-endian encoded `u64`.
+        let value = u64::from(*seq).to_be_bytes().to_vec();
+
+        Ok(verify_membership(prefix, proof, root, path, value)?)
     }
 
     fn verify_packet_receipt_absence(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _seq: &Sequence,
+        _ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        client_state.verify_height(height)?;
+
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        Ok(verify_non_membership(prefix, proof, root, path)?)
     }
 
     fn verify_upgrade_and_update_state(
         &self,
         _client_state: &Self::ClientState,
         _consensus_state: &Self::ConsensusState,
-        _proof_upgrade_client: MerkleProof,
-        _proof_upgrade_consensus_state: MerkleProof,
+        _proof_upgrade_client: RawMerkleProof,
+        _proof_upgrade_consensus_state: RawMerkleProof,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
         todo!()
     }
 }
+
+/// Checks that a header of a misbehaviour descends from a consensus state trusted by the client.
+fn check_misbehaviour_header(
+    ctx: &dyn ClientReader,
+    client_id: &ClientId,
+    client_state: &ClientState,
+    header: &Header,
+) -> Result<(), Error> {
+    if header.signed_header.header.chain_id.as_str() != client_state.chain_id.as_str() {
+        return Err(Kind::InvalidMisbehaviourHeader(format!(
+            "chain identifier {} does not match the client chain identifier {}",
+            header.signed_header.header.chain_id, client_state.chain_id
+        ))
+        .into());
+    }
+
+    if header.height() <= header.trusted_height {
+        return Err(Kind::InvalidMisbehaviourHeader(format!(
+            "header height {} is not greater than the trusted height {}",
+            header.height(),
+            header.trusted_height
+        ))
+        .into());
+    }
+
+    let trusted_consensus_state = match ctx.consensus_state(client_id, header.trusted_height) {
+        Some(AnyConsensusState::Tendermint(cs)) => cs,
+        _ => return Err(Kind::TrustedConsensusStateNotFound(header.trusted_height).into()),
+    };
+
+    if header.trusted_validator_set.hash() != trusted_consensus_state.next_validators_hash {
+        return Err(Kind::InvalidMisbehaviourHeader(
+            "trusted validator set does not match the trusted consensus state".to_string(),
+        )
+        .into());
+    }
+
+    if header.validator_set.hash() != header.signed_header.header.validators_hash {
+        return Err(Kind::InvalidMisbehaviourHeader(
+            "validator set does not match the header validators hash".to_string(),
+        )
+        .into());
+    }
+
+    let signed_header = &header.signed_header;
+
+    if signed_header.header.hash() != signed_header.commit.block_id.hash {
+        return Err(
+            Kind::InvalidMisbehaviourHeader("commit is not for the header".to_string()).into(),
+        );
+    }
+
+    // The header must be signed by enough of the trusted validators for the client to trust it,
+    // and by more than 2/3 of its own validators for it to be committed.
+    let calculator = ProdVotingPowerCalculator::default();
+
+    calculator
+        .check_enough_trust(
+            signed_header,
+            &header.trusted_validator_set,
+            client_state.trust_level,
+        )
+        .map_err(|e| {
+            Kind::InvalidMisbehaviourHeader(format!("not enough trust in the header commit: {}", e))
+        })?;
+
+    calculator
+        .check_signers_overlap(signed_header, &header.validator_set)
+        .map_err(|e| {
+            Kind::InvalidMisbehaviourHeader(format!(
+                "header commit not signed by its validators: {}",
+                e
+            ))
+        })?;
+
+    Ok(())
+}
+
+/// Verifies that `value` is stored under `path` (prefixed with the counterparty's commitment
+/// `prefix`) in the store committed to by `root`.
+fn verify_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), Error> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])
+        .map_err(|e| Kind::InvalidCommitmentPrefix.context(e.to_string()))?;
+    let merkle_proof = RawMerkleProof::try_from(proof.clone())
+        .and_then(MerkleProof::try_from)
+        .map_err(|e| Kind::InvalidCommitmentProof.context(e))?;
+
+    merkle_proof
+        .verify_membership(&ProofSpecs::cosmos(), root, &merkle_path, value, 0)
+        .map_err(|e| Kind::ProofVerificationFailure(e).into())
+}
+
+/// Verifies that no value is stored under `path` (prefixed with the counterparty's commitment
+/// `prefix`) in the store committed to by `root`.
+fn verify_non_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), Error> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])
+        .map_err(|e| Kind::InvalidCommitmentPrefix.context(e.to_string()))?;
+    let merkle_proof = RawMerkleProof::try_from(proof.clone())
+        .and_then(MerkleProof::try_from)
+        .map_err(|e| Kind::InvalidCommitmentProof.context(e))?;
+
+    merkle_proof
+        .verify_non_membership(&ProofSpecs::cosmos(), root, &merkle_path)
+        .map_err(|e| Kind::ProofVerificationFailure(e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ics23::commitment_proof::Proof;
+    use ics23::{CommitmentProof, ExistenceProof};
+    use subtle_encoding::hex;
+    use test_env_log::test;
+
+    use tendermint::block::CommitSig;
+    use tendermint::Hash;
+    use tendermint_testgen::light_block::TmLightBlock;
+    use tendermint_testgen::{
+        Commit as TestgenCommit, Generator, Header as TestgenHeader,
+        LightBlock as TestgenLightBlock, Validator as TestgenValidator,
+    };
+
+    use crate::ics02_client::client_consensus::AnyConsensusState;
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::client_state::AnyClientState;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics07_tendermint::client_def::TendermintClient;
+    use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::ics07_tendermint::client_state::ClientState;
+    use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::ics07_tendermint::header::Header;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+    use crate::ics23_commitment::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use crate::ics23_commitment::merkle::MerkleProof;
+    use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostType;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    const CHAIN_ID: &str = "ibc-0";
+
+    fn validators(names: &[&str]) -> Vec<TestgenValidator> {
+        names
+            .iter()
+            .map(|name| TestgenValidator::new(name).voting_power(50))
+            .collect()
+    }
+
+    /// Returns a block at the given height and time, signed by the given validators.
+    fn signed_block(validators: &[TestgenValidator], height: u64, time: u64) -> TmLightBlock {
+        let header = TestgenHeader::new(validators)
+            .chain_id(CHAIN_ID)
+            .height(height)
+            .time(time);
+        let commit = TestgenCommit::new(header.clone(), 1);
+
+        TestgenLightBlock::new(header, commit).generate().unwrap()
+    }
+
+    fn misbehaviour_header(block: TmLightBlock, trusted_block: &TmLightBlock) -> Header {
+        Header {
+            signed_header: block.signed_header,
+            validator_set: block.validators,
+            trusted_height: Height::new(0, 1),
+            trusted_validator_set: trusted_block.next_validators.clone(),
+        }
+    }
+
+    /// Returns a context with a Tendermint client trusting the given block at height 1,
+    /// along with the state of this client.
+    fn context_with_client(
+        client_id: &ClientId,
+        trusted_block: &TmLightBlock,
+    ) -> (MockContext, ClientState) {
+        let mut ctx = MockContext::new(
+            ChainId::new("mockgaia".to_string(), 1),
+            HostType::Mock,
+            5,
+            Height::new(1, 5),
+        );
+
+        ctx.store_consensus_state(
+            client_id.clone(),
+            Height::new(0, 1),
+            AnyConsensusState::from(trusted_block.clone()),
+        )
+        .unwrap();
+
+        let client_state =
+            match get_dummy_tendermint_client_state(trusted_block.signed_header.header.clone()) {
+                AnyClientState::Tendermint(client_state) => client_state,
+                _ => unreachable!(),
+            };
+
+        (ctx, client_state)
+    }
+
+    /// Returns whether a Tendermint client trusting `trusted_block` accepts the misbehaviour
+    /// made of the given headers.
+    fn check_misbehaviour(trusted_block: &TmLightBlock, header1: Header, header2: Header) -> bool {
+        let client_id = ClientId::default();
+        let (ctx, client_state) = context_with_client(&client_id, trusted_block);
+
+        let misbehaviour = Misbehaviour {
+            client_id: client_id.clone(),
+            header1,
+            header2,
+        };
+
+        TendermintClient
+            .check_misbehaviour_and_update_state(&ctx, client_id, client_state, misbehaviour)
+            .is_ok()
+    }
+
+    #[test]
+    fn misbehaviour_requires_signed_headers() {
+        let honest = validators(&["a", "b", "c", "d"]);
+        let trusted_block = signed_block(&honest, 1, 1);
+        let header = |block| misbehaviour_header(block, &trusted_block);
+        let check = |header1: &Header, header2: Header| {
+            check_misbehaviour(&trusted_block, header1.clone(), header2)
+        };
+
+        // Two conflicting headers signed by the trusted validators freeze the client
+        let header1 = header(signed_block(&honest, 3, 3));
+        assert!(check(&header1, header(signed_block(&honest, 3, 4))));
+
+        // The commit of a header made up from a signed one does not match it
+        let mut made_up = header(signed_block(&honest, 3, 4));
+        made_up.signed_header.header.consensus_hash = Hash::Sha256([1; 32]);
+        assert!(!check(&header1, made_up.clone()));
+
+        // Nor do its signatures sign it, even when its commit is made to match it
+        made_up.signed_header.commit.block_id.hash = made_up.signed_header.header.hash();
+        assert!(!check(&header1, made_up));
+
+        // An unsigned header
+        let mut unsigned = header(signed_block(&honest, 3, 4));
+        unsigned.signed_header.commit.signatures = unsigned
+            .signed_header
+            .commit
+            .signatures
+            .iter()
+            .map(|_| CommitSig::BlockIdFlagAbsent)
+            .collect();
+        assert!(!check(&header1, unsigned));
+
+        // A header signed by validators the client does not trust
+        let forged = header(signed_block(&validators(&["e", "f", "g", "h"]), 3, 4));
+        assert!(!check(&header1, forged));
+    }
+
+    /// Returns the proof that `value` is stored under `key` in an IBC store holding this sole
+    /// entry, within a multistore holding this sole store, as produced by Cosmos SDK chains.
+    fn proof_fixture(key: &str, value: &[u8]) -> CommitmentProofBytes {
+        let mut iavl_leaf = ics23::iavl_spec().leaf_spec.unwrap();
+        // IAVL leaf prefix: height 0, size 1, version 1 (zig-zag varints).
+        iavl_leaf.prefix = vec![0, 2, 2];
+        let iavl_proof = ExistenceProof {
+            key: key.as_bytes().to_vec(),
+            value: value.to_vec(),
+            leaf: Some(iavl_leaf),
+            path: vec![],
+        };
+        let store_root = ics23::calculate_existence_root(&iavl_proof).unwrap();
+        let store_proof = ExistenceProof {
+            key: b"ibc".to_vec(),
+            value: store_root,
+            leaf: ics23::tendermint_spec().leaf_spec,
+            path: vec![],
+        };
+
+        MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(iavl_proof)),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store_proof)),
+                },
+            ],
+        }
+        .into()
+    }
+
+    fn root(hex_root: &str) -> CommitmentRoot {
+        hex::decode(hex_root).unwrap().into()
+    }
+
+    fn dummy_client_state() -> ClientState {
+        match get_dummy_tendermint_client_state(get_dummy_tendermint_header()) {
+            AnyClientState::Tendermint(client_state) => client_state,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn verify_packet_data() {
+        let ctx = MockContext::default();
+        let mut client_state = dummy_client_state();
+        let height = client_state.latest_height;
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::default();
+        let seq = Sequence::from(1);
+
+        // The commitment to a packet with data `packet data`, timing out at height 0-10
+        let commitment = ctx.packet_commitment(
+            b"packet data".to_vec(),
+            Height::new(0, 10),
+            Timestamp::none(),
+        );
+        assert_eq!(
+            hex::encode(&commitment),
+            b"a26caf488c3f082e881748fc3f5fb30bbd56d4428f2db3b4b8cc8d6b77a26229".to_vec()
+        );
+
+        let proof = proof_fixture(
+            "commitments/ports/transfer/channels/channel-0/sequences/1",
+            &commitment,
+        );
+        let root = root("1e60fa4298405383cf4d8d8f43f3ae3d379184c58a4a8b55a71ae5b9ec67c97b");
+        let mut verify = |seq: &Sequence, commitment: Vec<u8>| {
+            TendermintClient
+                .verify_packet_data(
+                    &ctx,
+                    &mut client_state,
+                    height,
+                    &prefix,
+                    &proof,
+                    &root,
+                    &port_id,
+                    &channel_id,
+                    seq,
+                    commitment,
+                )
+                .is_ok()
+        };
+
+        assert!(verify(&seq, commitment.clone()));
+        assert!(!verify(&seq, ctx.hash(b"packet data".to_vec())));
+        assert!(!verify(&Sequence::from(2), commitment));
+    }
+
+    #[test]
+    fn verify_packet_acknowledgement() {
+        let ctx = MockContext::default();
+        let mut client_state = dummy_client_state();
+        let height = client_state.latest_height;
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::default();
+        let seq = Sequence::from(1);
+
+        // Chains store the hash of the acknowledgement `ack`
+        let proof = proof_fixture(
+            "acks/ports/transfer/channels/channel-0/sequences/1",
+            &ctx.ack_commitment(b"ack".to_vec()),
+        );
+        let root = root("7ade88669b345891a4cdbe5140e47e0314835c71a332070814966dc9a5494dcc");
+        let mut verify = |height: Height, ack: &[u8]| {
+            TendermintClient
+                .verify_packet_acknowledgement(
+                    &ctx,
+                    &mut client_state,
+                    height,
+                    &prefix,
+                    &proof,
+                    &root,
+                    &port_id,
+                    &channel_id,
+                    &seq,
+                    ack.to_vec(),
+                )
+                .is_ok()
+        };
+
+        assert!(verify(height, b"ack"));
+        assert!(!verify(height, b"other ack"));
+        assert!(!verify(height.increment(), b"ack"));
+    }
+}
@@ -111,6 +111,14 @@ impl ClientState {
         }
     }
 
+    /// Returns a copy of this client state, frozen at height `h`.
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        ClientState {
+            frozen_height: h,
+            ..self
+        }
+    }
+
     /// Helper function to verify the upgrade client procedure.
     /// Resets all fields except the blockchain-specific ones.
     pub fn zero_custom_fields(mut client_state: Self) -> Self {
@@ -136,6 +144,20 @@ impl ClientState {
     pub fn expired(&self, elapsed: Duration) -> bool {
         elapsed > self.trusting_period
     }
+
+    /// Check that this client can verify proofs at the given `height`, i.e., the client has
+    /// advanced to that height and was not frozen at or below it.
+    pub fn verify_height(&self, height: Height) -> Result<(), Error> {
+        if self.latest_height < height {
+            return Err(Kind::InsufficientHeight(self.latest_height, height).into());
+        }
+
+        if !self.frozen_height.is_zero() && self.frozen_height <= height {
+            return Err(Kind::ClientFrozen(self.frozen_height, height).into());
+        }
+
+        Ok(())
+    }
 }
 
 impl crate::ics02_client::client_state::ClientState for ClientState {
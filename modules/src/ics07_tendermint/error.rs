@@ -1,7 +1,9 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics23_commitment::error::Error as Ics23Error;
 use crate::ics24_host::error::ValidationKind;
+use crate::Height;
 
 pub type Error = anomaly::Error<Kind>;
 
@@ -39,6 +41,36 @@ pub enum Kind {
 
     #[error("invalid raw misbehaviour")]
     InvalidRawMisbehaviour,
+
+    #[error("insufficient client height: latest height {0} is lower than the proof height {1}")]
+    InsufficientHeight(Height, Height),
+
+    #[error("client is frozen at height {0}, cannot verify proofs at height {1}")]
+    ClientFrozen(Height, Height),
+
+    #[error("invalid commitment proof bytes")]
+    InvalidCommitmentProof,
+
+    #[error("failed to encode the expected value")]
+    InvalidExpectedValue,
+
+    #[error("missing counterparty connection identifier")]
+    MissingConnectionId,
+
+    #[error("invalid commitment prefix")]
+    InvalidCommitmentPrefix,
+
+    #[error("proof verification failed: {0}")]
+    ProofVerificationFailure(Ics23Error),
+
+    #[error("the headers of the misbehaviour are not conflicting")]
+    MisbehaviourHeadersNotConflicting,
+
+    #[error("invalid misbehaviour header: {0}")]
+    InvalidMisbehaviourHeader(String),
+
+    #[error("no trusted consensus state found at height {0}")]
+    TrustedConsensusStateNotFound(Height),
 }
 
 impl Kind {
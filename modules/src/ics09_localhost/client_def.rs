@@ -0,0 +1,436 @@
+use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
+use crate::ics02_client::client_consensus::AnyConsensusState;
+use crate::ics02_client::client_def::ClientDef;
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::header::{AnyHeader, Header};
+use crate::ics02_client::misbehaviour::AnyMisbehaviour;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics09_localhost::client_state::ClientState;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// The localhost client, i.e., a client of the host chain itself. Rather than verifying proofs,
+/// it reads the value stored under each path directly from the host store, so that modules on the
+/// same chain can communicate over IBC.
+///
+/// The client keeps the consensus states of the host as its own consensus states, and can only be
+/// updated to heights for which the host still holds a consensus state. The content of a header is
+/// otherwise ignored, since the host trusts itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalhostClient;
+
+impl ClientDef for LocalhostClient {
+    type Header = AnyHeader;
+    type ClientState = ClientState;
+    type ConsensusState = AnyConsensusState;
+    type Misbehaviour = AnyMisbehaviour;
+
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        let height = header.height();
+        if height <= client_state.latest_height() {
+            return Err(Error::from(Kind::InvalidHeaderHeight(
+                client_state.latest_height(),
+                height,
+            ))
+            .into());
+        }
+
+        let consensus_state = ctx
+            .host_consensus_state(height)
+            .ok_or_else(|| Error::from(Kind::MissingHostConsensusState(height)))?;
+
+        Ok((client_state.with_height(height), consensus_state))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        Err(Error::from(Kind::MisbehaviourNotSupported).into())
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _proof_upgrade_client: MerkleProof,
+        _proof_upgrade_consensus_state: MerkleProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Error::from(Kind::UpgradeNotSupported).into())
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let consensus_state = ctx.client_consensus_state(client_id, consensus_height);
+
+        Ok(check_value(
+            path,
+            consensus_state.as_ref() == Some(expected_consensus_state),
+        )?)
+    }
+
+    fn verify_connection_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: Option<&ConnectionId>,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let connection_id = connection_id.ok_or_else(|| Error::from(Kind::MissingConnectionId))?;
+        let connection_end = ctx.connection_end(connection_id);
+
+        Ok(check_value(
+            Path::Connections(connection_id.clone()),
+            connection_end.as_ref() == Some(expected_connection_end),
+        )?)
+    }
+
+    fn verify_channel_state(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let channel_end = ctx.channel_end(&(port_id.clone(), channel_id.clone()));
+
+        Ok(check_value(
+            Path::ChannelEnds(port_id.clone(), channel_id.clone()),
+            channel_end.as_ref() == Some(expected_channel_end),
+        )?)
+    }
+
+    fn verify_client_full_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        _proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let stored_client_state = ctx.client_state(client_id);
+
+        Ok(check_value(
+            Path::ClientState(client_id.clone()),
+            stored_client_state.as_ref() == Some(expected_client_state),
+        )?)
+    }
+
+    fn verify_packet_data(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let stored_commitment =
+            ctx.get_packet_commitment(&(port_id.clone(), channel_id.clone(), *seq));
+
+        Ok(check_value(
+            Path::Commitments {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: *seq,
+            },
+            stored_commitment == Some(commitment),
+        )?)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        // The host stores a commitment to the acknowledgement, not the acknowledgement itself.
+        let commitment = ctx.ack_commitment(ack);
+        let stored_commitment =
+            ctx.get_packet_acknowledgement(&(port_id.clone(), channel_id.clone(), *seq));
+
+        Ok(check_value(
+            Path::Acks {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: *seq,
+            },
+            stored_commitment == Some(commitment),
+        )?)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let next_sequence_recv = ctx.get_next_sequence_recv(&(port_id.clone(), channel_id.clone()));
+
+        Ok(check_value(
+            Path::SeqRecvs(port_id.clone(), channel_id.clone()),
+            next_sequence_recv == Some(*seq),
+        )?)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &mut Self::ClientState,
+        height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        check_proof_height(client_state, height)?;
+
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        match ctx.get_packet_receipt(&(port_id.clone(), channel_id.clone(), *seq)) {
+            Some(_) => Err(Error::from(Kind::PacketReceiptPresent(path.to_string())).into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Values can only be read at heights the client has been updated to.
+fn check_proof_height(client_state: &ClientState, height: Height) -> Result<(), Error> {
+    if height > client_state.latest_height() {
+        return Err(Kind::InvalidProofHeight(client_state.latest_height(), height).into());
+    }
+
+    Ok(())
+}
+
+fn check_value(path: Path, matches: bool) -> Result<(), Error> {
+    if matches {
+        Ok(())
+    } else {
+        Err(Kind::ValueMismatch(path.to_string()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use crate::ics02_client::client_state::AnyClientState;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::{Counterparty, State};
+    use crate::ics03_connection::context::ConnectionReader;
+    use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+    use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+    use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+    use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+    use crate::ics03_connection::msgs::ConnectionMsg;
+    use crate::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+    use crate::ics26_routing::handler::dispatch;
+    use crate::ics26_routing::msgs::Ics26Envelope;
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostType;
+    use crate::proofs::{ConsensusProof, Proofs};
+    use crate::test_utils::get_dummy_account_id;
+    use crate::timestamp::ZERO_DURATION;
+    use crate::Height;
+
+    fn localhost_context(client_id: &ClientId, height: Height) -> MockContext {
+        MockContext::new(
+            ChainId::new("mockgaia".to_string(), height.revision_number),
+            HostType::Mock,
+            5,
+            height,
+        )
+        .with_client_parametrized(
+            client_id,
+            height,
+            Some(ClientType::Localhost),
+            Some(height),
+        )
+    }
+
+    /// Localhost proofs are never inspected, but the messages require non-empty proofs.
+    fn proofs(height: Height, with_client_proofs: bool) -> Proofs {
+        let (client_proof, consensus_proof) = if with_client_proofs {
+            (
+                Some(vec![0].into()),
+                Some(ConsensusProof::new(vec![0].into(), height).unwrap()),
+            )
+        } else {
+            (None, None)
+        };
+
+        Proofs::new(vec![0].into(), client_proof, consensus_proof, None, height).unwrap()
+    }
+
+    #[test]
+    fn connection_handshake_over_localhost() {
+        let client_id = ClientId::new(ClientType::Localhost, 0).unwrap();
+        let height = Height::new(1, 5);
+        let mut ctx = localhost_context(&client_id, height);
+
+        let signer = get_dummy_account_id();
+        let prefix = ctx.commitment_prefix();
+        let versions = ctx.get_compatible_versions();
+        let client_state: Option<AnyClientState> = ctx.client_state(&client_id);
+        let (conn_id_a, conn_id_b) = (ConnectionId::new(0), ConnectionId::new(1));
+
+        let init = MsgConnectionOpenInit {
+            client_id: client_id.clone(),
+            counterparty: Counterparty::new(client_id.clone(), None, prefix.clone()),
+            version: versions[0].clone(),
+            delay_period: ZERO_DURATION,
+            signer: signer.clone(),
+        };
+        let try_msg = MsgConnectionOpenTry {
+            previous_connection_id: None,
+            client_id: client_id.clone(),
+            client_state: client_state.clone(),
+            counterparty: Counterparty::new(client_id.clone(), Some(conn_id_a.clone()), prefix),
+            counterparty_versions: versions,
+            proofs: proofs(height, true),
+            delay_period: ZERO_DURATION,
+            signer: signer.clone(),
+        };
+
+        for msg in vec![
+            ConnectionMsg::ConnectionOpenInit(init),
+            ConnectionMsg::ConnectionOpenTry(Box::new(try_msg)),
+        ] {
+            dispatch(&mut ctx, Ics26Envelope::Ics3Msg(msg)).unwrap();
+        }
+
+        let version = ctx.connection_end(&conn_id_b).unwrap().versions()[0].clone();
+        let ack = MsgConnectionOpenAck {
+            connection_id: conn_id_a.clone(),
+            counterparty_connection_id: conn_id_b.clone(),
+            client_state,
+            proofs: proofs(height, true),
+            version,
+            signer: signer.clone(),
+        };
+        let confirm = MsgConnectionOpenConfirm {
+            connection_id: conn_id_b.clone(),
+            proofs: proofs(height, false),
+            signer,
+        };
+
+        for msg in vec![
+            ConnectionMsg::ConnectionOpenAck(Box::new(ack)),
+            ConnectionMsg::ConnectionOpenConfirm(confirm),
+        ] {
+            dispatch(&mut ctx, Ics26Envelope::Ics3Msg(msg)).unwrap();
+        }
+
+        for conn_id in &[conn_id_a, conn_id_b] {
+            assert_eq!(ctx.connection_end(conn_id).unwrap().state(), &State::Open);
+        }
+    }
+
+    #[test]
+    fn connection_try_without_counterparty_end_fails() {
+        let client_id = ClientId::new(ClientType::Localhost, 0).unwrap();
+        let height = Height::new(1, 5);
+        let mut ctx = localhost_context(&client_id, height);
+
+        // No connection was initialized on the host, hence there is nothing to read.
+        let try_msg = MsgConnectionOpenTry {
+            previous_connection_id: None,
+            client_id: client_id.clone(),
+            client_state: ctx.client_state(&client_id),
+            counterparty: Counterparty::new(
+                client_id.clone(),
+                Some(ConnectionId::new(0)),
+                ctx.commitment_prefix(),
+            ),
+            counterparty_versions: ctx.get_compatible_versions(),
+            proofs: proofs(height, true),
+            delay_period: ZERO_DURATION,
+            signer: get_dummy_account_id(),
+        };
+
+        let msg = ConnectionMsg::ConnectionOpenTry(Box::new(try_msg));
+        assert!(dispatch(&mut ctx, Ics26Envelope::Ics3Msg(msg)).is_err());
+    }
+}
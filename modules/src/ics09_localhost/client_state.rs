@@ -0,0 +1,88 @@
+use std::convert::{TryFrom, TryInto};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::localhost::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::ics24_host::identifier::ChainId;
+use crate::utils::UnwrapInfallible;
+use crate::Height;
+
+/// The client state of a localhost client, i.e., a client of the host chain itself. As proofs are
+/// replaced by reads of the host store, the client only tracks the identifier of the host chain
+/// and the latest host height it was updated to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, height: Height) -> Self {
+        Self { chain_id, height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    /// Returns a copy of this client state, updated to the host height `height`.
+    pub fn with_height(self, height: Height) -> Self {
+        Self { height, ..self }
+    }
+}
+
+impl crate::ics02_client::client_state::ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    fn is_frozen(&self) -> bool {
+        // The host chain cannot misbehave towards itself.
+        false
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::Localhost(self)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: ChainId::from_str(raw.chain_id.as_str())
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            height: raw
+                .height
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing height"))?
+                .try_into()
+                .unwrap_infallible(),
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            chain_id: value.chain_id.to_string(),
+            height: Some(value.height.into()),
+        }
+    }
+}
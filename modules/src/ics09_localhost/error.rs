@@ -0,0 +1,42 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("header height {1} is not greater than the client latest height {0}")]
+    InvalidHeaderHeight(Height, Height),
+
+    #[error("the host does not have a consensus state at height {0}")]
+    MissingHostConsensusState(Height),
+
+    #[error("proof height {1} is greater than the client latest height {0}")]
+    InvalidProofHeight(Height, Height),
+
+    #[error("missing counterparty connection identifier")]
+    MissingConnectionId,
+
+    #[error("the value stored at path {0} does not match the expected value")]
+    ValueMismatch(String),
+
+    #[error("a packet receipt is stored at path {0}")]
+    PacketReceiptPresent(String),
+
+    #[error("localhost clients cannot misbehave")]
+    MisbehaviourNotSupported,
+
+    #[error("localhost clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
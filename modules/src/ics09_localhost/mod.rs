@@ -0,0 +1,5 @@
+//! ICS 09: Loopback (localhost) Client
+
+pub mod client_def;
+pub mod client_state;
+pub mod error;
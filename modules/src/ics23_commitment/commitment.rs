@@ -1,4 +1,5 @@
 use crate::ics23_commitment::error::Error;
+use crate::ics23_commitment::merkle::MerkleProof;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, fmt};
@@ -68,12 +69,12 @@ impl From<CommitmentProofBytes> for Vec<u8> {
     }
 }
 
-// impl From<MerkleProof> for CommitmentProofBytes {
-//     fn from(proof: MerkleProof) -> Self {
-//         let raw_proof: RawMerkleProof = proof.into();
-//         raw_proof.into()
-//     }
-// }
+impl From<MerkleProof> for CommitmentProofBytes {
+    fn from(proof: MerkleProof) -> Self {
+        let raw_proof: RawMerkleProof = proof.into();
+        raw_proof.into()
+    }
+}
 
 impl From<RawMerkleProof> for CommitmentProofBytes {
     fn from(proof: RawMerkleProof) -> Self {
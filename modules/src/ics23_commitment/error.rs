@@ -8,4 +8,25 @@ pub enum Error {
 
     #[error("failed to decode commitment proof")]
     CommitmentProofDecodingFailed(DecodeError),
+
+    #[error("empty merkle proof")]
+    EmptyMerkleProof,
+
+    #[error("empty merkle root")]
+    EmptyMerkleRoot,
+
+    #[error("empty verified value")]
+    EmptyVerifiedValue,
+
+    #[error("mismatch between the number of proofs ({0}) and the number of proof specs ({1})")]
+    NumberOfSpecsMismatch(usize, usize),
+
+    #[error("mismatch between the number of proofs ({0}) and the number of keys ({1})")]
+    NumberOfKeysMismatch(usize, usize),
+
+    #[error("invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[error("proof verification failed")]
+    VerificationFailure,
 }
@@ -1,10 +1,18 @@
-use tendermint::merkle::proof::Proof;
+use std::convert::TryFrom;
+
+use ics23::commitment_proof::Proof;
+use ics23::{
+    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
+    NonExistenceProof,
+};
+use tendermint::merkle::proof::Proof as TendermintProof;
 
 use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 
-use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
 use crate::ics23_commitment::error::Error;
+use crate::ics23_commitment::specs::ProofSpecs;
 
 pub fn apply_prefix(
     prefix: &CommitmentPrefix,
@@ -20,9 +28,168 @@ pub fn apply_prefix(
     Ok(MerklePath { key_path: result })
 }
 
+/// Domain type for a chain of ICS23 commitment proofs.
+/// The proofs are ordered from leaf to root, i.e., the first proof is for the key in the lowest
+/// subtree (e.g., the IBC store), while the last one proves the subtree root within the app hash.
 #[derive(Clone, Debug, PartialEq)]
 pub struct MerkleProof {
-    pub proof: Vec<CommitmentProofBytes>,
+    pub proofs: Vec<CommitmentProof>,
+}
+
+impl TryFrom<RawMerkleProof> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(proof: RawMerkleProof) -> Result<Self, Self::Error> {
+        // Both `CommitmentProof` types implement trait `prost::Message`, and they have identical
+        // definitions. Convert by encoding, then decoding into the destination type.
+        let proofs = proof
+            .proofs
+            .iter()
+            .map(|p| {
+                let mut encoded = Vec::new();
+                // Safety note: encoding into a `Vec` cannot run out of capacity.
+                prost::Message::encode(p, &mut encoded).unwrap();
+                prost::Message::decode(&*encoded).map_err(Error::CommitmentProofDecodingFailed)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(MerkleProof { proofs })
+    }
+}
+
+impl From<MerkleProof> for RawMerkleProof {
+    fn from(proof: MerkleProof) -> Self {
+        // Same conversion as above, in the opposite direction.
+        let proofs = proof
+            .proofs
+            .iter()
+            .map(|p| {
+                let mut encoded = Vec::new();
+                prost::Message::encode(p, &mut encoded).unwrap();
+                prost::Message::decode(&*encoded).unwrap()
+            })
+            .collect();
+
+        RawMerkleProof { proofs }
+    }
+}
+
+impl MerkleProof {
+    /// Verifies that `value` is stored under the path `keys` in the tree with the given `root`.
+    /// The `keys` are represented from root to leaf. Verification starts at the proof with index
+    /// `start_index`, this allows callers to skip proofs for subtrees that were already verified.
+    pub fn verify_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: &MerklePath,
+        value: Vec<u8>,
+        start_index: usize,
+    ) -> Result<(), Error> {
+        self.validate_arguments(specs, root, keys)?;
+
+        if value.is_empty() {
+            return Err(Error::EmptyVerifiedValue);
+        }
+
+        let mut subroot = value.clone();
+        let mut value = value;
+
+        for ((proof, spec), key) in self
+            .proofs
+            .iter()
+            .zip(specs.specs().iter())
+            .zip(keys.key_path.iter().rev())
+            .skip(start_index)
+        {
+            match &proof.proof {
+                Some(Proof::Exist(existence_proof)) => {
+                    subroot = calculate_existence_root(existence_proof)
+                        .map_err(|_| Error::InvalidMerkleProof)?;
+
+                    if !verify_membership(proof, spec, &subroot, key.as_bytes(), &value) {
+                        return Err(Error::VerificationFailure);
+                    }
+
+                    value = subroot.clone();
+                }
+                _ => return Err(Error::InvalidMerkleProof),
+            }
+        }
+
+        if root.as_bytes() != subroot.as_slice() {
+            return Err(Error::VerificationFailure);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that no value is stored under the path `keys` in the tree with the given `root`.
+    /// The first proof must be a non-existence proof for the leaf key, while the remaining
+    /// proofs must show that the root of the lowest subtree is committed to in `root`.
+    pub fn verify_non_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: &MerklePath,
+    ) -> Result<(), Error> {
+        self.validate_arguments(specs, root, keys)?;
+
+        // Keys are represented from root to leaf, hence the key to check is the last one.
+        let proof = &self.proofs[0];
+        let spec = &specs.specs()[0];
+        let key = &keys.key_path[keys.key_path.len() - 1];
+
+        match &proof.proof {
+            Some(Proof::Nonexist(non_existence_proof)) => {
+                let subroot = calculate_non_existence_root(non_existence_proof)?;
+
+                if !verify_non_membership(proof, spec, &subroot, key.as_bytes()) {
+                    return Err(Error::VerificationFailure);
+                }
+
+                // Verify the membership proofs starting from index 1, with the subroot as value.
+                self.verify_membership(specs, root, keys, subroot, 1)
+            }
+            _ => Err(Error::InvalidMerkleProof),
+        }
+    }
+
+    fn validate_arguments(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: &MerklePath,
+    ) -> Result<(), Error> {
+        if self.proofs.is_empty() {
+            return Err(Error::EmptyMerkleProof);
+        }
+
+        if root.as_bytes().is_empty() {
+            return Err(Error::EmptyMerkleRoot);
+        }
+
+        let num = self.proofs.len();
+        if specs.specs().len() != num {
+            return Err(Error::NumberOfSpecsMismatch(num, specs.specs().len()));
+        }
+
+        if keys.key_path.len() != num {
+            return Err(Error::NumberOfKeysMismatch(num, keys.key_path.len()));
+        }
+
+        Ok(())
+    }
+}
+
+fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, Error> {
+    if let Some(left) = &proof.left {
+        calculate_existence_root(left).map_err(|_| Error::InvalidMerkleProof)
+    } else if let Some(right) = &proof.right {
+        calculate_existence_root(right).map_err(|_| Error::InvalidMerkleProof)
+    } else {
+        Err(Error::InvalidMerkleProof)
+    }
 }
 
 // Merkle Proof serialization notes:
@@ -39,8 +206,8 @@ pub struct MerkleProof {
 //  - Vec<u8>: RawMerkleProof is not explicitly used but, serialized as Vec<u8>, it is
 //       included in all handshake messages that require proofs (i.e. all except the two `OpenInit`),
 //       and also in all queries that require proofs
-//  - MerkleProof: Domain type for RawMerkleProof, currently not used and identical to RawMerkleProof.
-//       This will change with verification implementation.
+//  - MerkleProof: Domain type for RawMerkleProof, holding `ics23::CommitmentProof`s and
+//       implementing membership and non-membership verification.
 //  - CommitmentProof: Defined in ibc-rs as Vec<u8> and currently used in all its messages
 //
 // Here are a couple of flows that illustrate the different conversions:
@@ -48,9 +215,6 @@ pub struct MerkleProof {
 //    Vec<u8> -> CommitmentProof -> RawMerkleProof -> MerkleProof
 //
 // Relayer: from the proof in the  query response to the proof being included in a message
-//    TmProof -> RawProofOps => RawMerkleProof -> MerkleProof -> verify()
-//      -> MerkleProof -> RawMerkleProof -> CommitmentProof -> Vec<u8>
-// Note: current implementation for ^ is simplified since verification is not yet implemented:
 //    TmProof -> RawProofOps => RawMerkleProof -> CommitmentProof -> Vec<u8>
 //
 // Implementations of (de)serializers and conversions:
@@ -58,26 +222,13 @@ pub struct MerkleProof {
 //      Vec<u8> <-> CommitmentProof
 //      CommitmentProof <-> RawMerkleProof
 //  - merkle.rs:
-//      RawMerkleProof <-> MerkleProof
+//      RawMerkleProof -> MerkleProof
 //  - tendermint-rs/src/merkle/proof.rs:
 //      TmProof <-> RawProofOps
 //  - cosmos.rs:abci_query() converts from query proof to Merkle proof:
 //      RawProofOps => RawMerkleProof
-//
-// impl TryFrom<RawMerkleProof> for MerkleProof {
-//     type Error = Error;
-//     fn try_from(value: RawMerkleProof) -> Result<Self, Self::Error> {
-//         Ok(MerkleProof { proof: value.proofs.into_iter().map(|v| v.into()).collect() })
-//     }
-// }
-//
-// impl From<MerkleProof> for RawMerkleProof {
-//     fn from(value: MerkleProof) -> Self {
-//         RawMerkleProof { proof: value.proof }
-//     }
-// }
 
-pub fn convert_tm_to_ics_merkle_proof(tm_proof: &Proof) -> Result<RawMerkleProof, Error> {
+pub fn convert_tm_to_ics_merkle_proof(tm_proof: &TendermintProof) -> Result<RawMerkleProof, Error> {
     let mut proofs = vec![];
 
     for op in &tm_proof.ops {
@@ -90,3 +241,123 @@ pub fn convert_tm_to_ics_merkle_proof(tm_proof: &Proof) -> Result<RawMerkleProof
 
     Ok(RawMerkleProof { proofs })
 }
+
+#[cfg(test)]
+mod tests {
+    use ics23::commitment_proof::Proof;
+    use ics23::{calculate_existence_root, CommitmentProof, ExistenceProof, NonExistenceProof};
+    use test_env_log::test;
+
+    use ibc_proto::ibc::core::commitment::v1::MerklePath;
+
+    use crate::ics23_commitment::commitment::CommitmentRoot;
+    use crate::ics23_commitment::merkle::MerkleProof;
+    use crate::ics23_commitment::specs::ProofSpecs;
+
+    const STORE_KEY: &str = "ibc";
+
+    /// Builds an existence proof for a single-leaf IAVL tree storing `value` under `key`.
+    fn iavl_leaf(key: &str, value: &[u8]) -> ExistenceProof {
+        let mut leaf = ics23::iavl_spec().leaf_spec.unwrap();
+        // IAVL leaf prefix: height 0, size 1, version 1 (zig-zag varints).
+        leaf.prefix = vec![0, 2, 2];
+
+        ExistenceProof {
+            key: key.as_bytes().to_vec(),
+            value: value.to_vec(),
+            leaf: Some(leaf),
+            path: vec![],
+        }
+    }
+
+    /// Builds a membership proof for the IBC store root in a single-store multistore, and
+    /// returns it along with the resulting app hash.
+    fn store_proof(store_root: Vec<u8>) -> (CommitmentProof, CommitmentRoot) {
+        let proof = ExistenceProof {
+            key: STORE_KEY.as_bytes().to_vec(),
+            value: store_root,
+            leaf: ics23::tendermint_spec().leaf_spec,
+            path: vec![],
+        };
+        let root = calculate_existence_root(&proof).unwrap();
+
+        (
+            CommitmentProof {
+                proof: Some(Proof::Exist(proof)),
+            },
+            root.into(),
+        )
+    }
+
+    fn merkle_path(key: &str) -> MerklePath {
+        MerklePath {
+            key_path: vec![STORE_KEY.to_string(), key.to_string()],
+        }
+    }
+
+    #[test]
+    fn verify_membership() {
+        let key = "connections/connection-0";
+        let value = b"connection end".to_vec();
+
+        let leaf = iavl_leaf(key, &value);
+        let (store_proof, root) = store_proof(calculate_existence_root(&leaf).unwrap());
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Exist(leaf)),
+                },
+                store_proof,
+            ],
+        };
+        let specs = ProofSpecs::cosmos();
+
+        assert!(proof
+            .verify_membership(&specs, &root, &merkle_path(key), value.clone(), 0)
+            .is_ok());
+        assert!(proof
+            .verify_membership(&specs, &root, &merkle_path(key), b"other".to_vec(), 0)
+            .is_err());
+        assert!(proof
+            .verify_membership(
+                &specs,
+                &root,
+                &merkle_path("connections/connection-1"),
+                value,
+                0
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn verify_non_membership() {
+        let key = "receipts/ports/transfer/channels/channel-0/sequences/1";
+
+        let left = iavl_leaf("acks/ports/transfer/channels/channel-0/sequences/1", &[1]);
+        let (store_proof, root) = store_proof(calculate_existence_root(&left).unwrap());
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(Proof::Nonexist(NonExistenceProof {
+                        key: key.as_bytes().to_vec(),
+                        left: Some(left),
+                        right: None,
+                    })),
+                },
+                store_proof,
+            ],
+        };
+        let specs = ProofSpecs::cosmos();
+
+        assert!(proof
+            .verify_non_membership(&specs, &root, &merkle_path(key))
+            .is_ok());
+        assert!(proof
+            .verify_non_membership(
+                &specs,
+                &CommitmentRoot::from(vec![0; 32]),
+                &merkle_path(key)
+            )
+            .is_err());
+    }
+}
@@ -9,6 +9,7 @@ use ibc_proto::ics23::ProofSpec as ProtoProofSpec;
 /// Additionally, this type also aids in the conversion from `ProofSpec` types from crate `ics23`
 /// into proof specifications as represented in the `ibc_proto` type; see the
 /// `From` trait(s) below.
+#[derive(Clone, Debug, PartialEq)]
 pub struct ProofSpecs {
     specs: Vec<ProofSpec>,
 }
@@ -23,6 +24,20 @@ impl ProofSpecs {
             ],
         }
     }
+
+    /// Returns the specification for proofs of a store committed into a simple Merkle tree, whose
+    /// root is in turn committed into another simple Merkle tree, e.g., as done by the mock host
+    /// chain (cf. `MockContext::with_merkle_store`)
+    pub fn simple_merkle() -> Self {
+        Self {
+            specs: vec![ics23::tendermint_spec(), ics23::tendermint_spec()],
+        }
+    }
+
+    /// Returns the proof specifications, ordered from the lowest subtree to the root.
+    pub fn specs(&self) -> &[ProofSpec] {
+        &self.specs
+    }
 }
 
 /// Converts from the domain type (which is represented as a vector of `ics23::ProofSpec`
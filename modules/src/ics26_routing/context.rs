@@ -3,6 +3,7 @@ use crate::ics02_client::context::{ClientKeeper, ClientReader};
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
 use crate::ics05_port::context::PortReader;
+use crate::ics26_routing::module::Router;
 
 /// This trait captures all the functional dependencies (i.e., context) which the ICS26 module
 /// requires to be able to dispatch and process IBC messages. In other words, this is the
@@ -18,4 +19,6 @@ pub trait Ics26Context:
     + Ics20Context
     + Clone
 {
+    /// Returns the router which maps ports to the application modules bound to them.
+    fn router(&self) -> &Router<Self>;
 }
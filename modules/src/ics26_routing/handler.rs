@@ -3,15 +3,24 @@ use tendermint_proto::Protobuf;
 
 use crate::application::ics20_fungible_token_transfer::msgs::transfer;
 use crate::application::ics20_fungible_token_transfer::relay_application_logic::send_transfer::send_transfer as ics20_msg_dispatcher;
+use crate::handler::{HandlerOutput, HandlerOutputBuilder};
+use crate::ics02_client::client_state::AnyClientState;
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::handler::dispatch as ics2_msg_dispatcher;
-use crate::ics02_client::msgs::{create_client, update_client, upgrade_client, ClientMsg};
+use crate::ics02_client::msgs::{
+    create_client, misbehavior, update_client, upgrade_client, ClientMsg,
+};
 use crate::ics03_connection::handler::dispatch as ics3_msg_dispatcher;
 use crate::ics03_connection::msgs::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try, ConnectionMsg,
 };
+use crate::ics04_channel::error::{Error as Ics4Error, Kind as Ics4Kind};
 use crate::ics04_channel::handler::channel_dispatch as ics4_msg_dispatcher;
 use crate::ics04_channel::handler::packet_dispatch as ics04_packet_msg_dispatcher;
-use crate::{events::IbcEvent, handler::HandlerOutput};
+use crate::ics04_channel::handler::write_acknowledgement::process as ics04_write_ack_dispatcher;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics05_port::capabilities::{Capability, CapabilityName};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 use crate::ics04_channel::msgs::{
     acknowledgement, chan_close_confirm, chan_close_init, chan_open_ack, chan_open_confirm,
@@ -19,24 +28,26 @@ use crate::ics04_channel::msgs::{
 };
 use crate::ics26_routing::context::Ics26Context;
 use crate::ics26_routing::error::{Error, Kind};
+use crate::ics26_routing::module::Module;
 use crate::ics26_routing::msgs::Ics26Envelope::{
     self, Ics20Msg, Ics2Msg, Ics3Msg, Ics4ChannelMsg, Ics4PacketMsg,
 };
 
 /// Mimics the DeliverTx ABCI interface, but a slightly lower level. No need for authentication
 /// info or signature checks here.
-/// Returns a vector of all events that got generated as a byproduct of processing `messages`.
+/// Returns the output of processing each of `messages`, in order, i.e., the log and the events
+/// generated by the message, along with its result.
 ///
 /// See <https://github.com/cosmos/cosmos-sdk/tree/master/docs/basics>
-pub fn deliver<Ctx>(ctx: &mut Ctx, messages: Vec<Any>) -> Result<Vec<IbcEvent>, Error>
+pub fn deliver<Ctx>(ctx: &mut Ctx, messages: Vec<Any>) -> Result<Vec<HandlerOutput<()>>, Error>
 where
     Ctx: Ics26Context,
 {
     // Create a clone, which will store each intermediary stage of applying txs.
     let mut ctx_interim = ctx.clone();
 
-    // A buffer for all the events, to be used as return value.
-    let mut res: Vec<IbcEvent> = vec![];
+    // A buffer for the outputs of all the messages, to be used as return value.
+    let mut res: Vec<HandlerOutput<()>> = vec![];
 
     for any_msg in messages {
         // Decode the proto message into a domain message, creating an ICS26 envelope.
@@ -58,6 +69,11 @@ where
                     .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
                 Ok(Ics2Msg(ClientMsg::UpgradeClient(domain_msg)))
             }
+            misbehavior::TYPE_URL => {
+                let domain_msg = misbehavior::MsgSubmitAnyMisbehaviour::decode_vec(&any_msg.value)
+                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+                Ok(Ics2Msg(ClientMsg::Misbehaviour(domain_msg)))
+            }
 
             // ICS03
             conn_open_init::TYPE_URL => {
@@ -150,10 +166,9 @@ where
             _ => Err(Kind::UnknownMessageTypeUrl(any_msg.type_url)),
         }?;
 
-        // Process the envelope, and accumulate any events that were generated.
-        let mut output = dispatch(&mut ctx_interim, envelope)?;
-        // TODO: output.log and output.result are discarded
-        res.append(&mut output.events);
+        // Process the envelope, and accumulate its output.
+        let output = process(&mut ctx_interim, envelope)?;
+        res.push(output);
     }
 
     // No error has surfaced, so we now apply the changes permanently to the original context.
@@ -164,7 +179,24 @@ where
 /// Top-level ICS dispatch function. Routes incoming IBC messages to their corresponding module.
 /// Returns a handler output with empty result of type `HandlerOutput<()>` which contains the log
 /// and events produced after processing the input `msg`.
+///
+/// The message is processed against a clone of the context, which replaces `ctx` only once the
+/// IBC handlers and the callbacks of the application module all succeeded. Hence a failed message
+/// leaves `ctx` untouched, even if its failure surfaced after some results were stored.
 pub fn dispatch<Ctx>(ctx: &mut Ctx, msg: Ics26Envelope) -> Result<HandlerOutput<()>, Error>
+where
+    Ctx: Ics26Context,
+{
+    let mut ctx_interim = ctx.clone();
+    let output = process(&mut ctx_interim, msg)?;
+
+    *ctx = ctx_interim;
+    Ok(output)
+}
+
+/// Processes `msg` and applies its results to `ctx`, along with the state changes made by the
+/// application module. On failure, `ctx` may hold the results of the steps preceding it.
+fn process<Ctx>(ctx: &mut Ctx, msg: Ics26Envelope) -> Result<HandlerOutput<()>, Error>
 where
     Ctx: Ics26Context,
 {
@@ -188,6 +220,7 @@ where
                 ics3_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
             // Apply any results to the host chain store.
+            store_verifying_client_state(ctx, handler_output.result.client_state.clone())?;
             ctx.store_connection_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
@@ -198,13 +231,21 @@ where
         }
 
         Ics4ChannelMsg(msg) => {
-            let handler_output =
-                ics4_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            // Present the capability owned by the IBC handlers on behalf of the module.
+            let capability = channel_msg_capability(ctx, &msg)
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            let handler_output = ics4_msg_dispatcher(ctx, capability, msg.clone())
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            let module = ctx.router().get_route(&handler_output.result.port_id)?;
 
             // Apply any results to the host chain store.
-            ctx.store_channel_result(handler_output.result)
+            store_verifying_client_state(ctx, handler_output.result.client_state.clone())?;
+            ctx.store_channel_result(handler_output.result.clone())
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
+            // Hand the handshake step over to the module bound to the port.
+            channel_callback(ctx, module.as_ref(), &msg, &handler_output.result)?;
+
             HandlerOutput::builder()
                 .with_log(handler_output.log)
                 .with_events(handler_output.events)
@@ -216,7 +257,9 @@ where
                 ics20_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
             // Apply any results to the host chain store.
-            ctx.store_packet_result(handler_output.result)
+            ctx.store_transfer_result(handler_output.result.transfer_result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+            ctx.store_packet_result(handler_output.result.packet_result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
             HandlerOutput::builder()
@@ -226,23 +269,236 @@ where
         }
 
         Ics4PacketMsg(msg) => {
-            let handler_output = ics04_packet_msg_dispatcher(ctx, msg)
+            let (port_id, channel_id) = packet_msg_channel_end(&msg);
+
+            // Present the capability owned by the IBC handlers on behalf of the module.
+            let channel_cap = channel_capability(ctx, port_id, channel_id)
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            let handler_output = ics04_packet_msg_dispatcher(ctx, channel_cap, msg.clone())
                 .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            let module = ctx.router().get_route(port_id)?;
 
             // Apply any results to the host chain store.
+            store_verifying_client_state(ctx, handler_output.result.client_state().cloned())?;
             ctx.store_packet_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
-            HandlerOutput::builder()
+            let mut output = HandlerOutput::builder()
                 .with_log(handler_output.log)
-                .with_events(handler_output.events)
-                .with_result(())
+                .with_events(handler_output.events);
+
+            // Hand the packet over to the module bound to the port.
+            packet_callback(ctx, module.as_ref(), &msg, &mut output)?;
+
+            output.with_result(())
         }
     };
 
     Ok(output)
 }
 
+/// Stores the state of the client which verified the proofs of a message, if the verification
+/// updated it, e.g., solo machine clients consume a sequence with each proof.
+fn store_verifying_client_state<Ctx>(
+    ctx: &mut Ctx,
+    client_state: Option<(ClientId, AnyClientState)>,
+) -> Result<(), Error>
+where
+    Ctx: Ics26Context,
+{
+    if let Some((client_id, client_state)) = client_state {
+        if <Ctx as ClientReader>::client_state(ctx, &client_id).as_ref() != Some(&client_state) {
+            ctx.store_client_state(client_id, client_state)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the capability which the IBC handlers own for the channel handshake message `msg`,
+/// i.e., that of its port for the opening messages, and that of its channel otherwise.
+fn channel_msg_capability<'a, Ctx>(
+    ctx: &'a Ctx,
+    msg: &ChannelMsg,
+) -> Result<&'a Capability, Ics4Error>
+where
+    Ctx: Ics26Context,
+{
+    match msg {
+        ChannelMsg::ChannelOpenInit(msg) => port_capability(ctx, msg.port_id()),
+        ChannelMsg::ChannelOpenTry(msg) => port_capability(ctx, msg.port_id()),
+        ChannelMsg::ChannelOpenAck(msg) => channel_capability(ctx, msg.port_id(), msg.channel_id()),
+        ChannelMsg::ChannelOpenConfirm(msg) => {
+            channel_capability(ctx, msg.port_id(), msg.channel_id())
+        }
+        ChannelMsg::ChannelCloseInit(msg) => {
+            channel_capability(ctx, msg.port_id(), msg.channel_id())
+        }
+        ChannelMsg::ChannelCloseConfirm(msg) => {
+            channel_capability(ctx, msg.port_id(), msg.channel_id())
+        }
+    }
+}
+
+/// Returns the capability of port `port_id`, which the IBC handlers own.
+fn port_capability<'a, Ctx>(ctx: &'a Ctx, port_id: &PortId) -> Result<&'a Capability, Ics4Error>
+where
+    Ctx: Ics26Context,
+{
+    ctx.lookup_module_by_port(port_id)
+        .ok_or_else(|| Ics4Kind::NoPortCapability(port_id.clone()).into())
+}
+
+/// Returns the capability of the channel end (`port_id`, `channel_id`), which the IBC handlers
+/// own.
+fn channel_capability<'a, Ctx>(
+    ctx: &'a Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<&'a Capability, Ics4Error>
+where
+    Ctx: Ics26Context,
+{
+    ctx.lookup_module_by_channel(port_id, channel_id)
+        .ok_or_else(|| Ics4Kind::NoChannelCapability(port_id.clone(), channel_id.clone()).into())
+}
+
+/// Creates the capability of the channel end which the handshake step `result` allocated.
+fn new_channel_capability<Ctx>(ctx: &mut Ctx, result: &ChannelResult) -> Result<Capability, Error>
+where
+    Ctx: Ics26Context,
+{
+    ctx.new_capability(CapabilityName::channel(&result.port_id, &result.channel_id))
+        .map_err(|e| {
+            let e: Ics4Error = Ics4Kind::ChannelCapabilityCreationFailure(
+                result.port_id.clone(),
+                result.channel_id.clone(),
+            )
+            .context(e)
+            .into();
+            Kind::KeeperRaisedError.context(e).into()
+        })
+}
+
+/// Calls the callback of `module` matching the channel handshake message `msg`, once the
+/// `result` of processing it was stored. The capability of a channel end allocated by the
+/// opening messages is created here, and handed over to the module.
+fn channel_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &ChannelMsg,
+    result: &ChannelResult,
+) -> Result<(), Error>
+where
+    Ctx: Ics26Context,
+{
+    let port_id = &result.port_id;
+    let channel_id = &result.channel_id;
+
+    let callback_result = match msg {
+        ChannelMsg::ChannelOpenInit(_) => {
+            let channel_cap = new_channel_capability(ctx, result)?;
+            module.on_chan_open_init(ctx, port_id, channel_id, &channel_cap, &result.channel_end)
+        }
+        ChannelMsg::ChannelOpenTry(msg) => {
+            // A reused channel end was initialized on this chain, its capability exists already.
+            let channel_cap = match result.channel_id_state {
+                ChannelIdState::Generated => Some(new_channel_capability(ctx, result)?),
+                ChannelIdState::Reused => None,
+            };
+            module.on_chan_open_try(
+                ctx,
+                port_id,
+                channel_id,
+                channel_cap.as_ref(),
+                &result.channel_end,
+                msg.counterparty_version(),
+            )
+        }
+        ChannelMsg::ChannelOpenAck(msg) => {
+            module.on_chan_open_ack(ctx, port_id, channel_id, msg.counterparty_version())
+        }
+        ChannelMsg::ChannelOpenConfirm(_) => module.on_chan_open_confirm(ctx, port_id, channel_id),
+        ChannelMsg::ChannelCloseInit(_) => module.on_chan_close_init(ctx, port_id, channel_id),
+        ChannelMsg::ChannelCloseConfirm(_) => {
+            module.on_chan_close_confirm(ctx, port_id, channel_id)
+        }
+    };
+
+    callback_result.map_err(|e| Kind::ModuleRaisedError(port_id.clone()).context(e).into())
+}
+
+/// Calls the callback of `module` matching the packet message `msg`, once the result of
+/// processing it was stored. The acknowledgement which the module returns for a received packet
+/// is written right away, and the corresponding events are added to `output`.
+fn packet_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &PacketMsg,
+    output: &mut HandlerOutputBuilder<()>,
+) -> Result<(), Error>
+where
+    Ctx: Ics26Context,
+{
+    let port_id = packet_msg_channel_end(msg).0.clone();
+
+    let callback_result = match msg {
+        PacketMsg::RecvPacket(msg) => {
+            let ack = match module.on_recv_packet(ctx, &msg.packet, &msg.signer) {
+                Some(ack) => ack,
+                // The module will write the acknowledgement asynchronously.
+                None => return Ok(()),
+            };
+
+            // The routing module writes the acknowledgement on behalf of the module, which owns
+            // the destination channel.
+            let channel_cap = channel_capability(
+                ctx,
+                &msg.packet.destination_port,
+                &msg.packet.destination_channel,
+            )
+            .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            let handler_output =
+                ics04_write_ack_dispatcher(ctx, channel_cap, msg.packet.clone(), ack)
+                    .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+            ctx.store_packet_result(handler_output.result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+            for log in handler_output.log {
+                output.log(log);
+            }
+            for event in handler_output.events {
+                output.emit(event);
+            }
+
+            Ok(())
+        }
+        PacketMsg::AckPacket(msg) => {
+            module.on_acknowledgement_packet(ctx, &msg.packet, msg.acknowledgement(), &msg.signer)
+        }
+        PacketMsg::ToPacket(msg) => module.on_timeout_packet(ctx, &msg.packet, &msg.signer),
+        PacketMsg::ToClosePacket(msg) => module.on_timeout_packet(ctx, &msg.packet, &msg.signer),
+    };
+
+    callback_result.map_err(|e| Kind::ModuleRaisedError(port_id).context(e).into())
+}
+
+/// Returns the channel end on this chain of the packet message `msg`, whose port is that of the
+/// module which processes it: the destination end for received packets, the source end otherwise.
+fn packet_msg_channel_end(msg: &PacketMsg) -> (&PortId, &ChannelId) {
+    match msg {
+        PacketMsg::RecvPacket(msg) => (
+            &msg.packet.destination_port,
+            &msg.packet.destination_channel,
+        ),
+        PacketMsg::AckPacket(msg) => (&msg.packet.source_port, &msg.packet.source_channel),
+        PacketMsg::ToPacket(msg) => (&msg.packet.source_port, &msg.packet.source_channel),
+        PacketMsg::ToClosePacket(msg) => (&msg.packet.source_port, &msg.packet.source_channel),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
@@ -253,6 +509,7 @@ mod tests {
     use crate::ics02_client::client_state::AnyClientState;
     use crate::{
         application::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_msg_transfer,
+        application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData,
         ics23_commitment::commitment::test_util::get_dummy_merkle_proof,
     };
 
@@ -279,13 +536,22 @@ mod tests {
         ChannelMsg, PacketMsg,
     };
 
-    use crate::ics24_host::identifier::ConnectionId;
-    use crate::ics26_routing::handler::dispatch;
+    use crate::application::ics20_fungible_token_transfer::context::BankReader;
+    use crate::application::ics20_fungible_token_transfer::module::{self as ics20, Ics20Module};
+    use crate::application::ics20_fungible_token_transfer::packet::Acknowledgement;
+    use crate::ics03_connection::connection::{ConnectionEnd, State as ConnectionState};
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::Order;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ChannelId, ConnectionId};
+    use crate::ics26_routing::handler::{deliver, dispatch};
     use crate::ics26_routing::msgs::Ics26Envelope;
     use crate::mock::client_state::{MockClientState, MockConsensusState};
     use crate::mock::context::MockContext;
     use crate::mock::header::MockHeader;
     use crate::test_utils::get_dummy_account_id;
+    use crate::tx_msg::Msg;
     use crate::Height;
 
     #[test]
@@ -313,11 +579,14 @@ mod tests {
         let upgrade_client_height_second = Height::new(1, 1);
 
         // We reuse this same context across all tests. Nothing in particular needs parametrizing.
-        let mut ctx = MockContext::default();
+        // The sender needs enough funds to escrow the tokens of both transfers.
+        let mut ctx = MockContext::default().with_balance(default_signer.clone(), "uatom", 20);
 
         let create_client_msg = MsgCreateAnyClient::new(
-            AnyClientState::from(MockClientState(MockHeader::new(start_client_height))),
-            AnyConsensusState::from(MockConsensusState(MockHeader::new(start_client_height))),
+            AnyClientState::from(MockClientState::new(MockHeader::new(start_client_height))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(
+                start_client_height,
+            ))),
             default_signer.clone(),
         )
         .unwrap();
@@ -349,18 +618,23 @@ mod tests {
         //
         // Channel handshake messages.
         //
-        let msg_chan_init =
+        // The channels have the version of the ICS20 module bound to their port.
+        let mut msg_chan_init =
             MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+        msg_chan_init.channel.version = ics20::VERSION.to_string();
 
         // The handler will fail to process this b/c the associated connection does not exist
         let mut incorrect_msg_chan_init = msg_chan_init.clone();
         incorrect_msg_chan_init.channel.connection_hops = vec![ConnectionId::new(590)];
 
-        let msg_chan_try =
+        let mut msg_chan_try =
             MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try(client_height)).unwrap();
+        msg_chan_try.channel.version = ics20::VERSION.to_string();
+        msg_chan_try.counterparty_version = ics20::VERSION.to_string();
 
-        let msg_chan_ack =
+        let mut msg_chan_ack =
             MsgChannelOpenAck::try_from(get_dummy_raw_msg_chan_open_ack(client_height)).unwrap();
+        msg_chan_ack.counterparty_version = ics20::VERSION.to_string();
 
         let msg_chan_close_init =
             MsgChannelCloseInit::try_from(get_dummy_raw_msg_chan_close_init()).unwrap();
@@ -378,8 +652,16 @@ mod tests {
         msg_to_on_close.packet.sequence = 2.into();
         msg_to_on_close.packet.timeout_height = msg_transfer_two.timeout_height;
         msg_to_on_close.packet.timeout_timestamp = msg_transfer_two.timeout_timestamp;
+        msg_to_on_close.packet.data = FungibleTokenPacketData {
+            amount: 10,
+            denom: "uatom".to_string(),
+            receiver: msg_transfer_two.receiver.clone(),
+            sender: msg_transfer_two.sender.clone(),
+        }
+        .to_json();
 
         let msg_recv_packet = MsgRecvPacket::try_from(get_dummy_raw_msg_recv_packet(35)).unwrap();
+        let recv_packet = msg_recv_packet.packet.clone();
 
         // First, create a client..
         let res = dispatch(
@@ -394,7 +676,8 @@ mod tests {
             res
         );
 
-        ctx.add_port(msg_chan_init.port_id().clone());
+        ctx.add_route(msg_chan_init.port_id().clone(), Ics20Module)
+            .unwrap();
 
         // Figure out the ID of the client that was just created.
         let mut events = res.unwrap().events;
@@ -546,8 +829,10 @@ mod tests {
                 name: "Client upgrade successful".to_string(),
                 msg: Ics26Envelope::Ics2Msg(ClientMsg::UpgradeClient(MsgUpgradeAnyClient::new(
                     client_id.clone(),
-                    AnyClientState::Mock(MockClientState(MockHeader::new(upgrade_client_height))),
-                    AnyConsensusState::Mock(MockConsensusState(MockHeader::new(
+                    AnyClientState::Mock(MockClientState::new(MockHeader::new(
+                        upgrade_client_height,
+                    ))),
+                    AnyConsensusState::Mock(MockConsensusState::new(MockHeader::new(
                         upgrade_client_height,
                     ))),
                     get_dummy_merkle_proof(),
@@ -560,15 +845,15 @@ mod tests {
                 name: "Client upgrade un-successful".to_string(),
                 msg: Ics26Envelope::Ics2Msg(ClientMsg::UpgradeClient(MsgUpgradeAnyClient::new(
                     client_id,
-                    AnyClientState::Mock(MockClientState(MockHeader::new(
+                    AnyClientState::Mock(MockClientState::new(MockHeader::new(
                         upgrade_client_height_second,
                     ))),
-                    AnyConsensusState::Mock(MockConsensusState(MockHeader::new(
+                    AnyConsensusState::Mock(MockConsensusState::new(MockHeader::new(
                         upgrade_client_height_second,
                     ))),
                     get_dummy_merkle_proof(),
                     get_dummy_merkle_proof(),
-                    default_signer,
+                    default_signer.clone(),
                 ))),
                 want_pass: false,
             },
@@ -576,6 +861,7 @@ mod tests {
         .into_iter()
         .collect();
 
+        let mut events = vec![];
         for test in tests {
             let res = dispatch(&mut ctx, test.msg.clone());
 
@@ -587,6 +873,103 @@ mod tests {
                 test.msg,
                 res
             );
+
+            if let Ok(output) = res {
+                events.extend(output.events);
+            }
+        }
+
+        // The ICS20 module acknowledged the received packet, which does not carry valid ICS20
+        // data, with an error.
+        let written_ack = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::WriteAcknowledgement(write_ack) => Some(write_ack.ack.clone()),
+                _ => None,
+            })
+            .expect("the acknowledgement of the received packet was written");
+        assert!(!Acknowledgement::from_json(&written_ack)
+            .unwrap()
+            .is_success());
+
+        // The chain committed to this acknowledgement.
+        let ack = ChannelReader::get_packet_acknowledgement(
+            &ctx,
+            &(
+                recv_packet.destination_port,
+                recv_packet.destination_channel,
+                recv_packet.sequence,
+            ),
+        );
+        assert_eq!(ack, Some(ctx.ack_commitment(written_ack)));
+
+        // The tokens of the second transfer were refunded upon the timeout on close.
+        assert_eq!(ctx.balance(&default_signer, "uatom"), 10);
+    }
+
+    #[test]
+    fn failed_callback_discards_state() {
+        let msg_conn_init =
+            MsgConnectionOpenInit::try_from(get_dummy_raw_msg_conn_open_init()).unwrap();
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            msg_conn_init.client_id().clone(),
+            msg_conn_init.counterparty().clone(),
+            get_compatible_versions(),
+            msg_conn_init.delay_period,
+        );
+
+        // The ICS20 module rejects ordered channels, which the connection supports.
+        let mut msg_chan_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+        msg_chan_init.channel.ordering = Order::Ordered;
+        let port_id = msg_chan_init.port_id().clone();
+
+        let mut ctx = MockContext::default().with_connection(ConnectionId::default(), conn_end);
+        ctx.add_route(port_id.clone(), Ics20Module).unwrap();
+
+        let msg = Ics26Envelope::Ics4ChannelMsg(ChannelMsg::ChannelOpenInit(msg_chan_init));
+        assert!(dispatch(&mut ctx, msg).is_err());
+
+        // Neither the channel end nor its capability were stored before the module failed.
+        let channel_id = ChannelId::new(0);
+        assert!(ctx
+            .channel_end(&(port_id.clone(), channel_id.clone()))
+            .is_none());
+        assert!(ctx
+            .lookup_module_by_channel(&port_id, &channel_id)
+            .is_none());
+        assert_eq!(ctx.channel_counter(), 0);
+    }
+
+    #[test]
+    fn deliver_returns_output_of_each_message() {
+        let signer = get_dummy_account_id();
+        let create_client_msg = |height| {
+            MsgCreateAnyClient::new(
+                MockClientState::new(MockHeader::new(height)).into(),
+                MockConsensusState::new(MockHeader::new(height)).into(),
+                signer.clone(),
+            )
+            .unwrap()
+            .to_any()
+        };
+
+        let mut ctx = MockContext::default();
+        let outputs = deliver(
+            &mut ctx,
+            vec![
+                create_client_msg(Height::new(0, 5)),
+                create_client_msg(Height::new(0, 6)),
+            ],
+        )
+        .unwrap();
+
+        // Each message has its own log and events, in the order the messages were delivered.
+        assert_eq!(outputs.len(), 2);
+        for (index, output) in outputs.iter().enumerate() {
+            assert!(output.log[0].ends_with(&format!("9999-mock-{}", index)));
+            assert!(matches!(output.events[..], [IbcEvent::CreateClient(_)]));
         }
     }
 }
@@ -3,4 +3,5 @@
 pub mod context;
 pub mod error;
 pub mod handler;
+pub mod module;
 pub mod msgs;
@@ -0,0 +1,169 @@
+//! The ICS26 callbacks through which the routing module hands channel handshake and packet
+//! messages over to the application module bound to their port, and the router which maps ports
+//! to those modules.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use anomaly::BoxError;
+
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::error::{Error, Kind};
+use crate::signer::Signer;
+
+/// An IBC application module, e.g., ICS20 fungible token transfer.
+///
+/// The routing module calls into the module bound to the port of a channel once the ICS4 handler
+/// accepted the corresponding message and its result was stored. Returning an error from any
+/// callback fails the whole message, and discards all the state changes it made. The host context
+/// `Ctx` is the one given to the routing module, so that the module can apply its own state
+/// changes.
+pub trait Module<Ctx>: Send + Sync {
+    /// Called once a channel end was initialized. The module should check that it supports the
+    /// ordering and version of `channel_end`, and take ownership of `channel_cap`.
+    fn on_chan_open_init(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_cap: &Capability,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), BoxError>;
+
+    /// Called once a channel end was created in response to the initialization of its
+    /// counterparty, which proposed the version `counterparty_version`. The module should take
+    /// ownership of `channel_cap`, which is `None` if the channel end was initialized on this
+    /// chain already, i.e., the module owns it since `on_chan_open_init`.
+    fn on_chan_open_try(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_cap: Option<&Capability>,
+        channel_end: &ChannelEnd,
+        counterparty_version: &str,
+    ) -> Result<(), BoxError>;
+
+    /// Called once the counterparty accepted the channel with version `counterparty_version`.
+    fn on_chan_open_ack(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &str,
+    ) -> Result<(), BoxError>;
+
+    /// Called once the channel is open on both ends.
+    fn on_chan_open_confirm(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), BoxError>;
+
+    /// Called once the closing of the channel was initiated on this end.
+    fn on_chan_close_init(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), BoxError>;
+
+    /// Called once the channel was closed in response to its counterparty closing.
+    fn on_chan_close_confirm(
+        &self,
+        ctx: &mut Ctx,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), BoxError>;
+
+    /// Processes a received packet. The returned acknowledgement, if any, is written right
+    /// away. A module which acknowledges asynchronously returns `None` and later writes the
+    /// acknowledgement itself. Failures of the application logic are meant to be reported in
+    /// the acknowledgement, not to fail the reception of the packet.
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet, relayer: &Signer) -> Option<Vec<u8>>;
+
+    /// Processes the acknowledgement written by the counterparty for a packet sent by this
+    /// module.
+    fn on_acknowledgement_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> Result<(), BoxError>;
+
+    /// Processes the timeout of a packet sent by this module, e.g., by reverting its effects.
+    fn on_timeout_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), BoxError>;
+}
+
+/// Maps each port to the module bound to it.
+pub struct Router<Ctx> {
+    routes: HashMap<PortId, Arc<dyn Module<Ctx>>>,
+}
+
+impl<Ctx> Router<Ctx> {
+    pub fn new() -> Self {
+        Self {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Routes the messages concerning port `port_id` to `module`. Fails if the port already has
+    /// a route.
+    pub fn add_route(
+        &mut self,
+        port_id: PortId,
+        module: impl Module<Ctx> + 'static,
+    ) -> Result<(), Error> {
+        if self.routes.contains_key(&port_id) {
+            return Err(Kind::RouteAlreadyExists(port_id).into());
+        }
+
+        self.routes.insert(port_id, Arc::new(module));
+        Ok(())
+    }
+
+    /// Returns the module bound to `port_id`.
+    pub fn get_route(&self, port_id: &PortId) -> Result<Arc<dyn Module<Ctx>>, Error> {
+        self.routes
+            .get(port_id)
+            .cloned()
+            .ok_or_else(|| Kind::RouteNotFound(port_id.clone()).into())
+    }
+
+    pub fn has_route(&self, port_id: &PortId) -> bool {
+        self.routes.contains_key(port_id)
+    }
+}
+
+impl<Ctx> Default for Router<Ctx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ctx> Clone for Router<Ctx> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+        }
+    }
+}
+
+impl<Ctx> fmt::Debug for Router<Ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router")
+            .field("ports", &self.routes.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
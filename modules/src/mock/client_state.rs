@@ -35,15 +35,33 @@ pub struct MockClientRecord {
 
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
 /// `ClientState` of ics07_tendermint/client_state.rs.
-// TODO: `MockClientState` should evolve, at the very least needs a `is_frozen` boolean field.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct MockClientState(pub MockHeader);
+pub struct MockClientState {
+    pub header: MockHeader,
+    /// The height at which misbehaviour was detected, if any. A frozen client cannot be updated.
+    pub frozen_height: Option<Height>,
+}
 
 impl Protobuf<RawMockClientState> for MockClientState {}
 
 impl MockClientState {
+    pub fn new(header: MockHeader) -> Self {
+        Self {
+            header,
+            frozen_height: None,
+        }
+    }
+
     pub fn latest_height(&self) -> Height {
-        (self.0).height
+        self.header.height
+    }
+
+    /// Returns a copy of this client state, frozen at `height`.
+    pub fn with_frozen_height(self, height: Height) -> Self {
+        Self {
+            frozen_height: Some(height),
+            ..self
+        }
     }
 
     pub fn refresh_time(&self) -> Option<Duration> {
@@ -64,17 +82,14 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = Error;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(MockClientState(raw.header.unwrap().try_into()?))
+        Ok(MockClientState::new(raw.header.unwrap().try_into()?))
     }
 }
 
 impl From<MockClientState> for RawMockClientState {
     fn from(value: MockClientState) -> Self {
         RawMockClientState {
-            header: Some(ibc_proto::ibc::mock::Header {
-                height: Some(value.0.height().into()),
-                timestamp: (value.0).timestamp.as_nanoseconds(),
-            }),
+            header: Some(value.header.into()),
         }
     }
 }
@@ -89,12 +104,11 @@ impl ClientState for MockClientState {
     }
 
     fn latest_height(&self) -> Height {
-        self.0.height()
+        self.header.height()
     }
 
     fn is_frozen(&self) -> bool {
-        // TODO
-        false
+        self.frozen_height.is_some()
     }
 
     fn wrap_any(self) -> AnyClientState {
@@ -104,16 +118,35 @@ impl ClientState for MockClientState {
 
 impl From<MockConsensusState> for MockClientState {
     fn from(cs: MockConsensusState) -> Self {
-        Self(cs.0)
+        Self::new(cs.header)
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
-pub struct MockConsensusState(pub MockHeader);
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MockConsensusState {
+    pub header: MockHeader,
+    pub root: CommitmentRoot,
+}
+
+/// The root of the consensus states whose header does not commit to the host store. Proofs
+/// verified against this root are not checked (cf. `MockClient`).
+pub const UNCOMMITTED_ROOT: [u8; 1] = [0];
 
 impl MockConsensusState {
+    pub fn new(header: MockHeader) -> Self {
+        let root = match header.root {
+            Some(root) => root.to_vec(),
+            None => UNCOMMITTED_ROOT.to_vec(),
+        };
+
+        MockConsensusState {
+            header,
+            root: CommitmentRoot::from(root),
+        }
+    }
+
     pub fn timestamp(&self) -> Timestamp {
-        (self.0).timestamp
+        self.header.timestamp
     }
 }
 
@@ -127,17 +160,14 @@ impl TryFrom<RawMockConsensusState> for MockConsensusState {
             .header
             .ok_or_else(|| ClientKind::InvalidRawConsensusState.context("missing header"))?;
 
-        Ok(Self(MockHeader::try_from(raw_header)?))
+        Ok(Self::new(MockHeader::try_from(raw_header)?))
     }
 }
 
 impl From<MockConsensusState> for RawMockConsensusState {
     fn from(value: MockConsensusState) -> Self {
         RawMockConsensusState {
-            header: Some(ibc_proto::ibc::mock::Header {
-                height: Some(value.0.height().into()),
-                timestamp: (value.0).timestamp.as_nanoseconds(),
-            }),
+            header: Some(value.header.into()),
         }
     }
 }
@@ -154,7 +184,7 @@ impl ConsensusState for MockConsensusState {
     }
 
     fn root(&self) -> &CommitmentRoot {
-        todo!()
+        &self.root
     }
 
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -6,8 +6,15 @@ use std::error::Error;
 
 use prost_types::Any;
 use sha2::Digest;
-
-use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+use tendermint_proto::Protobuf;
+
+use crate::application::ics20_fungible_token_transfer::context::{
+    BankKeeper, BankReader, Ics20Context,
+};
+use crate::application::ics20_fungible_token_transfer::denom::{Coin, DenomTrace};
+use crate::application::ics20_fungible_token_transfer::error::{
+    Error as Ics20Error, Kind as Ics20Kind,
+};
 use crate::events::IbcEvent;
 use crate::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
 use crate::ics02_client::client_state::AnyClientState;
@@ -20,25 +27,39 @@ use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
 use crate::ics03_connection::error::Error as Ics3Error;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
-use crate::ics04_channel::error::{Error as Ics4Error, Kind as Ics4Kind};
+use crate::ics04_channel::error::Error as Ics4Error;
 use crate::ics04_channel::packet::{Receipt, Sequence};
-use crate::ics05_port::capabilities::Capability;
-use crate::ics05_port::context::PortReader;
+use crate::ics05_port::capabilities::{
+    Capability, CapabilityName, CapabilityStore, ScopedCapabilityKeeper,
+};
+use crate::ics05_port::context::{CapabilityKeeper, CapabilityReader, PortReader};
+use crate::ics05_port::error::Error as Ics5Error;
+use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
 use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
 use crate::ics18_relayer::context::Ics18Context;
 use crate::ics18_relayer::error::{Error as Ics18Error, Kind as Ics18ErrorKind};
-use crate::ics23_commitment::commitment::CommitmentPrefix;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::ics23_commitment::merkle::MerkleProof;
 use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
 use crate::ics26_routing::context::Ics26Context;
+use crate::ics26_routing::error::Error as Ics26Error;
 use crate::ics26_routing::handler::{deliver, dispatch};
+use crate::ics26_routing::module::{Module, Router};
 use crate::ics26_routing::msgs::Ics26Envelope;
 use crate::mock::client_state::{MockClientRecord, MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
 use crate::mock::host::{HostBlock, HostType};
+use crate::mock::store::MerkleStore;
 use crate::signer::Signer;
 use crate::timestamp::Timestamp;
 use crate::Height;
 
+/// The key under which the IBC store root is committed in the root of the host chain, when the
+/// host commits its store (see `MockContext::with_merkle_store`).
+const IBC_STORE_KEY: &str = "ibc";
+
 /// A context implementing the dependencies necessary for testing any IBC module.
 #[derive(Clone, Debug)]
 pub struct MockContext {
@@ -95,16 +116,32 @@ pub struct MockContext {
     /// Tracks the sequence number for the next packet to be acknowledged.
     next_sequence_ack: HashMap<(PortId, ChannelId), Sequence>,
 
-    packet_acknowledgement: HashMap<(PortId, ChannelId, Sequence), String>,
+    packet_acknowledgement: HashMap<(PortId, ChannelId, Sequence), Vec<u8>>,
 
-    /// Maps ports to their capabilities
-    port_capabilities: HashMap<PortId, Capability>,
+    /// The capabilities of ports and channels, as owned by the IBC handlers.
+    capabilities: CapabilityStore,
+
+    /// The capabilities claimed by the ICS20 module.
+    transfer_capabilities: ScopedCapabilityKeeper,
 
     /// Constant-size commitments to packets data fields
-    packet_commitment: HashMap<(PortId, ChannelId, Sequence), String>,
+    packet_commitment: HashMap<(PortId, ChannelId, Sequence), Vec<u8>>,
 
     // Used by unordered channel
     packet_receipt: HashMap<(PortId, ChannelId, Sequence), Receipt>,
+
+    /// Token balances of each account, indexed by denomination.
+    balances: HashMap<Signer, HashMap<String, u64>>,
+
+    /// The ICS20 denomination traces, indexed by their hash.
+    denom_traces: HashMap<String, DenomTrace>,
+
+    /// Maps ports to the application modules bound to them.
+    router: Router<MockContext>,
+
+    /// The IBC store committed at each height of the history, if the host commits its store into
+    /// a Merkle tree (see `with_merkle_store`).
+    committed_stores: Option<HashMap<Height, MerkleStore>>,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -173,10 +210,15 @@ impl MockContext {
             next_sequence_send: Default::default(),
             next_sequence_recv: Default::default(),
             next_sequence_ack: Default::default(),
-            port_capabilities: Default::default(),
+            capabilities: Default::default(),
+            transfer_capabilities: Default::default(),
             packet_commitment: Default::default(),
             packet_receipt: Default::default(),
             packet_acknowledgement: Default::default(),
+            balances: Default::default(),
+            denom_traces: Default::default(),
+            router: Default::default(),
+            committed_stores: None,
             connection_ids_counter: 0,
             channel_ids_counter: 0,
         }
@@ -208,8 +250,8 @@ impl MockContext {
         let (client_state, consensus_state) = match client_type {
             // If it's a mock client, create the corresponding mock states.
             ClientType::Mock => (
-                Some(MockClientState(MockHeader::new(client_state_height)).into()),
-                MockConsensusState(MockHeader::new(cs_height)).into(),
+                Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
+                MockConsensusState::new(MockHeader::new(cs_height)).into(),
             ),
             // If it's a Tendermint client, we need TM states.
             ClientType::Tendermint => {
@@ -224,6 +266,30 @@ impl MockContext {
                 // Return the tuple.
                 (Some(client_state), consensus_state)
             }
+            // If it's a localhost client, it keeps the consensus states of this very host.
+            ClientType::Localhost => {
+                let client_state =
+                    LocalhostClientState::new(self.host_chain_id.clone(), client_state_height);
+                let consensus_state = ConnectionReader::host_consensus_state(&self, cs_height)
+                    .expect("the host has no consensus state at the requested height");
+
+                (
+                    Some(AnyClientState::Localhost(client_state)),
+                    consensus_state,
+                )
+            }
+            // A solo machine has a single consensus state, that of its current sequence.
+            ClientType::SoloMachine => {
+                let client_state =
+                    get_dummy_solomachine_client_state(client_state_height.revision_height);
+                let consensus_state =
+                    AnyConsensusState::SoloMachine(client_state.consensus_state.clone());
+
+                (
+                    Some(AnyClientState::SoloMachine(client_state)),
+                    consensus_state,
+                )
+            }
         };
         let consensus_states = vec![(cs_height, consensus_state)].into_iter().collect();
 
@@ -247,7 +313,20 @@ impl MockContext {
     }
 
     pub fn with_port_capability(mut self, port_id: PortId) -> Self {
-        self.port_capabilities.insert(port_id, Capability::new());
+        self.add_port(port_id);
+        self
+    }
+
+    /// Creates the capability of the channel end (`port_id`, `chan_id`), as if the channel was
+    /// opened by the ICS20 module, which claimed the capability.
+    pub fn with_channel_capability(mut self, port_id: PortId, chan_id: ChannelId) -> Self {
+        let name = CapabilityName::channel(&port_id, &chan_id);
+        if self.capabilities.get_capability(&name).is_none() {
+            let capability = self.capabilities.new_capability(name.clone()).unwrap();
+            self.transfer_capabilities
+                .claim_capability(name, &capability)
+                .unwrap();
+        }
         self
     }
 
@@ -334,7 +413,7 @@ impl MockContext {
         port_id: PortId,
         chan_id: ChannelId,
         seq: Sequence,
-        data: String,
+        data: Vec<u8>,
     ) -> Self {
         let mut packet_commitment = self.packet_commitment.clone();
         packet_commitment.insert((port_id, chan_id, seq), data);
@@ -344,6 +423,159 @@ impl MockContext {
         }
     }
 
+    /// Credits `amount` tokens of denomination `denom` to `account`.
+    pub fn with_balance(mut self, account: Signer, denom: &str, amount: u64) -> Self {
+        *self
+            .balances
+            .entry(account)
+            .or_default()
+            .entry(denom.to_string())
+            .or_default() += amount;
+        self
+    }
+
+    /// Makes the host chain commit its IBC store into a Merkle tree at every block, so that the
+    /// headers of the host carry a commitment root, and `query_proof` returns proofs which
+    /// `MockClient` verifies against this root. The current store is committed in the latest
+    /// block, further changes are committed with the next block. Only `HostType::Mock` hosts can
+    /// commit their store.
+    pub fn with_merkle_store(mut self) -> Self {
+        assert!(
+            matches!(self.host_chain_type, HostType::Mock),
+            "Only mock hosts can commit their store"
+        );
+
+        self.committed_stores = Some(Default::default());
+        self.commit_store();
+        self
+    }
+
+    /// Returns the proof that a value is stored under `path` in the IBC store of the host, as
+    /// committed in the block at `height`, or the proof of its absence. Returns `None` if the
+    /// host does not commit its store (see `with_merkle_store`), or if the block at `height` does
+    /// not exist.
+    pub fn query_proof(&self, path: &Path, height: Height) -> Option<CommitmentProofBytes> {
+        let store = self.committed_stores.as_ref()?.get(&height)?;
+
+        let proof = MerkleProof {
+            proofs: vec![
+                store.prove(&path.to_string()),
+                root_store(store).prove(IBC_STORE_KEY),
+            ],
+        };
+
+        Some(proof.into())
+    }
+
+    /// Commits the current IBC store in the latest block of the history, if the host commits its
+    /// store, and prunes the stores committed in blocks no longer in the history.
+    fn commit_store(&mut self) {
+        let store = self.ibc_store();
+        let oldest_height = self.history.first().map(HostBlock::height);
+
+        let committed_stores = match self.committed_stores.as_mut() {
+            Some(committed_stores) => committed_stores,
+            None => return,
+        };
+
+        if let Some(HostBlock::Mock(header)) = self.history.last_mut() {
+            let root = root_store(&store).root();
+            let mut root_bytes = [0; 32];
+            root_bytes.copy_from_slice(&root);
+            header.root = Some(root_bytes);
+
+            committed_stores.insert(header.height(), store);
+        }
+
+        if let Some(oldest_height) = oldest_height {
+            committed_stores.retain(|height, _| *height >= oldest_height);
+        }
+    }
+
+    /// Builds the IBC store of the host, laid out as specified by ICS24.
+    fn ibc_store(&self) -> MerkleStore {
+        let mut store = MerkleStore::default();
+
+        for (client_id, record) in self.clients.iter() {
+            if let Some(client_state) = &record.client_state {
+                store.set(
+                    Path::ClientState(client_id.clone()).to_string(),
+                    client_state.encode_vec().unwrap(),
+                );
+            }
+            for (height, consensus_state) in record.consensus_states.iter() {
+                let path = Path::ClientConsensusState {
+                    client_id: client_id.clone(),
+                    epoch: height.revision_number,
+                    height: height.revision_height,
+                };
+                store.set(path.to_string(), consensus_state.encode_vec().unwrap());
+            }
+        }
+
+        for (connection_id, connection_end) in self.connections.iter() {
+            store.set(
+                Path::Connections(connection_id.clone()).to_string(),
+                connection_end.encode_vec().unwrap(),
+            );
+        }
+
+        for ((port_id, channel_id), channel_end) in self.channels.iter() {
+            store.set(
+                Path::ChannelEnds(port_id.clone(), channel_id.clone()).to_string(),
+                channel_end.encode_vec().unwrap(),
+            );
+        }
+
+        // Sequences are stored as big-endian encoded `u64`s.
+        let sequences = self
+            .next_sequence_send
+            .iter()
+            .map(|((p, c), seq)| (Path::SeqSends(p.clone(), c.clone()), seq))
+            .chain(
+                self.next_sequence_recv
+                    .iter()
+                    .map(|((p, c), seq)| (Path::SeqRecvs(p.clone(), c.clone()), seq)),
+            )
+            .chain(
+                self.next_sequence_ack
+                    .iter()
+                    .map(|((p, c), seq)| (Path::SeqAcks(p.clone(), c.clone()), seq)),
+            );
+        for (path, seq) in sequences {
+            store.set(path.to_string(), u64::from(*seq).to_be_bytes().to_vec());
+        }
+
+        for ((port_id, channel_id, sequence), commitment) in self.packet_commitment.iter() {
+            let path = Path::Commitments {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: *sequence,
+            };
+            store.set(path.to_string(), commitment.clone());
+        }
+
+        for (port_id, channel_id, sequence) in self.packet_receipt.keys() {
+            let path = Path::Receipts {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: *sequence,
+            };
+            store.set(path.to_string(), vec![1]);
+        }
+
+        for ((port_id, channel_id, sequence), ack) in self.packet_acknowledgement.iter() {
+            let path = Path::Acks {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: *sequence,
+            };
+            store.set(path.to_string(), ack.clone());
+        }
+
+        store
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     fn host_block(&self, target_height: Height) -> Option<&HostBlock> {
@@ -376,6 +608,8 @@ impl MockContext {
             self.history.push(new_block);
         }
         self.latest_height = self.latest_height.increment();
+
+        self.commit_store();
     }
 
     /// A datagram passes from the relayer to the IBC module (on host chain).
@@ -416,8 +650,24 @@ impl MockContext {
         Ok(())
     }
 
+    /// Binds the port `port_id`, i.e., creates its capability unless the port is already bound.
     pub fn add_port(&mut self, port_id: PortId) {
-        self.port_capabilities.insert(port_id, Capability::new());
+        let name = CapabilityName::port(&port_id);
+        if self.capabilities.get_capability(&name).is_none() {
+            self.capabilities.new_capability(name).unwrap();
+        }
+    }
+
+    /// Binds the port `port_id` to `module`, which will be called back on the channel handshake
+    /// steps and packets concerning the port.
+    pub fn add_route(
+        &mut self,
+        port_id: PortId,
+        module: impl Module<MockContext> + 'static,
+    ) -> Result<(), Ics26Error> {
+        self.router.add_route(port_id.clone(), module)?;
+        self.add_port(port_id);
+        Ok(())
     }
 
     pub fn consensus_states(&self, client_id: &ClientId) -> Vec<AnyConsensusStateWithHeight> {
@@ -432,17 +682,97 @@ impl MockContext {
     }
 }
 
-impl Ics26Context for MockContext {}
+/// Returns the store committing to the root of the IBC `store`, whose root is the root of the
+/// host chain.
+fn root_store(store: &MerkleStore) -> MerkleStore {
+    let mut root_store = MerkleStore::default();
+    root_store.set(IBC_STORE_KEY.to_string(), store.root());
+    root_store
+}
 
-impl Ics20Context for MockContext {}
+impl Ics26Context for MockContext {
+    fn router(&self) -> &Router<Self> {
+        &self.router
+    }
+}
 
-impl PortReader for MockContext {
-    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability> {
-        self.port_capabilities.get(port_id).cloned()
+impl Ics20Context for MockContext {
+    fn capability_keeper(&self) -> &ScopedCapabilityKeeper {
+        &self.transfer_capabilities
+    }
+
+    fn capability_keeper_mut(&mut self) -> &mut ScopedCapabilityKeeper {
+        &mut self.transfer_capabilities
+    }
+
+    fn get_channel_escrow_address(&self, port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        Signer::new(format!("escrow/{}/{}", port_id, channel_id))
+    }
+
+    fn get_denom_trace(&self, hash: &str) -> Option<DenomTrace> {
+        self.denom_traces.get(hash).cloned()
     }
 
-    fn authenticate(&self, _cap: &Capability, _port_id: &PortId) -> bool {
-        true
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Ics20Error> {
+        self.denom_traces.insert(denom_trace.hash(), denom_trace);
+        Ok(())
+    }
+}
+
+impl BankReader for MockContext {
+    fn balance(&self, account: &Signer, denom: &str) -> u64 {
+        self.balances
+            .get(account)
+            .and_then(|balances| balances.get(denom))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl BankKeeper for MockContext {
+    fn send_coins(&mut self, from: &Signer, to: &Signer, coin: &Coin) -> Result<(), Ics20Error> {
+        self.burn_coins(from, coin)?;
+        self.mint_coins(to, coin)
+    }
+
+    fn mint_coins(&mut self, account: &Signer, coin: &Coin) -> Result<(), Ics20Error> {
+        *self
+            .balances
+            .entry(account.clone())
+            .or_default()
+            .entry(coin.denom.clone())
+            .or_default() += coin.amount;
+        Ok(())
+    }
+
+    fn burn_coins(&mut self, account: &Signer, coin: &Coin) -> Result<(), Ics20Error> {
+        let balance = self
+            .balances
+            .get_mut(account)
+            .and_then(|balances| balances.get_mut(&coin.denom))
+            .filter(|balance| **balance >= coin.amount)
+            .ok_or_else(|| Ics20Kind::InsufficientFunds(account.clone(), coin.clone()))?;
+
+        *balance -= coin.amount;
+        Ok(())
+    }
+}
+
+impl CapabilityReader for MockContext {
+    fn authenticate_capability(&self, name: &CapabilityName, capability: &Capability) -> bool {
+        self.capabilities.authenticate_capability(name, capability)
+    }
+}
+
+impl CapabilityKeeper for MockContext {
+    fn new_capability(&mut self, name: CapabilityName) -> Result<Capability, Ics5Error> {
+        self.capabilities.new_capability(name)
+    }
+}
+
+impl PortReader for MockContext {
+    fn lookup_capability(&self, name: &CapabilityName) -> Option<&Capability> {
+        self.capabilities.get_capability(name)
     }
 }
 
@@ -471,20 +801,6 @@ impl ChannelReader for MockContext {
         ClientReader::consensus_state(self, client_id, height)
     }
 
-    fn authenticated_capability(&self, port_id: &PortId) -> Result<Capability, Ics4Error> {
-        let cap = PortReader::lookup_module_by_port(self, port_id);
-        match cap {
-            Some(key) => {
-                if !PortReader::authenticate(self, &key, port_id) {
-                    Err(Ics4Kind::InvalidPortCapability.into())
-                } else {
-                    Ok(key)
-                }
-            }
-            None => Err(Ics4Kind::NoPortCapability(port_id.clone()).into()),
-        }
-    }
-
     fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
         self.next_sequence_send.get(port_channel_id).cloned()
     }
@@ -497,7 +813,7 @@ impl ChannelReader for MockContext {
         self.next_sequence_ack.get(port_channel_id).cloned()
     }
 
-    fn get_packet_commitment(&self, key: &(PortId, ChannelId, Sequence)) -> Option<String> {
+    fn get_packet_commitment(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Vec<u8>> {
         self.packet_commitment.get(key).cloned()
     }
 
@@ -505,13 +821,12 @@ impl ChannelReader for MockContext {
         self.packet_receipt.get(key).cloned()
     }
 
-    fn get_packet_acknowledgement(&self, key: &(PortId, ChannelId, Sequence)) -> Option<String> {
+    fn get_packet_acknowledgement(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Vec<u8>> {
         self.packet_acknowledgement.get(key).cloned()
     }
 
-    fn hash(&self, input: String) -> String {
-        let r = sha2::Sha256::digest(input.as_bytes());
-        format!("{:x}", r)
+    fn hash(&self, input: Vec<u8>) -> Vec<u8> {
+        sha2::Sha256::digest(&input).to_vec()
     }
 
     fn host_height(&self) -> Height {
@@ -535,9 +850,9 @@ impl ChannelKeeper for MockContext {
         timeout_height: Height,
         data: Vec<u8>,
     ) -> Result<(), Ics4Error> {
-        let input = format!("{:?},{:?},{:?}", timeout_timestamp, timeout_height, data);
-        self.packet_commitment
-            .insert(key, ChannelReader::hash(self, input));
+        let commitment =
+            ChannelReader::packet_commitment(self, data, timeout_height, timeout_timestamp);
+        self.packet_commitment.insert(key, commitment);
         Ok(())
     }
 
@@ -546,9 +861,8 @@ impl ChannelKeeper for MockContext {
         key: (PortId, ChannelId, Sequence),
         ack: Vec<u8>,
     ) -> Result<(), Ics4Error> {
-        let input = format!("{:?}", ack);
-        self.packet_acknowledgement
-            .insert(key, ChannelReader::hash(self, input));
+        let commitment = ChannelReader::ack_commitment(self, ack);
+        self.packet_acknowledgement.insert(key, commitment);
         Ok(())
     }
 
@@ -650,7 +964,10 @@ impl ConnectionReader for MockContext {
     }
 
     fn commitment_prefix(&self) -> CommitmentPrefix {
-        CommitmentPrefix::from(vec![])
+        match self.committed_stores {
+            Some(_) => CommitmentPrefix::from(IBC_STORE_KEY.as_bytes().to_vec()),
+            None => CommitmentPrefix::from(vec![]),
+        }
     }
 
     fn client_consensus_state(
@@ -723,6 +1040,10 @@ impl ClientReader for MockContext {
         }
     }
 
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState> {
+        ConnectionReader::host_consensus_state(self, height)
+    }
+
     fn client_counter(&self) -> u64 {
         self.client_ids_counter
     }
@@ -799,11 +1120,14 @@ impl Ics18Context for MockContext {
 
     fn send(&mut self, msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Ics18Error> {
         // Forward call to Ics26 delivery method.
-        let events =
+        let outputs =
             deliver(self, msgs).map_err(|e| Ics18ErrorKind::TransactionFailed.context(e))?;
 
         self.advance_host_chain_height(); // Advance chain height
-        Ok(events)
+        Ok(outputs
+            .into_iter()
+            .flat_map(|output| output.events)
+            .collect())
     }
 
     fn signer(&self) -> Signer {
@@ -813,7 +1137,10 @@ impl Ics18Context for MockContext {
 
 #[cfg(test)]
 mod tests {
-    use crate::ics24_host::identifier::ChainId;
+    use crate::application::ics20_fungible_token_transfer::context::Ics20Context;
+    use crate::ics05_port::capabilities::CapabilityName;
+    use crate::ics05_port::context::{CapabilityKeeper, CapabilityReader, PortReader};
+    use crate::ics24_host::identifier::{ChainId, ChannelId, PortId};
     use crate::mock::context::MockContext;
     use crate::mock::host::HostType;
     use crate::Height;
@@ -957,4 +1284,38 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_capabilities() {
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let port_name = CapabilityName::port(&port_id);
+        let channel_name = CapabilityName::channel(&port_id, &channel_id);
+
+        let mut ctx = MockContext::default()
+            .with_port_capability(port_id.clone())
+            .with_channel_capability(port_id.clone(), channel_id.clone());
+
+        // The IBC handlers own both capabilities.
+        let port_cap = ctx.lookup_module_by_port(&port_id).unwrap();
+        let channel_cap = ctx.lookup_module_by_channel(&port_id, &channel_id).unwrap();
+        assert!(ctx.authenticate(port_cap, &port_id));
+        assert!(ctx.authenticate_capability(&channel_name, channel_cap));
+        assert!(!ctx.authenticate_capability(&channel_name, port_cap));
+
+        // The ICS20 module claimed the capability of the channel only.
+        let claimed = ctx
+            .capability_keeper()
+            .get_capability(&channel_name)
+            .unwrap();
+        assert!(ctx.authenticate_capability(&channel_name, claimed));
+        assert!(ctx.capability_keeper().get_capability(&port_name).is_none());
+
+        // Names are unique, and new capabilities authenticate under their own name only.
+        assert!(ctx.new_capability(port_name).is_err());
+        let other_name = CapabilityName::channel(&port_id, &ChannelId::new(1));
+        let other_cap = ctx.new_capability(other_name.clone()).unwrap();
+        assert!(ctx.authenticate_capability(&other_name, &other_cap));
+        assert!(!ctx.authenticate_capability(&channel_name, &other_cap));
+    }
 }
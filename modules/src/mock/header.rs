@@ -18,6 +18,9 @@ use crate::Height;
 pub struct MockHeader {
     pub height: Height,
     pub timestamp: Timestamp,
+    /// The commitment root of the host store at this height, if the host commits its store
+    /// (cf. `MockContext::with_merkle_store`).
+    pub root: Option<[u8; 32]>,
 }
 
 impl Protobuf<RawMockHeader> for MockHeader {}
@@ -34,13 +37,24 @@ impl TryFrom<RawMockHeader> for MockHeader {
                 .map_err(|e| error::Kind::InvalidRawHeader.context(e))?,
             timestamp: Timestamp::from_nanoseconds(raw.timestamp)
                 .map_err(|_| error::Kind::InvalidPacketTimestamp)?,
+            root: if raw.root.is_empty() {
+                None
+            } else {
+                Some(raw.root.as_slice().try_into().map_err(|_| {
+                    error::Kind::InvalidRawHeader.context("invalid commitment root in header")
+                })?)
+            },
         })
     }
 }
 
 impl From<MockHeader> for RawMockHeader {
     fn from(value: MockHeader) -> Self {
-        value.into()
+        RawMockHeader {
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.as_nanoseconds(),
+            root: value.root.map(|root| root.to_vec()).unwrap_or_default(),
+        }
     }
 }
 
@@ -52,6 +66,7 @@ impl MockHeader {
         Self {
             height,
             timestamp: Default::default(),
+            root: None,
         }
     }
 }
@@ -78,6 +93,6 @@ impl Header for MockHeader {
 
 impl From<MockHeader> for AnyConsensusState {
     fn from(h: MockHeader) -> Self {
-        AnyConsensusState::Mock(MockConsensusState(h))
+        AnyConsensusState::Mock(MockConsensusState::new(h))
     }
 }
@@ -52,6 +52,7 @@ impl HostBlock {
             HostType::Mock => HostBlock::Mock(MockHeader {
                 height: Height::new(chain_id.version(), height),
                 timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+                root: None,
             }),
             HostType::SyntheticTendermint => {
                 HostBlock::SyntheticTendermint(Box::new(Self::generate_tm_block(chain_id, height)))
@@ -31,6 +31,12 @@ impl crate::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
     }
 }
 
+impl From<Misbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self::Mock(misbehaviour)
+    }
+}
+
 impl Protobuf<RawMisbehaviour> for Misbehaviour {}
 
 impl TryFrom<RawMisbehaviour> for Misbehaviour {
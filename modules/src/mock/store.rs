@@ -0,0 +1,213 @@
+//! A key-value store committed into a Merkle tree, used by the context mock to produce proofs.
+
+use std::collections::BTreeMap;
+
+use ics23::commitment_proof::Proof;
+use ics23::{CommitmentProof, ExistenceProof, HashOp, InnerOp, NonExistenceProof};
+use sha2::{Digest, Sha256};
+
+/// A key-value store committed into a simple Merkle tree, as built by the `crypto/merkle` package
+/// of Tendermint. The leaves of the tree are the entries of the store, sorted by key, hence the
+/// commitment root and the proofs produced by this store follow the ICS23 `tendermint_spec`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerkleStore {
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl MerkleStore {
+    pub fn get(&self, key: &str) -> Option<&Vec<u8>> {
+        self.entries.get(key)
+    }
+
+    pub fn set(&mut self, key: String, value: Vec<u8>) {
+        self.entries.insert(key, value);
+    }
+
+    /// Returns the root of the Merkle tree committing to the entries of this store.
+    pub fn root(&self) -> Vec<u8> {
+        root(&self.leaf_hashes())
+    }
+
+    /// Returns a proof that `key` is stored in this store if it is, or a proof of its absence
+    /// otherwise. The absence of a key cannot be proven in an empty store.
+    pub fn prove(&self, key: &str) -> CommitmentProof {
+        let keys: Vec<&String> = self.entries.keys().collect();
+        let proof = match keys.binary_search_by(|k| k.as_str().cmp(key)) {
+            Ok(index) => Proof::Exist(self.existence_proof(index)),
+            Err(index) => Proof::Nonexist(NonExistenceProof {
+                key: key.as_bytes().to_vec(),
+                left: index.checked_sub(1).map(|i| self.existence_proof(i)),
+                right: if index < keys.len() {
+                    Some(self.existence_proof(index))
+                } else {
+                    None
+                },
+            }),
+        };
+
+        CommitmentProof { proof: Some(proof) }
+    }
+
+    fn existence_proof(&self, index: usize) -> ExistenceProof {
+        let (key, value) = self
+            .entries
+            .iter()
+            .nth(index)
+            .expect("the index of an existing entry");
+
+        ExistenceProof {
+            key: key.as_bytes().to_vec(),
+            value: value.clone(),
+            leaf: ics23::tendermint_spec().leaf_spec,
+            path: inner_path(&self.leaf_hashes(), index),
+        }
+    }
+
+    fn leaf_hashes(&self) -> Vec<Vec<u8>> {
+        self.entries
+            .iter()
+            .map(|(key, value)| leaf_hash(key.as_bytes(), value))
+            .collect()
+    }
+}
+
+/// Hashes a leaf as specified by the leaf operation of the ICS23 `tendermint_spec`.
+fn leaf_hash(key: &[u8], value: &[u8]) -> Vec<u8> {
+    let value_hash = Sha256::digest(value);
+
+    let mut bytes = vec![0];
+    prost::encoding::encode_varint(key.len() as u64, &mut bytes);
+    bytes.extend_from_slice(key);
+    prost::encoding::encode_varint(value_hash.len() as u64, &mut bytes);
+    bytes.extend_from_slice(&value_hash);
+
+    Sha256::digest(&bytes).to_vec()
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(&[1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().to_vec()
+}
+
+/// Computes the root of the tree with the given leaf hashes.
+fn root(leaves: &[Vec<u8>]) -> Vec<u8> {
+    match leaves.len() {
+        0 => Sha256::digest(&[]).to_vec(),
+        1 => leaves[0].clone(),
+        n => {
+            let k = split_point(n);
+            inner_hash(&root(&leaves[..k]), &root(&leaves[k..]))
+        }
+    }
+}
+
+/// Computes the inner operations hashing the leaf at `index` up to the root of the tree, ordered
+/// from the leaf to the root.
+fn inner_path(leaves: &[Vec<u8>], index: usize) -> Vec<InnerOp> {
+    if leaves.len() <= 1 {
+        return vec![];
+    }
+
+    let k = split_point(leaves.len());
+    if index < k {
+        let mut path = inner_path(&leaves[..k], index);
+        path.push(InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: vec![1],
+            suffix: root(&leaves[k..]),
+        });
+        path
+    } else {
+        let mut path = inner_path(&leaves[k..], index - k);
+        let mut prefix = vec![1];
+        prefix.extend(root(&leaves[..k]));
+        path.push(InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix,
+            suffix: vec![],
+        });
+        path
+    }
+}
+
+/// Returns the largest power of two strictly smaller than `n`, for `n > 1`.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use crate::mock::store::MerkleStore;
+
+    fn store(keys: &[&str]) -> MerkleStore {
+        let mut store = MerkleStore::default();
+        for key in keys {
+            store.set(key.to_string(), format!("value of {}", key).into_bytes());
+        }
+        store
+    }
+
+    #[test]
+    fn prove_membership() {
+        let spec = ics23::tendermint_spec();
+
+        for size in 1..8 {
+            let keys: Vec<String> = (0..size).map(|i| format!("key{}", i)).collect();
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            let store = store(&keys);
+            let root = store.root();
+
+            for key in keys {
+                let proof = store.prove(key);
+                let value = store.get(key).unwrap();
+                assert!(
+                    ics23::verify_membership(&proof, &spec, &root, key.as_bytes(), value),
+                    "membership of {} in a store of size {}",
+                    key,
+                    size
+                );
+                assert!(!ics23::verify_membership(
+                    &proof,
+                    &spec,
+                    &root,
+                    key.as_bytes(),
+                    b"other value"
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn prove_non_membership() {
+        let spec = ics23::tendermint_spec();
+        let store = store(&["b", "d", "f", "h", "j"]);
+        let root = store.root();
+
+        for key in &["a", "c", "e", "g", "i", "k"] {
+            let proof = store.prove(key);
+            assert!(
+                ics23::verify_non_membership(&proof, &spec, &root, key.as_bytes()),
+                "non-membership of {}",
+                key
+            );
+        }
+
+        // The proof of a stored key does not prove its absence.
+        let proof = store.prove("d");
+        assert!(!ics23::verify_non_membership(
+            &proof,
+            &spec,
+            &root,
+            "d".as_bytes()
+        ));
+    }
+}
@@ -1,12 +1,13 @@
 use tendermint::abci::transaction::Hash;
 
 use crate::ics02_client::client_consensus::QueryClientEventRequest;
-use crate::ics04_channel::channel::QueryPacketEventDataRequest;
+use crate::ics04_channel::channel::{QueryPacketEventDataRequest, QueryPacketEventRangeRequest};
 
 /// Used for queries and not yet standardized in channel's query.proto
 #[derive(Clone, Debug)]
 pub enum QueryTxRequest {
     Packet(QueryPacketEventDataRequest),
+    PacketRange(QueryPacketEventRangeRequest),
     Client(QueryClientEventRequest),
     Transaction(QueryTxHash),
 }
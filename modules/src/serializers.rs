@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 
@@ -9,3 +10,13 @@ where
     let hex = Hex::upper_case().encode_to_string(data).unwrap();
     hex.serialize(serializer)
 }
+
+pub fn deser_hex_upper<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?;
+    Hex::upper_case()
+        .decode(hex.as_bytes())
+        .map_err(|e| D::Error::custom(e.to_string()))
+}
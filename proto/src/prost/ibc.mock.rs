@@ -4,6 +4,8 @@ pub struct Header {
     pub height: ::core::option::Option<super::core::client::v1::Height>,
     #[prost(uint64, tag = "2")]
     pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub root: ::prost::alloc::vec::Vec<u8>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientState {
@@ -1,5 +1,14 @@
 //! `keys` subcommand
+use std::path::Path;
+
 use abscissa_core::{Command, Help, Options, Runnable};
+use anomaly::BoxError;
+
+use ibc::ics24_host::identifier::ChainId;
+use ibc_relayer::{
+    config::ChainConfig,
+    keyring::{KeyRing, Passphrase, Store},
+};
 
 mod add;
 mod list;
@@ -24,3 +33,53 @@ pub enum KeysCmd {
     #[options(help = "restore a key to a configured chain using a mnemonic")]
     Restore(restore::KeyRestoreCmd),
 }
+
+/// Open the key store of the given chain.
+///
+/// The passphrase of an encrypted key store is read from `passphrase_file` if given,
+/// otherwise from the environment, and otherwise prompted for if the standard input is
+/// a terminal. When `confirm` is set, the prompted passphrase must be entered twice.
+fn open_keyring(
+    config: &ChainConfig,
+    passphrase_file: Option<&Path>,
+    confirm: bool,
+) -> Result<KeyRing, BoxError> {
+    if config.key_store_type != Store::File {
+        return Ok(KeyRing::new(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+        )?);
+    }
+
+    let passphrase = match passphrase_file {
+        Some(path) => Passphrase::from_file(path)?,
+        None => match Passphrase::from_env() {
+            Ok(passphrase) => passphrase,
+            Err(_) if atty::is(atty::Stream::Stdin) => prompt_passphrase(&config.id, confirm)?,
+            Err(e) => return Err(e.into()),
+        },
+    };
+
+    Ok(KeyRing::new_encrypted(
+        &config.account_prefix,
+        &config.id,
+        passphrase,
+    )?)
+}
+
+fn prompt_passphrase(chain_id: &ChainId, confirm: bool) -> Result<Passphrase, BoxError> {
+    let passphrase = rpassword::prompt_password_stderr(&format!(
+        "Enter the passphrase of the key store for chain {}: ",
+        chain_id
+    ))?;
+
+    if confirm {
+        let confirmation = rpassword::prompt_password_stderr("Confirm the passphrase: ")?;
+        if confirmation != passphrase {
+            return Err("the passphrases do not match".into());
+        }
+    }
+
+    Ok(Passphrase::new(passphrase))
+}
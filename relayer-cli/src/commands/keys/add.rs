@@ -10,7 +10,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{HDPath, KeyEntry, KeyRing, Store},
+    keyring::{HDPath, KeyEntry},
 };
 
 use crate::application::app_config;
@@ -32,10 +32,12 @@ pub struct KeysAddCmd {
 
     #[options(
         short = "p",
-        help = "derivation path for this key",
-        default = "m/44'/118'/0'/0/0"
+        help = "derivation path for this key (defaults to the `hd_path` defined in the config)"
     )]
-    hd_path: String,
+    hd_path: Option<String>,
+
+    #[options(help = "path to a file containing the passphrase of an encrypted key store")]
+    passphrase_file: Option<PathBuf>,
 }
 
 impl KeysAddCmd {
@@ -49,14 +51,20 @@ impl KeysAddCmd {
             .clone()
             .unwrap_or_else(|| chain_config.key_name.clone());
 
-        let hd_path = HDPath::from_str(&self.hd_path)
-            .map_err(|_| format!("invalid derivation path: {}", self.hd_path))?;
+        let hd_path = self
+            .hd_path
+            .clone()
+            .unwrap_or_else(|| chain_config.hd_path.clone());
+
+        let hd_path = HDPath::from_str(&hd_path)
+            .map_err(|_| format!("invalid derivation path: {}", hd_path))?;
 
         Ok(KeysAddOptions {
             config: chain_config.clone(),
             file: self.file.clone(),
             name,
             hd_path,
+            passphrase_file: self.passphrase_file.clone(),
         })
     }
 }
@@ -67,6 +75,7 @@ pub struct KeysAddOptions {
     pub config: ChainConfig,
     pub file: PathBuf,
     pub hd_path: HDPath,
+    pub passphrase_file: Option<PathBuf>,
 }
 
 impl Runnable for KeysAddCmd {
@@ -78,7 +87,13 @@ impl Runnable for KeysAddCmd {
             Ok(result) => result,
         };
 
-        let key = add_key(&opts.config, &opts.name, &opts.file, &opts.hd_path);
+        let key = add_key(
+            &opts.config,
+            &opts.name,
+            &opts.file,
+            &opts.hd_path,
+            opts.passphrase_file.as_deref(),
+        );
 
         match key {
             Ok(key) => Output::success_msg(format!(
@@ -96,8 +111,9 @@ pub fn add_key(
     key_name: &str,
     file: &Path,
     hd_path: &HDPath,
+    passphrase_file: Option<&Path>,
 ) -> Result<KeyEntry, BoxError> {
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+    let mut keyring = super::open_keyring(config, passphrase_file, true)?;
 
     let key_contents = fs::read_to_string(file).map_err(|_| "error reading the key file")?;
     let key = keyring.key_from_seed_file(&key_contents, hd_path)?;
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use abscissa_core::{Command, Options, Runnable};
 use anomaly::BoxError;
@@ -6,7 +7,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyEntry, KeyRing, Store},
+    keyring::KeyEntry,
 };
 
 use crate::conclude::Output;
@@ -16,6 +17,9 @@ use crate::{application::app_config, conclude::json};
 pub struct KeysListCmd {
     #[options(free, required, help = "identifier of the chain")]
     chain_id: ChainId,
+
+    #[options(help = "path to a file containing the passphrase of an encrypted key store")]
+    passphrase_file: Option<PathBuf>,
 }
 
 impl KeysListCmd {
@@ -26,6 +30,7 @@ impl KeysListCmd {
 
         Ok(KeysListOptions {
             chain_config: chain_config.clone(),
+            passphrase_file: self.passphrase_file.clone(),
         })
     }
 }
@@ -39,7 +44,7 @@ impl Runnable for KeysListCmd {
             Ok(result) => result,
         };
 
-        match list_keys(opts.chain_config) {
+        match list_keys(opts.chain_config, opts.passphrase_file.as_deref()) {
             Ok(keys) if json() => {
                 let keys = keys.into_iter().collect::<HashMap<_, _>>();
                 Output::success(keys).exit()
@@ -59,10 +64,14 @@ impl Runnable for KeysListCmd {
 #[derive(Clone, Debug)]
 pub struct KeysListOptions {
     pub chain_config: ChainConfig,
+    pub passphrase_file: Option<PathBuf>,
 }
 
-pub fn list_keys(config: ChainConfig) -> Result<Vec<(String, KeyEntry)>, BoxError> {
-    let keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+pub fn list_keys(
+    config: ChainConfig,
+    passphrase_file: Option<&Path>,
+) -> Result<Vec<(String, KeyEntry)>, BoxError> {
+    let keyring = super::open_keyring(&config, passphrase_file, false)?;
     let keys = keyring.keys()?;
     Ok(keys)
 }
@@ -1,3 +1,4 @@
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use abscissa_core::{Command, Options, Runnable};
@@ -6,7 +7,7 @@ use anomaly::BoxError;
 use ibc::ics24_host::identifier::ChainId;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{HDPath, KeyEntry, KeyRing, Store},
+    keyring::{HDPath, KeyEntry},
 };
 
 use crate::application::app_config;
@@ -28,10 +29,12 @@ pub struct KeyRestoreCmd {
 
     #[options(
         short = "p",
-        help = "derivation path for this key",
-        default = "m/44'/118'/0'/0/0"
+        help = "derivation path for this key (defaults to the `hd_path` defined in the config)"
     )]
-    hd_path: String,
+    hd_path: Option<String>,
+
+    #[options(help = "path to a file containing the passphrase of an encrypted key store")]
+    passphrase_file: Option<PathBuf>,
 }
 
 #[derive(Clone, Debug)]
@@ -40,6 +43,7 @@ pub struct KeysRestoreOptions {
     pub config: ChainConfig,
     pub hd_path: HDPath,
     pub key_name: String,
+    pub passphrase_file: Option<PathBuf>,
 }
 
 impl KeyRestoreCmd {
@@ -48,8 +52,13 @@ impl KeyRestoreCmd {
             .find_chain(&self.chain_id)
             .ok_or_else(|| format!("chain '{}' not found in configuration file", self.chain_id))?;
 
-        let hd_path = HDPath::from_str(&self.hd_path)
-            .map_err(|_| format!("invalid derivation path: {}", self.hd_path))?;
+        let hd_path = self
+            .hd_path
+            .clone()
+            .unwrap_or_else(|| chain_config.hd_path.clone());
+
+        let hd_path = HDPath::from_str(&hd_path)
+            .map_err(|_| format!("invalid derivation path: {}", hd_path))?;
 
         let key_name = self
             .name
@@ -61,6 +70,7 @@ impl KeyRestoreCmd {
             config: chain_config.clone(),
             hd_path,
             key_name,
+            passphrase_file: self.passphrase_file.clone(),
         })
     }
 }
@@ -74,7 +84,13 @@ impl Runnable for KeyRestoreCmd {
             Ok(result) => result,
         };
 
-        let key = restore_key(&opts.mnemonic, &opts.key_name, &opts.hd_path, &opts.config);
+        let key = restore_key(
+            &opts.mnemonic,
+            &opts.key_name,
+            &opts.hd_path,
+            &opts.config,
+            opts.passphrase_file.as_deref(),
+        );
 
         match key {
             Ok(key) => Output::success_msg(format!(
@@ -92,9 +108,10 @@ pub fn restore_key(
     key_name: &str,
     hdpath: &HDPath,
     config: &ChainConfig,
+    passphrase_file: Option<&Path>,
 ) -> Result<KeyEntry, BoxError> {
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
-    let key_entry = keyring.key_from_mnemonic(mnemonic, hdpath)?;
+    let mut keyring = super::open_keyring(config, passphrase_file, true)?;
+    let key_entry = keyring.key_from_mnemonic(mnemonic, hdpath, &config.address_type)?;
 
     keyring.add_key(key_name, key_entry.clone())?;
     Ok(key_entry)
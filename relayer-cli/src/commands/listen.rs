@@ -141,7 +141,8 @@ fn subscribe(
 ) -> Result<(EventMonitor, EventReceiver), BoxError> {
     let (mut event_monitor, rx, _) = EventMonitor::new(
         chain_config.id.clone(),
-        chain_config.websocket_addr.clone(),
+        chain_config.nodes(),
+        chain_config.event_source.clone(),
         rt,
     )
     .map_err(|e| format!("could not initialize event monitor: {}", e))?;
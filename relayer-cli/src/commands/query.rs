@@ -116,4 +116,8 @@ pub enum QueryPacketCmds {
     /// The `query packet unreceived-acks` subcommand
     #[options(help = "Query unreceived acknowledgments")]
     UnreceivedAcks(packet::QueryUnreceivedAcknowledgementCmd),
+
+    /// The `query packet pending` subcommand
+    #[options(help = "Query pending packets and acknowledgments on both ends of a channel")]
+    Pending(packet::QueryPendingPacketsCmd),
 }
@@ -5,15 +5,20 @@ use serde::Serialize;
 use subtle_encoding::{Encoding, Hex};
 use tokio::runtime::Runtime as TokioRuntime;
 
+use ibc::events::{IbcEvent, IbcEventType};
 use ibc::ics02_client::client_state::ClientState;
+use ibc::ics04_channel::channel::QueryPacketEventDataRequest;
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc::query::QueryTxRequest;
+use ibc::timestamp::Timestamp;
 use ibc::Height;
 use ibc_proto::ibc::core::channel::v1::{
     PacketState, QueryPacketAcknowledgementsRequest, QueryPacketCommitmentsRequest,
     QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
 };
 use ibc_relayer::chain::counterparty::channel_connection_client;
+use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::{runtime::ChainRuntime, CosmosSdkChain};
 
 use crate::conclude::Output;
@@ -502,3 +507,259 @@ impl Runnable for QueryUnreceivedAcknowledgementCmd {
         }
     }
 }
+
+/// One end of a channel, on which packets are sent
+struct ChannelSide {
+    chain: Box<dyn ChainHandle>,
+    port_id: PortId,
+    channel_id: ChannelId,
+}
+
+/// A packet sent on a channel which is not yet received on the counterparty chain
+#[derive(Debug, Serialize)]
+struct UnreceivedPacket {
+    sequence: u64,
+    /// The timeout height of the packet, unless its `SendPacket` event could not be found
+    timeout_height: Option<Height>,
+    /// The timeout timestamp of the packet, unless its `SendPacket` event could not be found
+    timeout_timestamp: Option<Timestamp>,
+    /// Whether the packet already timed out at the latest height of the counterparty chain
+    expired: Option<bool>,
+}
+
+/// The packets sent on one end of a channel which are not yet fully relayed
+#[derive(Debug, Serialize)]
+struct PendingPackets {
+    chain_id: ChainId,
+    port_id: PortId,
+    channel_id: ChannelId,
+    /// The packets sent which are not yet received on the counterparty chain
+    unreceived_packets: Vec<UnreceivedPacket>,
+    /// The sequences of the packets received on the counterparty chain,
+    /// whose acknowledgments are not yet received
+    unreceived_acks: Vec<u64>,
+}
+
+/// The pending packets sent on both ends of a channel
+#[derive(Debug, Serialize)]
+struct PendingPacketsReport {
+    src: PendingPackets,
+    dst: PendingPackets,
+}
+
+/// Collect the packets sent on the `src` end of a channel which are either not yet received
+/// on the `dst` end, or whose acknowledgments are not yet received back on the `src` end.
+fn pending_packets(src: &ChannelSide, dst: &ChannelSide) -> Result<PendingPackets, Error> {
+    let query_error = |e| Kind::Query.context(e);
+
+    let (commitments, commitments_height) = src
+        .chain
+        .query_packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: src.port_id.to_string(),
+            channel_id: src.channel_id.to_string(),
+            pagination: ibc_proto::cosmos::base::query::pagination::all(),
+        })
+        .map_err(query_error)?;
+
+    let unreceived = dst
+        .chain
+        .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+            port_id: dst.port_id.to_string(),
+            channel_id: dst.channel_id.to_string(),
+            packet_commitment_sequences: commitments.iter().map(|c| c.sequence).collect(),
+        })
+        .map_err(query_error)?;
+
+    let sent = if unreceived.is_empty() {
+        vec![]
+    } else {
+        src.chain
+            .query_txs(QueryTxRequest::Packet(QueryPacketEventDataRequest {
+                event_id: IbcEventType::SendPacket,
+                source_channel_id: src.channel_id.clone(),
+                source_port_id: src.port_id.clone(),
+                destination_channel_id: dst.channel_id.clone(),
+                destination_port_id: dst.port_id.clone(),
+                sequences: unreceived.iter().map(|s| Sequence::from(*s)).collect(),
+                height: commitments_height,
+            }))
+            .map_err(query_error)?
+    };
+
+    let dst_height = dst.chain.query_latest_height().map_err(query_error)?;
+
+    let unreceived_packets = unreceived
+        .into_iter()
+        .map(|sequence| {
+            let packet = sent.iter().find_map(|event| match event {
+                IbcEvent::SendPacket(send_packet)
+                    if u64::from(send_packet.packet.sequence) == sequence =>
+                {
+                    Some(&send_packet.packet)
+                }
+                _ => None,
+            });
+
+            UnreceivedPacket {
+                sequence,
+                timeout_height: packet.map(|p| p.timeout_height),
+                timeout_timestamp: packet.map(|p| p.timeout_timestamp),
+                expired: packet.map(|p| p.timed_out(dst_height)),
+            }
+        })
+        .collect();
+
+    let (acks, _) = dst
+        .chain
+        .query_packet_acknowledgements(QueryPacketAcknowledgementsRequest {
+            port_id: dst.port_id.to_string(),
+            channel_id: dst.channel_id.to_string(),
+            pagination: ibc_proto::cosmos::base::query::pagination::all(),
+        })
+        .map_err(query_error)?;
+
+    let unreceived_acks = src
+        .chain
+        .query_unreceived_acknowledgement(QueryUnreceivedAcksRequest {
+            port_id: src.port_id.to_string(),
+            channel_id: src.channel_id.to_string(),
+            packet_ack_sequences: acks.iter().map(|a| a.sequence).collect(),
+        })
+        .map_err(query_error)?;
+
+    Ok(PendingPackets {
+        chain_id: src.chain.id(),
+        port_id: src.port_id.clone(),
+        channel_id: src.channel_id.clone(),
+        unreceived_packets,
+        unreceived_acks,
+    })
+}
+
+/// This command does the following:
+/// 1. queries the chain to get its counterparty chain, channel and port identifiers
+/// 2. for the packets sent on each end of the channel, queries the sequences of the packets
+///    not yet received on the other end, along with their timeouts, and the sequences of the
+///    packets whose acknowledgments are not yet received back.
+#[derive(Clone, Command, Debug, Options)]
+pub struct QueryPendingPacketsCmd {
+    #[options(
+        free,
+        required,
+        help = "identifier of the chain at one end of the channel"
+    )]
+    chain_id: ChainId,
+
+    #[options(free, required, help = "port identifier")]
+    port_id: PortId,
+
+    #[options(free, required, help = "channel identifier")]
+    channel_id: ChannelId,
+}
+
+impl Runnable for QueryPendingPacketsCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        debug!("Options: {:?}", self);
+
+        let chain_config = match config.find_chain(&self.chain_id) {
+            None => {
+                return Output::error(format!(
+                    "chain '{}' not found in configuration file",
+                    self.chain_id
+                ))
+                .exit()
+            }
+            Some(chain_config) => chain_config,
+        };
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let chain = match ChainRuntime::<CosmosSdkChain>::spawn(chain_config.clone(), rt.clone()) {
+            Ok(chain) => chain,
+            Err(e) => {
+                return Output::error(format!(
+                    "error when spawning the chain runtime for {}: {}",
+                    chain_config.id, e,
+                ))
+                .exit();
+            }
+        };
+
+        let channel_connection_client =
+            match channel_connection_client(chain.as_ref(), &self.port_id, &self.channel_id) {
+                Ok(channel_connection_client) => channel_connection_client,
+                Err(e) => {
+                    return Output::error(format!(
+                        "error when getting channel/ connection for {} on {}: {}",
+                        self.channel_id,
+                        chain.id(),
+                        e,
+                    ))
+                    .exit();
+                }
+            };
+
+        let channel = channel_connection_client.channel;
+        let counterparty = channel.channel_end.counterparty().clone();
+        let counterparty_channel_id = match counterparty.channel_id {
+            Some(channel_id) => channel_id,
+            None => {
+                return Output::error(format!(
+                    "channel '{}' on chain '{}' has no counterparty channel",
+                    self.channel_id, self.chain_id
+                ))
+                .exit()
+            }
+        };
+
+        let counterparty_chain_id = channel_connection_client.client.client_state.chain_id();
+        let counterparty_chain_config = match config.find_chain(&counterparty_chain_id) {
+            None => {
+                return Output::error(format!(
+                    "counterparty chain '{}' for channel '{}' not found in configuration file",
+                    counterparty_chain_id, self.channel_id
+                ))
+                .exit()
+            }
+            Some(chain_config) => chain_config,
+        };
+
+        let counterparty_chain =
+            match ChainRuntime::<CosmosSdkChain>::spawn(counterparty_chain_config.clone(), rt) {
+                Ok(chain) => chain,
+                Err(e) => {
+                    return Output::error(format!(
+                        "error when spawning the chain runtime for {}: {}",
+                        counterparty_chain_config.id, e,
+                    ))
+                    .exit();
+                }
+            };
+
+        let src = ChannelSide {
+            chain,
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+        };
+
+        let dst = ChannelSide {
+            chain: counterparty_chain,
+            port_id: counterparty.port_id,
+            channel_id: counterparty_channel_id,
+        };
+
+        let report = pending_packets(&src, &dst).and_then(|src_pending| {
+            let dst_pending = pending_packets(&dst, &src)?;
+            Ok(PendingPacketsReport {
+                src: src_pending,
+                dst: dst_pending,
+            })
+        });
+
+        match report {
+            Ok(report) => Output::success(report).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
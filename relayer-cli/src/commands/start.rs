@@ -7,6 +7,8 @@ use crossbeam_channel::Sender;
 
 use ibc_relayer::config::reload::ConfigReload;
 use ibc_relayer::config::Config;
+#[cfg(feature = "telemetry")]
+use ibc_relayer::rest::RestApi;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, Supervisor};
 
 use crate::conclude::json;
@@ -21,23 +23,27 @@ impl Runnable for StartCmd {
         let config = (*app_config()).clone();
         let config = Arc::new(RwLock::new(config));
 
-        let (supervisor, tx_cmd) = make_supervisor(config.clone()).unwrap_or_else(|e| {
-            Output::error(format!("Hermes failed to start, last error: {}", e)).exit();
-            unreachable!()
-        });
+        let (supervisor, tx_cmd) = make_supervisor(config.clone());
 
-        match crate::config::config_path() {
+        let reload = match crate::config::config_path() {
             Some(config_path) => {
-                let reload = ConfigReload::new(config_path, config, tx_cmd.clone());
-                register_signals(reload, tx_cmd).unwrap_or_else(|e| {
+                let reload = ConfigReload::new(config_path, config.clone(), tx_cmd.clone());
+                register_signals(reload.clone(), tx_cmd.clone()).unwrap_or_else(|e| {
                     warn!("failed to install signal handler: {}", e);
                 });
+                Some(reload)
             }
             None => {
                 warn!("cannot figure out configuration path, skipping registration of signal handlers");
+                None
             }
         };
 
+        spawn_telemetry_server(config, tx_cmd, reload).unwrap_or_else(|e| {
+            Output::error(format!("Hermes failed to start, last error: {}", e)).exit();
+            unreachable!()
+        });
+
         info!("Hermes has started");
         match supervisor.run() {
             Ok(()) => Output::success_msg("done").exit(),
@@ -101,34 +107,55 @@ fn register_signals(reload: ConfigReload, tx_cmd: Sender<SupervisorCmd>) -> Resu
 }
 
 #[cfg(feature = "telemetry")]
-fn make_supervisor(
-    config: Arc<RwLock<Config>>,
-) -> Result<(Supervisor, Sender<SupervisorCmd>), Box<dyn Error + Send + Sync>> {
-    let state = ibc_telemetry::new_state();
+fn make_supervisor(config: Arc<RwLock<Config>>) -> (Supervisor, Sender<SupervisorCmd>) {
+    Supervisor::new(config, ibc_telemetry::new_state())
+}
+
+#[cfg(not(feature = "telemetry"))]
+fn make_supervisor(config: Arc<RwLock<Config>>) -> (Supervisor, Sender<SupervisorCmd>) {
+    Supervisor::new(config, ibc_relayer::telemetry::TelemetryDisabled)
+}
 
+/// Spawn the telemetry server if it is enabled in the configuration, serving the metrics
+/// along with the REST API of the supervisor listening on `tx_cmd`.
+#[cfg(feature = "telemetry")]
+fn spawn_telemetry_server(
+    config: Arc<RwLock<Config>>,
+    tx_cmd: Sender<SupervisorCmd>,
+    reload: Option<ConfigReload>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
     let telemetry = config.read().expect("poisoned lock").telemetry.clone();
-    if telemetry.enabled {
-        match ibc_telemetry::spawn((telemetry.host, telemetry.port), state.clone()) {
-            Ok((addr, _)) => {
-                info!(
-                    "telemetry service running, exposing metrics at {}/metrics",
-                    addr
-                );
-            }
-            Err(e) => {
-                error!("telemetry service failed to start: {}", e);
-                return Err(e);
-            }
-        }
+    if !telemetry.enabled {
+        return Ok(());
     }
 
-    Ok(Supervisor::new(config, state))
+    let api: Arc<dyn ibc_telemetry::Api> = Arc::new(RestApi::new(config, tx_cmd, reload));
+
+    match ibc_telemetry::spawn(
+        (telemetry.host, telemetry.port),
+        ibc_telemetry::new_state(),
+        Some(api),
+    ) {
+        Ok((addr, _)) => {
+            info!(
+                "telemetry service running, exposing metrics at {}/metrics",
+                addr
+            );
+            Ok(())
+        }
+        Err(e) => {
+            error!("telemetry service failed to start: {}", e);
+            Err(e)
+        }
+    }
 }
 
 #[cfg(not(feature = "telemetry"))]
-fn make_supervisor(
+fn spawn_telemetry_server(
     config: Arc<RwLock<Config>>,
-) -> Result<(Supervisor, Sender<SupervisorCmd>), Box<dyn Error + Send + Sync>> {
+    _tx_cmd: Sender<SupervisorCmd>,
+    _reload: Option<ConfigReload>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
     if config.read().expect("poisoned lock").telemetry.enabled {
         warn!(
             "telemetry enabled in the config but Hermes was built without telemetry support, \
@@ -136,6 +163,5 @@ fn make_supervisor(
         );
     }
 
-    let telemetry = ibc_relayer::telemetry::TelemetryDisabled;
-    Ok(Supervisor::new(config, telemetry))
+    Ok(())
 }
@@ -28,6 +28,15 @@ impl Runnable for TxRawPacketRecvCmd {
     fn run(&self) {
         let config = app_config();
 
+        if !config.relay_on_channel(&self.src_chain_id, &self.src_port_id, &self.src_channel_id) {
+            return Output::error(filtered_out(
+                &self.src_chain_id,
+                &self.src_port_id,
+                &self.src_channel_id,
+            ))
+            .exit();
+        }
+
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
             Err(e) => return Output::error(format!("{}", e)).exit(),
@@ -72,6 +81,15 @@ impl Runnable for TxRawPacketAckCmd {
     fn run(&self) {
         let config = app_config();
 
+        if !config.relay_on_channel(&self.src_chain_id, &self.src_port_id, &self.src_channel_id) {
+            return Output::error(filtered_out(
+                &self.src_chain_id,
+                &self.src_port_id,
+                &self.src_channel_id,
+            ))
+            .exit();
+        }
+
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
             Err(e) => return Output::error(format!("{}", e)).exit(),
@@ -96,3 +114,10 @@ impl Runnable for TxRawPacketAckCmd {
         }
     }
 }
+
+fn filtered_out(chain_id: &ChainId, port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!(
+        "channel {}/{} on chain {} is excluded by the packet filter policy of the chain",
+        port_id, channel_id, chain_id
+    )
+}
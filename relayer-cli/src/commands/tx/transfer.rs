@@ -1,5 +1,6 @@
 use abscissa_core::{config::Override, Command, FrameworkErrorKind, Options, Runnable};
 use anomaly::BoxError;
+use serde::Serialize;
 
 use ibc::{
     events::IbcEvent,
@@ -8,6 +9,7 @@ use ibc::{
     ics24_host::identifier::{ChainId, ChannelId, PortId},
 };
 use ibc_relayer::{
+    chain::FeesPaid,
     config::Config,
     transfer::{build_and_send_transfer_messages, TransferOptions},
 };
@@ -17,6 +19,13 @@ use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::{Error, Kind};
 use crate::prelude::*;
 
+/// The events of the transfers, and the fees paid on the source chain to send them
+#[derive(Debug, Serialize)]
+struct TransferResult {
+    events: Vec<IbcEvent>,
+    fees: FeesPaid,
+}
+
 #[derive(Clone, Command, Debug, Options)]
 pub struct TxIcs20MsgTransferCmd {
     #[options(free, required, help = "identifier of the destination chain")]
@@ -196,12 +205,20 @@ impl Runnable for TxIcs20MsgTransferCmd {
         }
 
         // Checks pass, build and send the tx
-        let res: Result<Vec<IbcEvent>, Error> =
+        let src_chain = chains.src.clone();
+        let res: Result<TransferResult, Error> =
             build_and_send_transfer_messages(chains.src, chains.dst, opts)
-                .map_err(|e| Kind::Tx.context(e).into());
+                .map_err(|e| Kind::Tx.context(e).into())
+                .and_then(|events| {
+                    let fees = src_chain
+                        .query_fees_paid()
+                        .map_err(|e| Kind::Query.context(e))?;
+
+                    Ok(TransferResult { events, fees })
+                });
 
         match res {
-            Ok(ev) => Output::success(ev).exit(),
+            Ok(result) => Output::success(result).exit(),
             Err(e) => Output::error(format!("{}", e)).exit(),
         }
     }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use prost_types::Any;
+use serde::Serialize;
 use tendermint::block::Height;
 use tokio::runtime::Runtime as TokioRuntime;
 
@@ -13,7 +14,7 @@ use ibc::ics02_client::client_consensus::{
 use ibc::ics02_client::client_state::{AnyClientState, ClientState, IdentifiedAnyClientState};
 use ibc::ics02_client::header::Header;
 use ibc::ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd, State};
-use ibc::ics03_connection::version::{get_compatible_versions, Version};
+use ibc::ics03_connection::version::Version;
 use ibc::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
@@ -39,6 +40,8 @@ use crate::error::{Error, Kind};
 use crate::event::monitor::TxMonitorCmd;
 use crate::keyring::{KeyEntry, KeyRing};
 use crate::light_client::LightClient;
+use crate::state::StateStore;
+use crate::telemetry::Telemetry;
 use crate::{config::ChainConfig, event::monitor::EventReceiver};
 
 pub(crate) mod cosmos;
@@ -66,6 +69,36 @@ pub struct QueryPacketOptions {
     pub height: u64,
 }
 
+/// The account of the relayer on a chain, as reported by the chain
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AccountStatus {
+    /// The address of the account of the main key of the chain
+    pub address: String,
+    /// The sequence of the next transaction signed by the account
+    pub sequence: u64,
+    /// The balance of the account, in the denomination of the fees
+    pub balance: String,
+    /// The denomination of the fees
+    pub denom: String,
+}
+
+/// The fees paid by the transactions sent by the relayer to a chain, since its runtime started
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FeesPaid {
+    /// The number of transactions which paid fees
+    pub txs: u64,
+    /// The total amount of the fees
+    pub amount: u64,
+    /// The denomination of the fees
+    pub denom: String,
+    /// The account granting the fees, if any, which paid them instead of the relayer
+    pub granter: Option<String>,
+}
+
+/// The events resulting from transactions which were broadcast to a chain,
+/// available once the transactions are committed.
+pub type PendingEvents = Box<dyn FnOnce() -> Result<Vec<IbcEvent>, Error> + Send>;
+
 /// Defines a blockchain as understood by the relayer
 pub trait Chain: Sized {
     /// Type of light blocks for this chain
@@ -95,6 +128,10 @@ pub trait Chain: Sized {
 
     fn shutdown(self) -> Result<(), Error>;
 
+    /// Provides the chain with a telemetry handle, to report its metrics to.
+    /// By default, the chain does not report any metrics.
+    fn set_telemetry(&mut self, _telemetry: Telemetry) {}
+
     /// Returns the chain's identifier
     fn id(&self) -> &ChainId;
 
@@ -107,18 +144,46 @@ pub trait Chain: Sized {
     /// Sends one or more transactions with `msgs` to chain.
     fn send_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error>;
 
+    /// Sends one or more transactions with `msgs` to chain, signed by the key of the given
+    /// `signer`, without waiting for them to be committed. Returns a closure which waits for
+    /// the commit of the transactions, and returns the resulting events.
+    /// By default, the transactions are committed by `send_msgs` before this returns.
+    fn broadcast_msgs(
+        &mut self,
+        proto_msgs: Vec<Any>,
+        _signer: &Signer,
+    ) -> Result<PendingEvents, Error> {
+        let events = self.send_msgs(proto_msgs)?;
+        Ok(Box::new(move || Ok(events)))
+    }
+
     fn get_signer(&mut self) -> Result<Signer, Error>;
 
+    /// Returns the signer to build the next messages with, taking turns among the keys
+    /// of the chain. By default, this is the signer returned by `get_signer`.
+    fn next_signer(&mut self) -> Result<Signer, Error> {
+        self.get_signer()
+    }
+
     fn get_key(&mut self) -> Result<KeyEntry, Error>;
 
+    /// The store of the state of the relayer on this chain, if this state is persisted
+    fn state_store(&self) -> Option<&StateStore> {
+        None
+    }
+
     // Queries
 
+    /// Query the account of the main key of the chain
+    fn query_account_status(&self) -> Result<AccountStatus, Error>;
+
+    /// Query the fees paid by the transactions sent to the chain since its runtime started
+    fn query_fees_paid(&self) -> Result<FeesPaid, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
-    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
-        // TODO - do a real chain query
-        Ok(get_compatible_versions())
-    }
+    /// Query the connection versions supported by the IBC module of the chain
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error>;
 
     /// Query the latest height the chain is at
     fn query_latest_height(&self) -> Result<ICSHeight, Error>;
@@ -399,7 +464,10 @@ pub trait Chain: Sized {
         sequence: Sequence,
         height: ICSHeight,
     ) -> Result<(Vec<u8>, Proofs), Error> {
-        let channel_proof = if packet_type == PacketMsgType::TimeoutOnClose {
+        let channel_proof = if matches!(
+            packet_type,
+            PacketMsgType::TimeoutOnClose | PacketMsgType::TimeoutOnCloseOrdered
+        ) {
             Some(CommitmentProofBytes::from(
                 self.proven_channel(&port_id, &channel_id, height)?.1,
             ))
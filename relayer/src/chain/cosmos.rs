@@ -1,5 +1,6 @@
 use std::{
     cmp::min,
+    collections::HashMap,
     convert::{TryFrom, TryInto},
     future::Future,
     str::FromStr,
@@ -22,10 +23,10 @@ use tendermint_light_client::types::LightBlock as TMLightBlock;
 use tendermint_proto::Protobuf;
 use tendermint_rpc::endpoint::tx::Response as ResultTx;
 use tendermint_rpc::query::{EventType, Query};
-use tendermint_rpc::{endpoint::broadcast::tx_sync::Response, Client, HttpClient, Order};
+use tendermint_rpc::{endpoint::broadcast::tx_sync::Response, Client, Order};
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
-use tracing::{debug, trace, warn};
+use tracing::{debug, info, trace, warn};
 
 use ibc::downcast;
 use ibc::events::{from_tx_response_event, IbcEvent};
@@ -35,7 +36,10 @@ use ibc::ics02_client::client_consensus::{
 use ibc::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
 use ibc::ics02_client::events as ClientEvents;
 use ibc::ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd};
-use ibc::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, QueryPacketEventDataRequest};
+use ibc::ics03_connection::version::Version;
+use ibc::ics04_channel::channel::{
+    ChannelEnd, IdentifiedChannelEnd, QueryPacketEventDataRequest, QueryPacketEventRangeRequest,
+};
 use ibc::ics04_channel::events as ChannelEvents;
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc::ics07_tendermint::client_state::{AllowUpdate, ClientState};
@@ -74,18 +78,26 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryClientConnectionsRequest, QueryConnectionsRequest,
 };
 
-use crate::config::{ChainConfig, GasPrice};
+use crate::config::{AddressType, ChainConfig, GasPrice};
 use crate::error::{Error, Kind};
 use crate::event::monitor::{EventMonitor, EventReceiver};
-use crate::keyring::{KeyEntry, KeyRing, Store};
+use crate::keyring::{KeyEntry, KeyRing, RemoteSigner};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::LightClient;
 use crate::light_client::Verified;
+use crate::state::StateStore;
+use crate::telemetry::Telemetry;
 use crate::{chain::QueryResponse, event::monitor::TxMonitorCmd};
 
-use super::Chain;
+use super::{AccountStatus, Chain, FeesPaid, PendingEvents};
 
+mod batch;
 mod compatibility;
+mod endpoints;
+mod gas_price;
+mod grpc;
+
+use endpoints::{Endpoint, EndpointPool};
 
 const DEFAULT_MAX_GAS: u64 = 300_000;
 const DEFAULT_GAS_PRICE_ADJUSTMENT: f64 = 0.1;
@@ -93,6 +105,17 @@ const DEFAULT_GAS_PRICE_ADJUSTMENT: f64 = 0.1;
 const DEFAULT_MAX_MSG_NUM: usize = 30;
 const DEFAULT_MAX_TX_SIZE: usize = 2 * 1048576; // 2 MBytes
 
+/// The maximum number of times a transaction is retried after an account sequence mismatch
+const MAX_ACCOUNT_SEQUENCE_RETRY: u32 = 3;
+
+/// Number of transactions fetched per page when searching for all the transactions matching a
+/// query.
+const TXS_PER_PAGE: u8 = 100;
+
+/// The minimum time between two queries of the balance of a wallet reported to telemetry
+#[cfg(feature = "telemetry")]
+const WALLET_BALANCE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
 mod retry_strategy {
     use crate::util::retry::Fixed;
     use std::time::Duration;
@@ -106,34 +129,60 @@ mod retry_strategy {
 
 pub struct CosmosSdkChain {
     config: ChainConfig,
-    rpc_client: HttpClient,
-    grpc_addr: Uri,
+    /// The endpoints of the full nodes of the chain, among which requests fail over
+    endpoints: EndpointPool,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing,
-    /// A cached copy of the account information
-    account: Option<BaseAccount>,
+    /// A cached copy of the account information, per key name
+    accounts: HashMap<String, BaseAccount>,
+    /// The index of the key of the next signer, among the configured keys
+    next_key: usize,
+    #[allow(dead_code)]
+    telemetry: Option<Telemetry>,
+    /// The gas price of the transactions, possibly refreshed from the dynamic gas price source
+    gas_price: GasPrice,
+    /// The last time the gas price was refreshed from the dynamic gas price source, if ever
+    gas_price_refreshed_at: Option<Instant>,
+    /// The fees paid by the transactions sent since the chain runtime started
+    fees_paid: FeesPaid,
+    /// The last time the balance of each wallet was reported to telemetry, per key name
+    #[allow(dead_code)]
+    balances_refreshed_at: HashMap<String, Instant>,
+    /// The state of the relayer on this chain, persisted across restarts
+    state: StateStore,
+    /// The connection versions supported by the IBC module of the chain, as reported by the
+    /// first node which passed the health check, if any
+    compatible_versions: Option<Vec<Version>>,
 }
 
 impl CosmosSdkChain {
-    /// Does multiple RPC calls to the full node, to check for
+    /// Does multiple RPC calls to each full node, to check for
     /// reachability and that some basic APIs are available.
     ///
     /// Currently this checks that:
     ///     - the node responds OK to `/health` RPC call;
     ///     - the node has transaction indexing enabled;
+    ///     - the application includes an IBC module;
     ///     - the SDK version is supported.
     ///
-    /// Emits a log warning in case anything is amiss.
-    /// Exits early if any health check fails, without doing any
-    /// further checks.
-    fn health_checkup(&self) {
-        async fn do_health_checkup(chain: &CosmosSdkChain) -> Result<(), Error> {
+    /// Emits a log warning in case anything is amiss with a node,
+    /// and lowers its health score so that requests fail over to the
+    /// other nodes. Exits early if any health check of a node fails,
+    /// without doing any further checks of that node.
+    ///
+    /// Records the connection versions supported by the IBC module
+    /// of the first node which passes all the health checks.
+    fn health_checkup(&mut self) {
+        async fn do_health_checkup(
+            chain: &CosmosSdkChain,
+            endpoint: &Endpoint,
+        ) -> Result<Vec<Version>, Error> {
             let chain_id = chain.id();
-            let grpc_address = chain.grpc_addr.to_string();
-            let rpc_address = chain.config.rpc_addr.to_string();
+            let grpc_address = endpoint.grpc_addr.to_string();
+            let rpc_address = endpoint.rpc_addr.to_string();
 
             // Checkup on the self-reported health endpoint
-            chain
+            endpoint
                 .rpc_client
                 .health()
                 .await
@@ -145,7 +194,7 @@ impl CosmosSdkChain {
                 })?;
 
             // Checkup on transaction indexing
-            chain
+            endpoint
                 .rpc_client
                 .tx_search(
                     Query::from(EventType::NewBlock),
@@ -162,7 +211,7 @@ impl CosmosSdkChain {
                     cause: e,
                 })?;
 
-            let mut client = ServiceClient::connect(chain.grpc_addr.clone())
+            let mut client = ServiceClient::connect(endpoint.grpc_addr.clone())
                 .await
                 .map_err(|e| {
                     // Failed to create the gRPC client to call into `/node_info`.
@@ -199,6 +248,16 @@ impl CosmosSdkChain {
                             .to_string(),
                     })?;
 
+            // Checkup on the connection versions supported by the IBC module
+            let versions =
+                compatibility::supported_connection_versions(&version).map_err(|diagnostic| {
+                    Kind::SdkModuleVersion {
+                        chain_id: chain_id.clone(),
+                        address: grpc_address.clone(),
+                        cause: diagnostic.to_string(),
+                    }
+                })?;
+
             // Checkup on the underlying SDK version
             if let Some(diagnostic) = compatibility::run_diagnostic(version) {
                 return Err(Kind::SdkModuleVersion {
@@ -209,33 +268,53 @@ impl CosmosSdkChain {
                 .into());
             }
 
-            Ok(())
+            Ok(versions)
+        }
+
+        let mut unhealthy = vec![];
+        let mut compatible_versions = None;
+
+        for (index, endpoint) in self.endpoints.endpoints().iter().enumerate() {
+            match self.block_on(do_health_checkup(self, endpoint)) {
+                Ok(versions) => {
+                    if compatible_versions.is_none() {
+                        compatible_versions = Some(versions);
+                    }
+                }
+                Err(e) => {
+                    warn!("{}", e);
+                    self.endpoints.record_failure(index, &e);
+                    unhealthy.push(index);
+                }
+            }
         }
 
-        if let Err(e) = self.block_on(do_health_checkup(self)) {
-            warn!("{}", e);
+        if unhealthy.contains(&self.endpoints.active_index()) {
             warn!("some Hermes features may not work in this mode!");
         }
+
+        self.compatible_versions = compatible_versions;
     }
 
     /// The unbonding period of this chain
     pub fn unbonding_period(&self) -> Result<Duration, Error> {
         crate::time!("unbonding_period");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            let mut client = self
+                .block_on(
+                    ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
+                        endpoint.grpc_addr.clone(),
+                    ),
+                )
+                .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request =
-            tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
+            let request =
+                tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
 
-        let response = self
-            .block_on(client.params(request))
-            .map_err(|e| Kind::Grpc.context(e))?;
+            self.block_on(client.params(request))
+                .map_err(|e| Kind::Grpc.context(e).into())
+        })?;
 
         let res = response
             .into_inner()
@@ -247,10 +326,6 @@ impl CosmosSdkChain {
         Ok(Duration::new(res.seconds as u64, res.nanos as u32))
     }
 
-    fn rpc_client(&self) -> &HttpClient {
-        &self.rpc_client
-    }
-
     pub fn config(&self) -> &ChainConfig {
         &self.config
     }
@@ -260,10 +335,12 @@ impl CosmosSdkChain {
     pub fn query_consensus_params(&self) -> Result<Params, Error> {
         crate::time!("query_consensus_params");
 
-        Ok(self
-            .block_on(self.rpc_client().genesis())
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?
-            .consensus_params)
+        let genesis = self.endpoints.with_failover(|endpoint| {
+            self.block_on(endpoint.rpc_client.genesis())
+                .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+        })?;
+
+        Ok(genesis.consensus_params)
     }
 
     /// Run a future to completion on the Tokio runtime.
@@ -272,23 +349,60 @@ impl CosmosSdkChain {
         self.rt.block_on(f)
     }
 
-    fn send_tx(&mut self, proto_msgs: Vec<Any>) -> Result<Response, Error> {
+    /// Sign and broadcast a transaction including the given messages with the given key.
+    /// If the transaction is rejected because the cached sequence of the account does not match
+    /// the one expected by the chain, e.g. because the key is used by another process, then the
+    /// account is queried again, and the transaction is retried with the up-to-date sequence.
+    fn send_tx(&mut self, key_name: &str, proto_msgs: Vec<Any>) -> Result<Response, Error> {
         crate::time!("send_tx");
-        let account_seq = self.account_sequence()?;
+
+        let mut retries = 0;
+        loop {
+            let response = self.sign_and_broadcast_tx(key_name, proto_msgs.clone())?;
+
+            match expected_account_sequence(&response) {
+                Some(expected) if retries < MAX_ACCOUNT_SEQUENCE_RETRY => {
+                    warn!(
+                        "[{}] send_tx: account sequence mismatch for key {}, chain expects {}, \
+                         refreshing the account and retrying",
+                        self.id(),
+                        key_name,
+                        expected
+                    );
+
+                    retries += 1;
+                    crate::telemetry!(if let Some(telemetry) = &self.telemetry {
+                        telemetry.tx_sequence_mismatch_retries(self.id(), 1)
+                    });
+
+                    self.refresh_account(key_name)?;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    fn sign_and_broadcast_tx(
+        &mut self,
+        key_name: &str,
+        proto_msgs: Vec<Any>,
+    ) -> Result<Response, Error> {
+        let account_seq = self.account_sequence(key_name)?;
 
         debug!(
-            "[{}] send_tx: sending {} messages using nonce {}",
+            "[{}] send_tx: sending {} messages with key {} using nonce {}",
             self.id(),
             proto_msgs.len(),
+            key_name,
             account_seq,
         );
 
-        let signer_info = self.signer(account_seq)?;
+        let signer_info = self.signer(key_name, account_seq)?;
         let fee = self.default_fee();
         let (body, body_buf) = tx_body_and_bytes(proto_msgs)?;
 
         let (auth_info, auth_buf) = auth_info_and_bytes(signer_info.clone(), fee.clone())?;
-        let signed_doc = self.signed_doc(body_buf.clone(), auth_buf, account_seq)?;
+        let signed_doc = self.signed_doc(key_name, body_buf.clone(), auth_buf, account_seq)?;
 
         // Try to simulate the Tx.
         // It is possible that a batch of messages are fragmented by the caller (`send_msgs`) such that
@@ -308,6 +422,10 @@ impl CosmosSdkChain {
                 sr.gas_info.map_or(self.max_gas(), |g| g.gas_used)
             });
 
+        crate::telemetry!(if let Some(telemetry) = &self.telemetry {
+            telemetry.tx_gas_estimated(self.id(), estimated_gas)
+        });
+
         if estimated_gas > self.max_gas() {
             return Err(Kind::TxSimulateGasEstimateExceeded {
                 chain_id: self.id().clone(),
@@ -326,10 +444,15 @@ impl CosmosSdkChain {
             adjusted_fee
         );
 
-        let (_auth_adjusted, auth_buf_adjusted) = auth_info_and_bytes(signer_info, adjusted_fee)?;
-        let account_number = self.account_number()?;
-        let signed_doc =
-            self.signed_doc(body_buf.clone(), auth_buf_adjusted.clone(), account_number)?;
+        let (_auth_adjusted, auth_buf_adjusted) =
+            auth_info_and_bytes(signer_info, adjusted_fee.clone())?;
+        let account_number = self.account_number(key_name)?;
+        let signed_doc = self.signed_doc(
+            key_name,
+            body_buf.clone(),
+            auth_buf_adjusted.clone(),
+            account_number,
+        )?;
 
         let tx_raw = TxRaw {
             body_bytes: body_buf,
@@ -340,13 +463,41 @@ impl CosmosSdkChain {
         let mut tx_bytes = Vec::new();
         prost::Message::encode(&tx_raw, &mut tx_bytes).unwrap();
 
-        let response = self
-            .block_on(broadcast_tx_sync(self, tx_bytes))
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            self.block_on(broadcast_tx_sync(endpoint, tx_bytes.clone()))
+        })?;
 
         debug!("[{}] send_tx: broadcast_tx_sync: {:?}", self.id(), response);
 
-        self.incr_account_sequence()?;
+        info!(
+            "[{}] send_tx: tx {} pays fee {} for gas limit {}{}",
+            self.id(),
+            response.hash,
+            adjusted_fee
+                .amount
+                .iter()
+                .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                .join(","),
+            adjusted_fee.gas_limit,
+            match &self.config.fee_granter {
+                Some(granter) => format!(", granted by {}", granter),
+                None => "".to_string(),
+            }
+        );
+
+        // The sequence of the account is only consumed by transactions which pass `CheckTx`
+        if response.code.value() == 0 {
+            self.incr_account_sequence(key_name)?;
+            self.record_fee_paid(&adjusted_fee);
+
+            crate::telemetry!(if let Some(telemetry) = &self.telemetry {
+                for coin in &adjusted_fee.amount {
+                    if let Ok(amount) = coin.amount.parse() {
+                        telemetry.tx_fees_paid(self.id(), &coin.denom, amount);
+                    }
+                }
+            });
+        }
 
         Ok(response)
     }
@@ -358,7 +509,100 @@ impl CosmosSdkChain {
 
     /// The gas price
     fn gas_price(&self) -> &GasPrice {
-        &self.config.gas_price
+        &self.gas_price
+    }
+
+    /// Refresh the gas price from the dynamic gas price source of the chain, if any,
+    /// once the refresh interval has elapsed since the last refresh.
+    /// The gas price is left unchanged if the source cannot be queried.
+    fn refresh_gas_price(&mut self) {
+        let dynamic = match &self.config.dynamic_gas_price {
+            Some(dynamic) => dynamic.clone(),
+            None => return,
+        };
+
+        if let Some(refreshed_at) = self.gas_price_refreshed_at {
+            if refreshed_at.elapsed() < dynamic.refresh_interval {
+                return;
+            }
+        }
+
+        let denom = self.config.gas_price.denom.clone();
+        let price = match &dynamic.endpoint {
+            Some(endpoint) => gas_price::query_endpoint_gas_price(endpoint),
+            None => self.endpoints.with_failover(|endpoint| {
+                self.block_on(gas_price::query_node_min_gas_price(
+                    endpoint.grpc_addr.clone(),
+                    &denom,
+                ))
+            }),
+        };
+
+        match price {
+            Ok(price) => {
+                let gas_price = GasPrice::new(dynamic.bound(price), denom);
+                debug!("[{}] refreshed gas price to {}", self.id(), gas_price);
+                self.gas_price = gas_price;
+            }
+            Err(e) => warn!(
+                "[{}] failed to refresh the gas price, keeping {}: {}",
+                self.id(),
+                self.gas_price,
+                e
+            ),
+        }
+
+        self.gas_price_refreshed_at = Some(Instant::now());
+    }
+
+    /// Report the balance of the wallet of the given key, in the denomination of the gas price,
+    /// to telemetry, once the refresh interval has elapsed since the last report.
+    #[cfg(feature = "telemetry")]
+    fn refresh_wallet_balance(&mut self, key_name: &str) {
+        let telemetry = match &self.telemetry {
+            Some(telemetry) => telemetry.clone(),
+            None => return,
+        };
+
+        if let Some(refreshed_at) = self.balances_refreshed_at.get(key_name) {
+            if refreshed_at.elapsed() < WALLET_BALANCE_REFRESH_INTERVAL {
+                return;
+            }
+        }
+
+        let balance = self.key_account(key_name).and_then(|account| {
+            let denom = self.gas_price().denom.clone();
+            let balance = self.endpoints.with_failover(|endpoint| {
+                self.block_on(grpc::query_balance(
+                    endpoint.grpc_addr.clone(),
+                    account.clone(),
+                    denom.clone(),
+                ))
+            })?;
+            let amount = balance.amount.parse::<f64>().map_err(|e| {
+                Kind::Grpc.context(format!(
+                    "invalid balance amount '{}': {}",
+                    balance.amount, e
+                ))
+            })?;
+
+            Ok((account, balance.denom, amount))
+        });
+
+        match balance {
+            Ok((account, denom, amount)) => {
+                telemetry.wallet_balance(self.id(), &account, &denom, amount)
+            }
+            Err(e) => warn!(
+                "[{}] failed to query the balance of key '{}': {}",
+                self.id(),
+                key_name,
+                e
+            ),
+        }
+
+        self.balances_refreshed_at
+            .insert(key_name.to_string(), Instant::now());
     }
 
     /// The gas price adjustment
@@ -412,7 +656,15 @@ impl CosmosSdkChain {
                 .into());
         }
 
-        let response = self.block_on(abci_query(self, path, data.to_string(), height, prove))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            self.block_on(abci_query(
+                endpoint,
+                path.clone(),
+                data.to_string(),
+                height,
+                prove,
+            ))
+        })?;
 
         // TODO - Verify response proof, if requested.
         if prove {}
@@ -430,13 +682,16 @@ impl CosmosSdkChain {
             Height::try_from(height.value() - 1).map_err(|e| Kind::InvalidHeight.context(e))?;
 
         let path = TendermintABCIPath::from_str(SDK_UPGRADE_QUERY_PATH).unwrap();
-        let response = self.block_on(abci_query(
-            self,
-            path,
-            Path::Upgrade(data).to_string(),
-            prev_height,
-            true,
-        ))?;
+        let data = Path::Upgrade(data).to_string();
+        let response = self.endpoints.with_failover(|endpoint| {
+            self.block_on(abci_query(
+                endpoint,
+                path.clone(),
+                data.clone(),
+                prev_height,
+                true,
+            ))
+        })?;
 
         let proof = response.proof.ok_or(Kind::EmptyResponseProof)?;
 
@@ -451,82 +706,222 @@ impl CosmosSdkChain {
     fn send_tx_simulate(&self, request: SimulateRequest) -> Result<SimulateResponse, Error> {
         crate::time!("tx simulate");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
-
-        let request = tonic::Request::new(request);
         let response = self
-            .block_on(client.simulate(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
+
+                let request = tonic::Request::new(request.clone());
+
+                self.block_on(client.simulate(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         Ok(response)
     }
 
-    fn key(&self) -> Result<KeyEntry, Error> {
-        Ok(self
+    fn key_bytes(&self, key_name: &str) -> Result<Vec<u8>, Error> {
+        let public_key = self
             .keybase()
-            .get_key(&self.config.key_name)
-            .map_err(|e| Kind::KeyBase.context(e))?)
-    }
+            .public_key(key_name)
+            .map_err(|e| Kind::KeyBase.context(e))?;
 
-    fn key_bytes(&self, key: &KeyEntry) -> Result<Vec<u8>, Error> {
         let mut pk_buf = Vec::new();
-        prost::Message::encode(&key.public_key.public_key.to_bytes(), &mut pk_buf).unwrap();
+        prost::Message::encode(&public_key, &mut pk_buf).unwrap();
         Ok(pk_buf)
     }
 
-    fn key_and_bytes(&self) -> Result<(KeyEntry, Vec<u8>), Error> {
-        let key = self.key()?;
-        let key_bytes = self.key_bytes(&key)?;
-        Ok((key, key_bytes))
+    fn key_account(&self, key_name: &str) -> Result<String, Error> {
+        Ok(self
+            .keybase()
+            .account(key_name, &self.config.address_type)
+            .map_err(|e| Kind::KeyBase.context(e))?)
     }
 
-    fn account(&mut self) -> Result<&mut BaseAccount, Error> {
-        if self.account == None {
+    fn account(&mut self, key_name: &str) -> Result<&mut BaseAccount, Error> {
+        if !self.accounts.contains_key(key_name) {
+            let address = self.key_account(key_name)?;
             let account = self
-                .block_on(query_account(self, self.key()?.account))
+                .endpoints
+                .with_failover(|endpoint| {
+                    self.block_on(query_account(endpoint.grpc_addr.clone(), address.clone()))
+                })
                 .map_err(|e| Kind::Grpc.context(e))?;
 
             debug!(
                 sequence = %account.sequence,
                 number = %account.account_number,
-                "[{}] send_tx: retrieved account",
-                self.id()
+                "[{}] send_tx: retrieved account for key {}",
+                self.id(),
+                key_name
             );
 
-            self.account = Some(account);
+            self.accounts.insert(key_name.to_string(), account);
         }
 
         Ok(self
-            .account
-            .as_mut()
+            .accounts
+            .get_mut(key_name)
             .expect("account was supposedly just cached"))
     }
 
-    fn account_number(&mut self) -> Result<u64, Error> {
-        Ok(self.account()?.account_number)
+    fn account_number(&mut self, key_name: &str) -> Result<u64, Error> {
+        Ok(self.account(key_name)?.account_number)
+    }
+
+    fn account_sequence(&mut self, key_name: &str) -> Result<u64, Error> {
+        Ok(self.account(key_name)?.sequence)
+    }
+
+    fn incr_account_sequence(&mut self, key_name: &str) -> Result<(), Error> {
+        self.account(key_name)?.sequence += 1;
+        Ok(())
+    }
+
+    /// Send the given batch of messages in a single transaction, unless the gas estimated for
+    /// the transaction exceeds the maximum gas, in which case the batch is split in two halves
+    /// which are sent in turn, recursively.
+    fn send_batch(
+        &mut self,
+        key_name: &str,
+        msg_batch: Vec<Any>,
+        tx_sync_results: &mut Vec<TxSyncResult>,
+    ) -> Result<(), Error> {
+        let events_per_tx = vec![IbcEvent::Empty("".to_string()); msg_batch.len()];
+
+        match self.send_tx(key_name, msg_batch.clone()) {
+            Ok(response) => {
+                self.record_pending_tx(&response);
+
+                tx_sync_results.push(TxSyncResult {
+                    response,
+                    events: events_per_tx,
+                });
+                Ok(())
+            }
+            Err(e)
+                if msg_batch.len() > 1
+                    && matches!(e.kind(), Kind::TxSimulateGasEstimateExceeded { .. }) =>
+            {
+                debug!(
+                    "[{}] send_batch: splitting batch of {} messages: {}",
+                    self.id(),
+                    msg_batch.len(),
+                    e
+                );
+
+                let (first, second) = batch::split_batch(msg_batch);
+                self.send_batch(key_name, first, tx_sync_results)?;
+                self.send_batch(key_name, second, tx_sync_results)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Record the hash of the given submitted transaction in the state store, until the
+    /// transaction is committed.
+    fn record_pending_tx(&self, response: &Response) {
+        if let Err(e) = self.state.add_pending_tx(&response.hash.to_string()) {
+            warn!(
+                "[{}] failed to record pending tx {}: {}",
+                self.id(),
+                response.hash,
+                e
+            );
+        }
+    }
+
+    /// Wait for the transactions submitted before the relayer was restarted to be committed,
+    /// such that the relay paths resuming their work see the effects of these transactions,
+    /// instead of submitting the same messages again.
+    fn resume_pending_txs(&self) {
+        let hashes = self.state.pending_txs();
+        if hashes.is_empty() {
+            return;
+        }
+
+        info!(
+            "[{}] waiting for the commit of {} transaction(s) submitted before the restart",
+            self.id(),
+            hashes.len()
+        );
+
+        self.commit_waiter().wait_for_pending_txs(hashes);
     }
 
-    fn account_sequence(&mut self) -> Result<u64, Error> {
-        Ok(self.account()?.sequence)
+    /// Add the given fee of a transaction which passed `CheckTx` to the fees paid.
+    fn record_fee_paid(&mut self, fee: &Fee) {
+        let denom = &self.fees_paid.denom;
+        let amount = fee
+            .amount
+            .iter()
+            .filter(|coin| &coin.denom == denom)
+            .filter_map(|coin| coin.amount.parse::<u64>().ok())
+            .fold(0u64, u64::saturating_add);
+
+        self.fees_paid.txs += 1;
+        self.fees_paid.amount = self.fees_paid.amount.saturating_add(amount);
     }
 
-    fn incr_account_sequence(&mut self) -> Result<(), Error> {
-        self.account()?.sequence += 1;
+    /// Drop the cached account information of the given key, and query it again.
+    fn refresh_account(&mut self, key_name: &str) -> Result<(), Error> {
+        self.accounts.remove(key_name);
+        self.account(key_name)?;
         Ok(())
     }
 
-    fn signer(&self, sequence: u64) -> Result<SignerInfo, Error> {
-        let (_key, pk_buf) = self.key_and_bytes()?;
+    /// The names of the keys of the chain, starting with the main key, followed by the
+    /// additional keys.
+    fn key_names(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.config.key_name).chain(self.config.additional_key_names.iter())
+    }
+
+    /// Get the signer of the given key
+    fn key_signer(&self, key_name: &str) -> Result<Signer, Error> {
+        let address = self
+            .keybase()
+            .address(key_name, &self.config.address_type)
+            .map_err(|e| Kind::KeyBase.context(e))?;
+
+        let bech32 = encode_to_bech32(&address.to_hex(), &self.config.account_prefix)?;
+        Ok(Signer::new(bech32))
+    }
+
+    /// Get the name of the configured key of the given signer
+    fn key_name_of(&self, signer: &Signer) -> Result<String, Error> {
+        for key_name in self.key_names() {
+            if &self.key_signer(key_name)? == signer {
+                return Ok(key_name.clone());
+            }
+        }
+
+        Err(Kind::KeyBase
+            .context(format!(
+                "no key of chain {} matches the signer {}",
+                self.id(),
+                signer
+            ))
+            .into())
+    }
+
+    fn signer(&self, key_name: &str, sequence: u64) -> Result<SignerInfo, Error> {
+        let pk_buf = self.key_bytes(key_name)?;
+
+        let pk_type = match &self.config.address_type {
+            AddressType::Cosmos => "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            AddressType::Ethermint { pk_type } => pk_type.clone(),
+        };
+
         // Create a MsgSend proto Any message
         let pk_any = Any {
-            type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            type_url: pk_type,
             value: pk_buf,
         };
 
@@ -546,7 +941,7 @@ impl CosmosSdkChain {
             amount: vec![self.max_fee_in_coins()],
             gas_limit: self.max_gas(),
             payer: "".to_string(),
-            granter: "".to_string(),
+            granter: self.config.fee_granter.clone().unwrap_or_default(),
         }
     }
 
@@ -561,6 +956,7 @@ impl CosmosSdkChain {
 
     fn signed_doc(
         &self,
+        key_name: &str,
         body_bytes: Vec<u8>,
         auth_info_bytes: Vec<u8>,
         account_number: u64,
@@ -579,7 +975,7 @@ impl CosmosSdkChain {
         // Sign doc
         let signed = self
             .keybase
-            .sign_msg(&self.config.key_name, signdoc_buf)
+            .sign_msg(key_name, signdoc_buf, &self.config.address_type)
             .map_err(|e| Kind::KeyBase.context(e))?;
 
         Ok(signed)
@@ -589,6 +985,38 @@ impl CosmosSdkChain {
     /// each including a transaction response hash for one or more messages, periodically queries the chain
     /// with the transaction hashes to get the list of IbcEvents included in those transactions.
     pub fn wait_for_block_commits(
+        &self,
+        tx_sync_results: Vec<TxSyncResult>,
+    ) -> Result<Vec<TxSyncResult>, Error> {
+        self.commit_waiter().wait_for_block_commits(tx_sync_results)
+    }
+
+    fn commit_waiter(&self) -> CommitWaiter {
+        CommitWaiter {
+            chain_id: self.config.id.clone(),
+            endpoints: self.endpoints.clone(),
+            rt: self.rt.clone(),
+            rpc_timeout: self.config.rpc_timeout,
+            telemetry: self.telemetry.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+/// Waits for the transactions broadcast to a chain to be committed, independently of the chain
+/// handle, such that the chain runtime can keep sending transactions in the meantime.
+struct CommitWaiter {
+    chain_id: ChainId,
+    endpoints: EndpointPool,
+    rt: Arc<TokioRuntime>,
+    rpc_timeout: Duration,
+    #[allow(dead_code)]
+    telemetry: Option<Telemetry>,
+    state: StateStore,
+}
+
+impl CommitWaiter {
+    fn wait_for_block_commits(
         &self,
         mut tx_sync_results: Vec<TxSyncResult>,
     ) -> Result<Vec<TxSyncResult>, Error> {
@@ -599,19 +1027,24 @@ impl CosmosSdkChain {
             .map(|res| res.response.hash.to_string())
             .join(", ");
 
-        debug!("[{}] waiting for commit of block(s) {}", self.id(), hashes);
+        debug!(
+            "[{}] waiting for commit of block(s) {}",
+            self.chain_id, hashes
+        );
+
+        let broadcast = Instant::now();
 
         // Wait a little bit initially
         thread::sleep(Duration::from_millis(200));
 
         let start = Instant::now();
         let result = retry_with_index(
-            retry_strategy::wait_for_block_commits(self.config.rpc_timeout),
+            retry_strategy::wait_for_block_commits(self.rpc_timeout),
             |index| {
                 if all_tx_results_found(&tx_sync_results) {
                     trace!(
                         "[{}] wait_for_block_commits: retrieved {} tx results after {} tries ({}ms)",
-                        self.id(),
+                        self.chain_id,
                         tx_sync_results.len(),
                         index,
                         start.elapsed().as_millis()
@@ -630,13 +1063,18 @@ impl CosmosSdkChain {
                         if response.code.value() != 0 {
                             *events = vec![IbcEvent::ChainError(format!(
                             "deliver_tx on chain {} for Tx hash {} reports error: code={:?}, log={:?}",
-                            self.id(), response.hash, response.code, response.log
+                            self.chain_id, response.hash, response.code, response.log
                         ))];
 
                         // Otherwise, try to resolve transaction hash to the corresponding events.
-                        } else if let Ok(events_per_tx) =
-                            self.query_txs(QueryTxRequest::Transaction(QueryTxHash(response.hash)))
-                        {
+                        } else if let Ok(Some(tx)) = self.query_tx(&QueryTxHash(response.hash)) {
+                            crate::telemetry!(if let Some(telemetry) = &self.telemetry {
+                                telemetry.tx_gas_used(&self.chain_id, tx.tx_result.gas_used.value())
+                            });
+
+                            let events_per_tx =
+                                all_ibc_events_from_tx_search_response(&self.chain_id, tx);
+
                             // If we get events back, progress was made, so we replace the events
                             // with the new ones. in both cases we will check in the next iteration
                             // whether or not the transaction was fully committed.
@@ -650,18 +1088,121 @@ impl CosmosSdkChain {
             },
         );
 
+        // The transactions are either committed, or given up on
+        for TxSyncResult { response, .. } in tx_sync_results.iter() {
+            self.forget_pending_tx(&response.hash.to_string());
+        }
+
         match result {
             // All transactions confirmed
-            Ok(()) => Ok(tx_sync_results),
+            Ok(()) => {
+                crate::telemetry!(if let Some(telemetry) = &self.telemetry {
+                    telemetry.tx_confirmation_time(&self.chain_id, broadcast.elapsed())
+                });
+
+                Ok(tx_sync_results)
+            }
             // Did not find confirmation
             Err(_) => Err(Kind::TxNoConfirmation(format!(
                 "from chain {} for hash(es) {}",
-                self.id(),
-                hashes
+                self.chain_id, hashes
             ))
             .into()),
         }
     }
+
+    /// Wait for the transactions with the given hashes to be committed, then forget them,
+    /// whether they were committed or not.
+    fn wait_for_pending_txs(&self, hashes: Vec<String>) {
+        use crate::util::retry::{retry_with_index, RetryResult};
+
+        let mut pending = hashes.clone();
+
+        let result = retry_with_index(
+            retry_strategy::wait_for_block_commits(self.rpc_timeout),
+            |index| {
+                pending.retain(|hash| match hash.parse() {
+                    Ok(hash) => !matches!(self.query_tx(&QueryTxHash(hash)), Ok(Some(_))),
+                    Err(_) => false,
+                });
+
+                if pending.is_empty() {
+                    RetryResult::Ok(())
+                } else {
+                    RetryResult::Retry(index)
+                }
+            },
+        );
+
+        if result.is_err() {
+            warn!(
+                "[{}] transaction(s) {} submitted before the restart were not committed",
+                self.chain_id,
+                pending.join(", ")
+            );
+        }
+
+        for hash in hashes {
+            self.forget_pending_tx(&hash);
+        }
+    }
+
+    fn forget_pending_tx(&self, hash: &str) {
+        if let Err(e) = self.state.remove_pending_tx(hash) {
+            warn!(
+                "[{}] failed to forget pending tx {}: {}",
+                self.chain_id, hash, e
+            );
+        }
+    }
+
+    /// Query the IBC events emitted by the transaction with the given hash, if it was committed.
+    fn query_tx_events(&self, tx: &QueryTxHash) -> Result<Vec<IbcEvent>, Error> {
+        Ok(self
+            .query_tx(tx)?
+            .map(|tx| all_ibc_events_from_tx_search_response(&self.chain_id, tx))
+            .unwrap_or_default())
+    }
+
+    /// Query the transaction with the given hash, if it was committed.
+    fn query_tx(&self, tx: &QueryTxHash) -> Result<Option<ResultTx>, Error> {
+        let mut response = self.endpoints.with_failover(|endpoint| {
+            self.rt
+                .block_on(endpoint.rpc_client.tx_search(
+                    tx_hash_query(tx),
+                    false,
+                    1,
+                    1, // get only the first Tx matching the query
+                    Order::Ascending,
+                ))
+                .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+        })?;
+
+        if response.txs.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(response.txs.remove(0)))
+        }
+    }
+}
+
+/// Returns the account sequence expected by the chain if the given transaction was rejected
+/// because of an account sequence mismatch, whose log is formatted by the SDK as
+/// `account sequence mismatch, expected 10, got 9: incorrect account sequence`.
+fn expected_account_sequence(response: &Response) -> Option<u64> {
+    if response.code.value() == 0 {
+        return None;
+    }
+
+    parse_expected_account_sequence(&response.log.to_string())
+}
+
+fn parse_expected_account_sequence(log: &str) -> Option<u64> {
+    const MISMATCH: &str = "account sequence mismatch, expected ";
+
+    let start = log.find(MISMATCH)? + MISMATCH.len();
+    let expected = log[start..].split(|c: char| !c.is_ascii_digit()).next()?;
+    expected.parse().ok()
 }
 
 fn empty_event_present(events: &[IbcEvent]) -> bool {
@@ -681,26 +1222,56 @@ impl Chain for CosmosSdkChain {
     type ClientState = ClientState;
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        let rpc_client = HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| Kind::Rpc(config.rpc_addr.clone()).context(e))?;
+        let endpoints = EndpointPool::new(config.id.clone(), &config.nodes())?;
+
+        // Initialize key store, or the remote signer holding the keys
+        let keybase = match &config.remote_signer {
+            Some(remote) => KeyRing::new_remote(
+                &config.account_prefix,
+                RemoteSigner::new(remote.socket.clone(), remote.timeout),
+            ),
+            None => KeyRing::new(config.key_store_type, &config.account_prefix, &config.id)
+                .map_err(|e| Kind::KeyBase.context(e))?,
+        };
 
-        // Initialize key store and load key
-        let keybase = KeyRing::new(Store::Test, &config.account_prefix, &config.id)
-            .map_err(|e| Kind::KeyBase.context(e))?;
+        let gas_price = config.gas_price.clone();
+        let fees_paid = FeesPaid {
+            denom: gas_price.denom.clone(),
+            granter: config.fee_granter.clone(),
+            ..FeesPaid::default()
+        };
 
-        let grpc_addr =
-            Uri::from_str(&config.grpc_addr.to_string()).map_err(|e| Kind::Grpc.context(e))?;
+        // The state store may already be in use, eg. by another instance of the relayer,
+        // in which case the state is only kept for the lifetime of this chain runtime.
+        let state = match StateStore::open(&config.id) {
+            Ok(state) => state,
+            Err(e) => {
+                warn!(
+                    "failed to open the state store of chain {}, falling back to a temporary store: {}",
+                    config.id, e
+                );
+                StateStore::temporary(&config.id)?
+            }
+        };
 
-        let chain = Self {
+        let mut chain = Self {
             config,
-            rpc_client,
-            grpc_addr,
+            endpoints,
             rt,
             keybase,
-            account: None,
+            accounts: HashMap::new(),
+            next_key: 0,
+            telemetry: None,
+            fees_paid,
+            gas_price,
+            gas_price_refreshed_at: None,
+            balances_refreshed_at: HashMap::new(),
+            state,
+            compatible_versions: None,
         };
 
         chain.health_checkup();
+        chain.resume_pending_txs();
 
         Ok(chain)
     }
@@ -710,13 +1281,20 @@ impl Chain for CosmosSdkChain {
 
         crate::time!("init_light_client");
 
-        let peer_id: PeerId = self
-            .rt
-            .block_on(self.rpc_client.status())
-            .map(|s| s.node_info.id)
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+        // The light client verifies the headers of the active node at the time of its creation
+        let (rpc_addr, peer_id): (_, PeerId) = self.endpoints.with_failover(|endpoint| {
+            self.rt
+                .block_on(endpoint.rpc_client.status())
+                .map(|s| (endpoint.rpc_addr.clone(), s.node_info.id))
+                .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+        })?;
+
+        let config = ChainConfig {
+            rpc_addr,
+            ..self.config.clone()
+        };
 
-        let light_client = TmLightClient::from_config(&self.config, peer_id)?;
+        let light_client = TmLightClient::from_config(&config, peer_id)?;
 
         Ok(Box::new(light_client))
     }
@@ -729,7 +1307,8 @@ impl Chain for CosmosSdkChain {
 
         let (mut event_monitor, event_receiver, monitor_tx) = EventMonitor::new(
             self.config.id.clone(),
-            self.config.websocket_addr.clone(),
+            self.config.nodes(),
+            self.config.event_source.clone(),
             rt,
         )
         .map_err(Kind::EventMonitor)?;
@@ -745,6 +1324,10 @@ impl Chain for CosmosSdkChain {
         Ok(())
     }
 
+    fn set_telemetry(&mut self, telemetry: Telemetry) {
+        self.telemetry = Some(telemetry);
+    }
+
     fn id(&self) -> &ChainId {
         &self.config().id
     }
@@ -762,70 +1345,83 @@ impl Chain for CosmosSdkChain {
     /// number of messages per transaction and the maximum transaction size.
     /// Then `send_tx()` is called with each Tx. `send_tx()` determines the fee based on the
     /// on-chain simulation and if this exceeds the maximum gas specified in the configuration file
-    /// then it returns error.
-    /// TODO - more work is required here for a smarter split maybe iteratively accumulating/ evaluating
-    /// msgs in a Tx until any of the max size, max num msgs, max fee are exceeded.
+    /// then the Tx is split in two halves, which are sent in turn, and split further if needed.
+    /// A client update is kept in the same Tx as the message following it, whose proofs are
+    /// usually verified against the updated client.
     fn send_msgs(&mut self, proto_msgs: Vec<Any>) -> Result<Vec<IbcEvent>, Error> {
         crate::time!("send_msgs");
 
+        let signer = self.get_signer()?;
+        let pending = self.broadcast_msgs(proto_msgs, &signer)?;
+        pending()
+    }
+
+    /// Broadcast the transactions including the specified messages, split as in `send_msgs`, and
+    /// return a closure waiting for their commit.
+    /// The transactions are signed by the key of the given signer, which must be one of the
+    /// configured keys of the chain, and which the messages must have been built with.
+    fn broadcast_msgs(
+        &mut self,
+        proto_msgs: Vec<Any>,
+        signer: &Signer,
+    ) -> Result<PendingEvents, Error> {
+        crate::time!("broadcast_msgs");
+
         if proto_msgs.is_empty() {
-            return Ok(vec![]);
+            return Ok(Box::new(|| Ok(vec![])));
         }
+
+        self.refresh_gas_price();
+
+        let key_name = self.key_name_of(signer)?;
+
         let mut tx_sync_results = vec![];
 
-        let mut n = 0;
-        let mut size = 0;
-        let mut msg_batch = vec![];
-        for msg in proto_msgs.iter() {
-            msg_batch.push(msg.clone());
-            let mut buf = Vec::new();
-            prost::Message::encode(msg, &mut buf).unwrap();
-            n += 1;
-            size += buf.len();
-            if n >= self.max_msg_num() || size >= self.max_tx_size() {
-                let events_per_tx = vec![IbcEvent::Empty("".to_string()); msg_batch.len()];
-                let tx_sync_result = self.send_tx(msg_batch)?;
-                tx_sync_results.push(TxSyncResult {
-                    response: tx_sync_result,
-                    events: events_per_tx,
-                });
-                n = 0;
-                size = 0;
-                msg_batch = vec![];
-            }
-        }
-        if !msg_batch.is_empty() {
-            let events_per_tx = vec![IbcEvent::Empty("".to_string()); msg_batch.len()];
-            let tx_sync_result = self.send_tx(msg_batch)?;
-            tx_sync_results.push(TxSyncResult {
-                response: tx_sync_result,
-                events: events_per_tx,
-            });
+        let batches = batch::batch_messages(proto_msgs, self.max_msg_num(), self.max_tx_size());
+        for msg_batch in batches {
+            self.send_batch(&key_name, msg_batch, &mut tx_sync_results)?;
         }
 
-        let tx_sync_results = self.wait_for_block_commits(tx_sync_results)?;
+        crate::telemetry!(self.refresh_wallet_balance(&key_name));
 
-        let events = tx_sync_results
-            .into_iter()
-            .map(|el| el.events)
-            .flatten()
-            .collect();
+        let commit_waiter = self.commit_waiter();
+
+        Ok(Box::new(move || {
+            let tx_sync_results = commit_waiter.wait_for_block_commits(tx_sync_results)?;
 
-        Ok(events)
+            let events = tx_sync_results
+                .into_iter()
+                .map(|el| el.events)
+                .flatten()
+                .collect();
+
+            Ok(events)
+        }))
     }
 
     /// Get the account for the signer
     fn get_signer(&mut self) -> Result<Signer, Error> {
         crate::time!("get_signer");
 
-        // Get the key from key seed file
-        let key = self
-            .keybase()
-            .get_key(&self.config.key_name)
-            .map_err(|e| Kind::KeyBase.context(e))?;
+        self.key_signer(&self.config.key_name)
+    }
 
-        let bech32 = encode_to_bech32(&key.address.to_hex(), &self.config.account_prefix)?;
-        Ok(Signer::new(bech32))
+    /// Get the signer of the next key among the main key and the additional keys of the chain,
+    /// such that the transactions of the relaying paths of the chain can be included in the
+    /// same block without depending on each other's account sequence.
+    fn next_signer(&mut self) -> Result<Signer, Error> {
+        crate::time!("next_signer");
+
+        let key_count = self.config.additional_key_names.len() + 1;
+        let index = self.next_key % key_count;
+        self.next_key = index + 1;
+
+        let key_name = match index {
+            0 => self.config.key_name.clone(),
+            i => self.config.additional_key_names[i - 1].clone(),
+        };
+
+        self.key_signer(&key_name)
     }
 
     /// Get the signing key
@@ -841,6 +1437,37 @@ impl Chain for CosmosSdkChain {
         Ok(key)
     }
 
+    fn state_store(&self) -> Option<&StateStore> {
+        Some(&self.state)
+    }
+
+    fn query_account_status(&self) -> Result<AccountStatus, Error> {
+        crate::time!("query_account_status");
+
+        let address = self.key_account(&self.config.key_name)?;
+        let account = self.endpoints.with_failover(|endpoint| {
+            self.block_on(query_account(endpoint.grpc_addr.clone(), address.clone()))
+        })?;
+        let balance = self.endpoints.with_failover(|endpoint| {
+            self.block_on(grpc::query_balance(
+                endpoint.grpc_addr.clone(),
+                address.clone(),
+                self.gas_price().denom.clone(),
+            ))
+        })?;
+
+        Ok(AccountStatus {
+            address,
+            sequence: account.sequence,
+            balance: balance.amount,
+            denom: balance.denom,
+        })
+    }
+
+    fn query_fees_paid(&self) -> Result<FeesPaid, Error> {
+        Ok(self.fees_paid.clone())
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!("query_commitment_prefix");
 
@@ -850,22 +1477,57 @@ impl Chain for CosmosSdkChain {
         ))
     }
 
-    /// Query the latest height the chain is at via a RPC query
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
+        crate::time!("query_compatible_versions");
+
+        // The versions were recorded by the health check, unless no node passed it
+        if let Some(versions) = &self.compatible_versions {
+            return Ok(versions.clone());
+        }
+
+        // The IBC module does not expose its supported connection versions, so derive them
+        // from the version of the IBC module the application was built with.
+        let response = self.endpoints.with_failover(|endpoint| {
+            let mut client = self
+                .block_on(ServiceClient::connect(endpoint.grpc_addr.clone()))
+                .map_err(|e| Kind::Grpc.context(e))?;
+
+            let request = tonic::Request::new(GetNodeInfoRequest {});
+
+            self.block_on(client.get_node_info(request))
+                .map_err(|e| Kind::Grpc.context(e).into())
+        })?;
+
+        let version = response.into_inner().application_version.ok_or_else(|| {
+            Kind::Grpc.context(
+                "the gRPC response contains no application version information".to_string(),
+            )
+        })?;
+
+        compatibility::supported_connection_versions(&version)
+            .map_err(|e| Kind::Grpc.context(e).into())
+    }
+
     fn query_latest_height(&self) -> Result<ICSHeight, Error> {
         crate::time!("query_latest_height");
 
-        let status = self
-            .block_on(self.rpc_client().status())
-            .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
+        // A node which is catching up is unhealthy, so that the height is queried from another node
+        let status = self.endpoints.with_failover(|endpoint| {
+            let status = self
+                .block_on(endpoint.rpc_client.status())
+                .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e))?;
+
+            if status.sync_info.catching_up {
+                fail!(
+                    Kind::Rpc(endpoint.rpc_addr.clone()),
+                    "node at {} running chain {} not caught up",
+                    endpoint.rpc_addr,
+                    self.config().id,
+                );
+            }
 
-        if status.sync_info.catching_up {
-            fail!(
-                Kind::LightClient(self.config.rpc_addr.to_string()),
-                "node at {} running chain {} not caught up",
-                self.config().rpc_addr,
-                self.config().id,
-            );
-        }
+            Ok(status)
+        })?;
 
         Ok(ICSHeight {
             revision_number: ChainId::chain_version(status.node_info.network.as_str()),
@@ -879,18 +1541,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
         crate::time!("query_chain_clients");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
-
-        let request = tonic::Request::new(request);
         let response = self
-            .block_on(client.client_states(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
+
+                let request = tonic::Request::new(request.clone());
+
+                self.block_on(client.client_states(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         // Deserialize into domain type
@@ -937,18 +1603,20 @@ impl Chain for CosmosSdkChain {
     ) -> Result<(Self::ClientState, MerkleProof), Error> {
         crate::time!("query_upgraded_client_state");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            let mut client = self
+                .block_on(
+                    ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
+                        endpoint.grpc_addr.clone(),
+                    ),
+                )
+                .map_err(|e| Kind::Grpc.context(e))?;
 
-        let req = tonic::Request::new(QueryCurrentPlanRequest {});
-        let response = self
-            .block_on(client.current_plan(req))
-            .map_err(|e| Kind::Grpc.context(e))?;
+            let request = tonic::Request::new(QueryCurrentPlanRequest {});
+
+            self.block_on(client.current_plan(request))
+                .map_err(|e| Kind::Grpc.context(e).into())
+        })?;
 
         let upgraded_client_state_raw = response
             .into_inner()
@@ -985,20 +1653,22 @@ impl Chain for CosmosSdkChain {
         let tm_height =
             Height::try_from(height.revision_height).map_err(|e| Kind::InvalidHeight.context(e))?;
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            let mut client = self
+                .block_on(
+                    ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
+                        endpoint.grpc_addr.clone(),
+                    ),
+                )
+                .map_err(|e| Kind::Grpc.context(e))?;
 
-        let req = tonic::Request::new(QueryUpgradedConsensusStateRequest {
-            last_height: tm_height.into(),
-        });
-        let response = self
-            .block_on(client.upgraded_consensus_state(req))
-            .map_err(|e| Kind::Grpc.context(e))?;
+            let request = tonic::Request::new(QueryUpgradedConsensusStateRequest {
+                last_height: tm_height.into(),
+            });
+
+            self.block_on(client.upgraded_consensus_state(request))
+                .map_err(|e| Kind::Grpc.context(e).into())
+        })?;
 
         let upgraded_consensus_state_raw = response
             .into_inner()
@@ -1031,18 +1701,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<AnyConsensusStateWithHeight>, Error> {
         crate::time!("query_chain_clients");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
-
-        let request = tonic::Request::new(request);
         let response = self
-            .block_on(client.consensus_states(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
+
+                let request = tonic::Request::new(request.clone());
+
+                self.block_on(client.consensus_states(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         let mut consensus_states: Vec<AnyConsensusStateWithHeight> = response
@@ -1075,20 +1749,27 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<ConnectionId>, Error> {
         crate::time!("query_connections");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self.endpoints.with_failover(|endpoint| {
+            let mut client = self
+                .block_on(
+                    ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
+                        endpoint.grpc_addr.clone(),
+                    ),
+                )
+                .map_err(|e| Kind::Grpc.context(e))?;
+
+            let request = tonic::Request::new(request.clone());
 
-        let request = tonic::Request::new(request);
+            match self.block_on(client.client_connections(request)) {
+                Ok(res) => Ok(Some(res.into_inner())),
+                Err(e) if e.code() == tonic::Code::NotFound => Ok(None),
+                Err(e) => Err(Kind::Grpc.context(e).into()),
+            }
+        })?;
 
-        let response = match self.block_on(client.client_connections(request)) {
-            Ok(res) => res.into_inner(),
-            Err(e) if e.code() == tonic::Code::NotFound => return Ok(vec![]),
-            Err(e) => return Err(Kind::Grpc.context(e).into()),
+        let response = match response {
+            Some(response) => response,
+            None => return Ok(vec![]),
         };
 
         // TODO: add warnings for any identifiers that fail to parse (below).
@@ -1109,19 +1790,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
         crate::time!("query_connections");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.connections(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.connections(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         // TODO: add warnings for any identifiers that fail to parse (below).
@@ -1142,17 +1826,16 @@ impl Chain for CosmosSdkChain {
         height: ICSHeight,
     ) -> Result<ConnectionEnd, Error> {
         async fn do_query_connection(
-            chain: &CosmosSdkChain,
+            grpc_addr: Uri,
             connection_id: &ConnectionId,
             height: ICSHeight,
         ) -> Result<ConnectionEnd, Error> {
             use ibc_proto::ibc::core::connection::v1 as connection;
             use tonic::{metadata::MetadataValue, IntoRequest};
 
-            let mut client =
-                connection::query_client::QueryClient::connect(chain.grpc_addr.clone())
-                    .await
-                    .map_err(|e| Kind::Grpc.context(e))?;
+            let mut client = connection::query_client::QueryClient::connect(grpc_addr)
+                .await
+                .map_err(|e| Kind::Grpc.context(e))?;
 
             let mut request = connection::QueryConnectionRequest {
                 connection_id: connection_id.to_string(),
@@ -1192,7 +1875,11 @@ impl Chain for CosmosSdkChain {
             }
         }
 
-        self.block_on(async { do_query_connection(self, connection_id, height).await })
+        self.endpoints.with_failover(|endpoint| {
+            self.block_on(async {
+                do_query_connection(endpoint.grpc_addr.clone(), connection_id, height).await
+            })
+        })
     }
 
     fn query_connection_channels(
@@ -1201,19 +1888,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
         crate::time!("query_connection_channels");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.connection_channels(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.connection_channels(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         // TODO: add warnings for any identifiers that fail to parse (below).
@@ -1233,19 +1923,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
         crate::time!("query_connections");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.channels(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.channels(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         let channels = response
@@ -1280,19 +1973,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Option<IdentifiedAnyClientState>, Error> {
         crate::time!("query_channel_client_state");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.channel_client_state(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.channel_client_state(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         let client_state: Option<IdentifiedAnyClientState> = response
@@ -1309,19 +2005,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<(Vec<PacketState>, ICSHeight), Error> {
         crate::time!("query_packet_commitments");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.packet_commitments(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.packet_commitments(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         let pc = response.commitments;
@@ -1342,19 +2041,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<u64>, Error> {
         crate::time!("query_unreceived_packets");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let mut response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let mut response = self
-            .block_on(client.unreceived_packets(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.unreceived_packets(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -1368,19 +2070,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<(Vec<PacketState>, ICSHeight), Error> {
         crate::time!("query_packet_acknowledgements");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.packet_acknowledgements(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.packet_acknowledgements(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         let pc = response.acknowledgements;
@@ -1401,19 +2106,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Vec<u64>, Error> {
         crate::time!("query_unreceived_acknowledgements");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let mut response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let mut response = self
-            .block_on(client.unreceived_acks(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.unreceived_acks(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -1426,19 +2134,22 @@ impl Chain for CosmosSdkChain {
     ) -> Result<Sequence, Error> {
         crate::time!("query_next_sequence_receive");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(|e| Kind::Grpc.context(e))?;
+        let response = self
+            .endpoints
+            .with_failover(|endpoint| {
+                let mut client = self
+                    .block_on(
+                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                            endpoint.grpc_addr.clone(),
+                        ),
+                    )
+                    .map_err(|e| Kind::Grpc.context(e))?;
 
-        let request = tonic::Request::new(request);
+                let request = tonic::Request::new(request.clone());
 
-        let response = self
-            .block_on(client.next_sequence_receive(request))
-            .map_err(|e| Kind::Grpc.context(e))?
+                self.block_on(client.next_sequence_receive(request))
+                    .map_err(|e| Kind::Grpc.context(e).into())
+            })?
             .into_inner();
 
         Ok(Sequence::from(response.next_sequence_receive))
@@ -1455,6 +2166,8 @@ impl Chain for CosmosSdkChain {
     ///    Therefore, for packets we perform one tx_search for each sequence.
     ///    Alternatively, a single query for all packets could be performed but it would return all
     ///    packets ever sent.
+    /// 3. Packet event range request - returns all the packet events emitted in a range of
+    ///    heights, which are searched for with a single (paginated) query bounding the Tx height.
     fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEvent>, Error> {
         crate::time!("query_txs");
 
@@ -1466,15 +2179,16 @@ impl Chain for CosmosSdkChain {
 
                 for seq in &request.sequences {
                     // query first (and only) Tx that includes the event specified in the query request
-                    let response = self
-                        .block_on(self.rpc_client.tx_search(
+                    let response = self.endpoints.with_failover(|endpoint| {
+                        self.block_on(endpoint.rpc_client.tx_search(
                             packet_query(&request, *seq),
                             false,
                             1,
                             1, // get only the first Tx matching the query
                             Order::Ascending,
                         ))
-                        .map_err(|e| Kind::Grpc.context(e))?;
+                        .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+                    })?;
 
                     assert!(
                         response.txs.len() <= 1,
@@ -1497,6 +2211,39 @@ impl Chain for CosmosSdkChain {
                 Ok(result)
             }
 
+            QueryTxRequest::PacketRange(request) => {
+                crate::time!("query_txs: query packet events in a range of heights");
+
+                let mut result: Vec<IbcEvent> = vec![];
+
+                // Page through all the Tx-es emitting packet events of the channel in the range
+                for page in 1.. {
+                    let response = self.endpoints.with_failover(|endpoint| {
+                        self.block_on(endpoint.rpc_client.tx_search(
+                            packet_range_query(&request),
+                            false,
+                            page,
+                            TXS_PER_PAGE,
+                            Order::Ascending,
+                        ))
+                        .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+                    })?;
+
+                    let fetched = (page - 1) * u32::from(TXS_PER_PAGE) + response.txs.len() as u32;
+                    let last_page = response.txs.is_empty() || fetched >= response.total_count;
+
+                    for tx in response.txs {
+                        result.extend(packets_from_tx_search_response(&request, tx));
+                    }
+
+                    if last_page {
+                        break;
+                    }
+                }
+
+                Ok(result)
+            }
+
             QueryTxRequest::Client(request) => {
                 crate::time!("query_txs: single client update event");
 
@@ -1506,15 +2253,16 @@ impl Chain for CosmosSdkChain {
                 // same header as the first one, otherwise a subsequent transaction would have
                 // failed on chain. Therefore only one Tx is of interest and current API returns
                 // the first one.
-                let mut response = self
-                    .block_on(self.rpc_client.tx_search(
+                let mut response = self.endpoints.with_failover(|endpoint| {
+                    self.block_on(endpoint.rpc_client.tx_search(
                         header_query(&request),
                         false,
                         1,
                         1, // get only the first Tx matching the query
                         Order::Ascending,
                     ))
-                    .map_err(|e| Kind::Grpc.context(e))?;
+                    .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e).into())
+                })?;
 
                 if response.txs.is_empty() {
                     return Ok(vec![]);
@@ -1532,24 +2280,7 @@ impl Chain for CosmosSdkChain {
                 Ok(event.into_iter().collect())
             }
 
-            QueryTxRequest::Transaction(tx) => {
-                let mut response = self
-                    .block_on(self.rpc_client.tx_search(
-                        tx_hash_query(&tx),
-                        false,
-                        1,
-                        1, // get only the first Tx matching the query
-                        Order::Ascending,
-                    ))
-                    .map_err(|e| Kind::Grpc.context(e))?;
-
-                if response.txs.is_empty() {
-                    Ok(vec![])
-                } else {
-                    let tx = response.txs.remove(0);
-                    Ok(all_ibc_events_from_tx_search_response(self.id(), tx))
-                }
-            }
+            QueryTxRequest::Transaction(tx) => self.commit_waiter().query_tx_events(&tx),
         }
     }
 
@@ -1684,10 +2415,12 @@ impl Chain for CosmosSdkChain {
                 channel_id,
                 sequence,
             },
-            PacketMsgType::TimeoutOrdered => Path::SeqRecvs {
-                0: port_id,
-                1: channel_id,
-            },
+            PacketMsgType::TimeoutOrdered | PacketMsgType::TimeoutOnCloseOrdered => {
+                Path::SeqRecvs {
+                    0: port_id,
+                    1: channel_id,
+                }
+            }
             PacketMsgType::TimeoutOnClose => Path::Receipts {
                 port_id,
                 channel_id,
@@ -1774,6 +2507,27 @@ fn packet_query(request: &QueryPacketEventDataRequest, seq: Sequence) -> Query {
     )
 }
 
+fn packet_range_query(request: &QueryPacketEventRangeRequest) -> Query {
+    tendermint_rpc::query::Query::eq(
+        format!("{}.packet_src_channel", request.event_id.as_str()),
+        request.source_channel_id.to_string(),
+    )
+    .and_eq(
+        format!("{}.packet_src_port", request.event_id.as_str()),
+        request.source_port_id.to_string(),
+    )
+    .and_eq(
+        format!("{}.packet_dst_channel", request.event_id.as_str()),
+        request.destination_channel_id.to_string(),
+    )
+    .and_eq(
+        format!("{}.packet_dst_port", request.event_id.as_str()),
+        request.destination_port_id.to_string(),
+    )
+    .and_gt("tx.height", request.from_height.revision_height)
+    .and_lte("tx.height", request.to_height.revision_height)
+}
+
 fn header_query(request: &QueryClientEventRequest) -> Query {
     tendermint_rpc::query::Query::eq(
         format!("{}.client_id", request.event_id.as_str()),
@@ -1833,6 +2587,34 @@ fn packet_from_tx_search_response(
         })
 }
 
+// Extracts from the Tx all the packet events of the requested type and channel.
+fn packets_from_tx_search_response(
+    request: &QueryPacketEventRangeRequest,
+    response: ResultTx,
+) -> Vec<IbcEvent> {
+    response
+        .tx_result
+        .events
+        .into_iter()
+        .filter(|abci_event| abci_event.type_str == request.event_id.as_str())
+        .flat_map(|abci_event| ChannelEvents::try_from_tx(&abci_event))
+        .filter(|event| {
+            let packet = match event {
+                IbcEvent::SendPacket(send_ev) => Some(&send_ev.packet),
+                IbcEvent::WriteAcknowledgement(ack_ev) => Some(&ack_ev.packet),
+                _ => None,
+            };
+
+            packet.map_or(false, |packet| {
+                packet.source_port == request.source_port_id
+                    && packet.source_channel == request.source_channel_id
+                    && packet.destination_port == request.destination_port_id
+                    && packet.destination_channel == request.destination_channel_id
+            })
+        })
+        .collect()
+}
+
 // Extracts from the Tx the update client event for the requested client and height.
 // Note: in the Tx, there may have been multiple events, some of them may be
 // for update of other clients that are not relevant to the request.
@@ -1889,7 +2671,7 @@ fn all_ibc_events_from_tx_search_response(chain_id: &ChainId, response: ResultTx
 
 /// Perform a generic `abci_query`, and return the corresponding deserialized response data.
 async fn abci_query(
-    chain: &CosmosSdkChain,
+    endpoint: &Endpoint,
     path: TendermintABCIPath,
     data: String,
     height: Height,
@@ -1902,15 +2684,15 @@ async fn abci_query(
     };
 
     // Use the Tendermint-rs RPC client to do the query.
-    let response = chain
-        .rpc_client()
+    let response = endpoint
+        .rpc_client
         .abci_query(Some(path), data.into_bytes(), height, prove)
         .await
-        .map_err(|e| Kind::Rpc(chain.config.rpc_addr.clone()).context(e))?;
+        .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e))?;
 
     if !response.code.is_ok() {
         // Fail with response log.
-        return Err(Kind::Rpc(chain.config.rpc_addr.clone())
+        return Err(Kind::Rpc(endpoint.rpc_addr.clone())
             .context(response.log.to_string())
             .into());
     }
@@ -1937,25 +2719,24 @@ async fn abci_query(
 
 /// Perform a `broadcast_tx_sync`, and return the corresponding deserialized response data.
 async fn broadcast_tx_sync(
-    chain: &CosmosSdkChain,
+    endpoint: &Endpoint,
     data: Vec<u8>,
 ) -> Result<Response, anomaly::Error<Kind>> {
-    let response = chain
-        .rpc_client()
+    let response = endpoint
+        .rpc_client
         .broadcast_tx_sync(data.into())
         .await
-        .map_err(|e| Kind::Rpc(chain.config.rpc_addr.clone()).context(e))?;
+        .map_err(|e| Kind::Rpc(endpoint.rpc_addr.clone()).context(e))?;
 
     Ok(response)
 }
 
 /// Uses the GRPC client to retrieve the account sequence
-async fn query_account(chain: &CosmosSdkChain, address: String) -> Result<BaseAccount, Error> {
-    let mut client = ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient::connect(
-        chain.grpc_addr.clone(),
-    )
-    .await
-    .map_err(|e| Kind::Grpc.context(e))?;
+async fn query_account(grpc_addr: Uri, address: String) -> Result<BaseAccount, Error> {
+    let mut client =
+        ibc_proto::cosmos::auth::v1beta1::query_client::QueryClient::connect(grpc_addr)
+            .await
+            .map_err(|e| Kind::Grpc.context(e))?;
 
     let request = tonic::Request::new(QueryAccountRequest { address });
 
@@ -2051,6 +2832,15 @@ fn mul_ceil(a: u64, f: f64) -> u64 {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn parse_expected_account_sequence() {
+        let log = "account sequence mismatch, expected 10, got 9: incorrect account sequence";
+        assert_eq!(super::parse_expected_account_sequence(log), Some(10));
+
+        let log = "insufficient fees; got: 0stake required: 300stake: insufficient fee";
+        assert_eq!(super::parse_expected_account_sequence(log), None);
+    }
+
     #[test]
     fn mul_ceil() {
         assert_eq!(super::mul_ceil(300_000, 0.001), 300);
@@ -0,0 +1,140 @@
+//! Splitting of the messages sent to a chain into transactions.
+
+use prost::Message;
+use prost_types::Any;
+
+const UPDATE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateClient";
+
+fn is_update_client(msg: &Any) -> bool {
+    msg.type_url == UPDATE_CLIENT_TYPE_URL
+}
+
+/// Splits the given messages in batches which do not exceed the maximum number of messages
+/// nor the maximum size of a transaction.
+///
+/// A client update is never the last message of a batch followed by other messages, as the
+/// messages following a client update usually carry proofs which are verified against it,
+/// and thus cannot be simulated in a separate transaction.
+pub fn batch_messages(msgs: Vec<Any>, max_msg_num: usize, max_tx_size: usize) -> Vec<Vec<Any>> {
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let mut size = 0;
+
+    for msg in msgs {
+        size += msg.encoded_len();
+        batch.push(msg);
+
+        if batch.len() >= max_msg_num || size >= max_tx_size {
+            let carried = match batch.last() {
+                Some(last) if batch.len() > 1 && is_update_client(last) => batch.pop(),
+                _ => None,
+            };
+
+            batches.push(std::mem::take(&mut batch));
+            size = 0;
+
+            if let Some(update) = carried {
+                size = update.encoded_len();
+                batch.push(update);
+            }
+        }
+    }
+
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+
+    batches
+}
+
+/// Splits the given batch of at least two messages in two halves, such that a client update
+/// and the message following it end up in the same half.
+pub fn split_batch(mut msgs: Vec<Any>) -> (Vec<Any>, Vec<Any>) {
+    let mid = msgs.len() / 2;
+    let ends_batch = |i: usize| !is_update_client(&msgs[i - 1]);
+
+    let at = (1..=mid)
+        .rev()
+        .find(|i| ends_batch(*i))
+        .or_else(|| (mid + 1..msgs.len()).find(|i| ends_batch(*i)))
+        .unwrap_or(mid);
+
+    let second = msgs.split_off(at);
+    (msgs, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use prost_types::Any;
+    use test_env_log::test;
+
+    use super::{batch_messages, split_batch, UPDATE_CLIENT_TYPE_URL};
+
+    const RECV_PACKET_TYPE_URL: &str = "/ibc.core.channel.v1.MsgRecvPacket";
+
+    fn msgs(kinds: &str) -> Vec<Any> {
+        kinds
+            .chars()
+            .map(|kind| Any {
+                type_url: match kind {
+                    'u' => UPDATE_CLIENT_TYPE_URL,
+                    _ => RECV_PACKET_TYPE_URL,
+                }
+                .to_string(),
+                value: vec![0; 10],
+            })
+            .collect()
+    }
+
+    fn kinds(batch: &[Any]) -> String {
+        batch
+            .iter()
+            .map(|msg| match msg.type_url.as_str() {
+                UPDATE_CLIENT_TYPE_URL => 'u',
+                _ => 'r',
+            })
+            .collect()
+    }
+
+    fn batches(kinds_of_msgs: &str, max_msg_num: usize) -> Vec<String> {
+        batch_messages(msgs(kinds_of_msgs), max_msg_num, usize::MAX)
+            .iter()
+            .map(|batch| kinds(batch))
+            .collect()
+    }
+
+    #[test]
+    fn batch_by_number_of_messages() {
+        assert_eq!(batches("rrrrr", 2), vec!["rr", "rr", "r"]);
+        assert_eq!(batches("urrurr", 3), vec!["urr", "urr"]);
+        assert_eq!(batches("rurrr", 2), vec!["r", "ur", "rr"]);
+        assert_eq!(batches("u", 1), vec!["u"]);
+    }
+
+    #[test]
+    fn batch_by_size() {
+        let msgs = msgs("rrrrr");
+        let max_tx_size = 2 * msgs[0].encoded_len();
+
+        let batches: Vec<String> = batch_messages(msgs, 30, max_tx_size)
+            .iter()
+            .map(|batch| kinds(batch))
+            .collect();
+
+        assert_eq!(batches, vec!["rr", "rr", "r"]);
+    }
+
+    #[test]
+    fn split_keeps_client_updates_with_the_next_message() {
+        let split = |kinds_of_msgs: &str| {
+            let (first, second) = split_batch(msgs(kinds_of_msgs));
+            (kinds(&first), kinds(&second))
+        };
+
+        assert_eq!(split("rrrr"), ("rr".to_string(), "rr".to_string()));
+        assert_eq!(split("urrr"), ("ur".to_string(), "rr".to_string()));
+        assert_eq!(split("rurr"), ("r".to_string(), "urr".to_string()));
+        assert_eq!(split("ur"), ("u".to_string(), "r".to_string()));
+    }
+}
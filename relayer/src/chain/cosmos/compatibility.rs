@@ -2,6 +2,7 @@
 
 use thiserror::Error;
 
+use ibc::ics03_connection::version::{get_compatible_versions, Version};
 use ibc_proto::cosmos::base::tendermint::v1beta1::VersionInfo;
 
 /// Specifies the SDK module path, as it is expected to appear
@@ -25,6 +26,10 @@ const SDK_MODULE_NAME: &str = "cosmos/cosmos-sdk";
 /// [features]: https://hermes.informal.systems/features.html
 const SDK_MODULE_VERSION_REQ: &str = ">=0.41.3, <=0.42.6";
 
+/// Specifies the path of the standalone IBC module, for applications
+/// which no longer rely on the IBC module embedded in the SDK.
+const IBC_GO_MODULE_NAME: &str = "cosmos/ibc-go";
+
 /// Helper struct to capture all the reported information of an
 /// IBC application, e.g., `gaiad`.
 #[derive(Clone, Debug)]
@@ -45,6 +50,9 @@ pub enum Diagnostic {
     #[error("no SDK module '{pattern}' was found for application {app}")]
     SdkModuleNotFound { pattern: String, app: AppInfo },
 
+    #[error("no IBC module was found for application {app}")]
+    IbcModuleNotFound { app: AppInfo },
+
     #[error("failed parsing the SDK module ('{module_path}') version number '{raw_version}' into a semver for application {app}; cause: {cause}")]
     VersionParsingFailed {
         module_path: String,
@@ -120,3 +128,31 @@ pub(crate) fn run_diagnostic(v: VersionInfo) -> Option<Diagnostic> {
         }
     }
 }
+
+/// Returns the connection versions supported by the IBC module
+/// of the application described by the provided [`VersionInfo`].
+///
+/// The IBC module is either the standalone `ibc-go` module, or the
+/// one embedded in the Cosmos SDK. All the releases of both modules
+/// support the same versions, so only the presence of either module
+/// is checked for.
+///
+/// Returns a [`Diagnostic`] if the application includes neither.
+pub(crate) fn supported_connection_versions(v: &VersionInfo) -> Result<Vec<Version>, Diagnostic> {
+    let has_ibc_module = v
+        .build_deps
+        .iter()
+        .any(|m| m.path.contains(IBC_GO_MODULE_NAME) || m.path.contains(SDK_MODULE_NAME));
+
+    if has_ibc_module {
+        Ok(get_compatible_versions())
+    } else {
+        Err(Diagnostic::IbcModuleNotFound {
+            app: AppInfo {
+                app_name: v.app_name.clone(),
+                version: v.version.clone(),
+                git_commit: v.git_commit.clone(),
+            },
+        })
+    }
+}
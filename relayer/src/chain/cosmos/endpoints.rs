@@ -0,0 +1,311 @@
+//! The endpoints of the full nodes of a chain, among which requests fail over
+//! when a node is unhealthy.
+
+use std::cmp::Reverse;
+use std::error::Error as StdError;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tendermint_rpc::{HttpClient, Url};
+use tonic::codegen::http::Uri;
+use tonic::Code;
+use tracing::{debug, warn};
+
+use ibc::ics24_host::identifier::ChainId;
+
+use crate::config::NodeEndpoints;
+use crate::error::{Error, Kind};
+
+/// The health score of a node which did not fail recently, which is the highest score
+const MAX_SCORE: u32 = 10;
+
+/// The amount by which the health score of a node is lowered when a request to it fails
+const FAILURE_PENALTY: u32 = 5;
+
+/// The amount by which the health score of a node is raised when a request to it succeeds
+const SUCCESS_REWARD: u32 = 1;
+
+/// The endpoints of a full node of a chain, with an RPC client to the node.
+#[derive(Clone)]
+pub struct Endpoint {
+    pub rpc_addr: Url,
+    pub websocket_addr: Url,
+    pub grpc_addr: Uri,
+    pub rpc_client: HttpClient,
+}
+
+struct Health {
+    /// The health score of each node
+    scores: Vec<u32>,
+    /// The index of the node requests are sent to first
+    active: usize,
+}
+
+/// The endpoints of the full nodes of a chain, ranked by a health score.
+///
+/// Requests are sent to the active node first, and when they fail because of the node,
+/// to the other nodes by decreasing score. The score of a node is lowered by each failed
+/// request, and raised by each successful one, and the active node is replaced by the
+/// healthiest node as soon as the latter has a strictly higher score.
+#[derive(Clone)]
+pub struct EndpointPool {
+    chain_id: ChainId,
+    endpoints: Arc<Vec<Endpoint>>,
+    health: Arc<Mutex<Health>>,
+}
+
+impl EndpointPool {
+    /// Create a pool over the given nodes, the first of which is initially active.
+    pub fn new(chain_id: ChainId, nodes: &[NodeEndpoints]) -> Result<Self, Error> {
+        let endpoints = nodes
+            .iter()
+            .map(|node| {
+                let rpc_client = HttpClient::new(node.rpc_addr.clone())
+                    .map_err(|e| Kind::Rpc(node.rpc_addr.clone()).context(e))?;
+
+                let grpc_addr = Uri::from_str(&node.grpc_addr.to_string())
+                    .map_err(|e| Kind::Grpc.context(e))?;
+
+                Ok(Endpoint {
+                    rpc_addr: node.rpc_addr.clone(),
+                    websocket_addr: node.websocket_addr.clone(),
+                    grpc_addr,
+                    rpc_client,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        if endpoints.is_empty() {
+            return Err(Kind::Config
+                .context(format!("no node configured for chain {}", chain_id))
+                .into());
+        }
+
+        let health = Health {
+            scores: vec![MAX_SCORE; endpoints.len()],
+            active: 0,
+        };
+
+        let pool = Self {
+            chain_id,
+            endpoints: Arc::new(endpoints),
+            health: Arc::new(Mutex::new(health)),
+        };
+
+        pool.report_active(0);
+
+        Ok(pool)
+    }
+
+    /// The endpoints of all the nodes, in the configured order.
+    pub fn endpoints(&self) -> &[Endpoint] {
+        &self.endpoints
+    }
+
+    /// The index of the active node.
+    pub fn active_index(&self) -> usize {
+        self.health().active
+    }
+
+    /// The endpoints of the active node.
+    pub fn active(&self) -> &Endpoint {
+        &self.endpoints[self.active_index()]
+    }
+
+    /// Send the given request to the active node and, as long as it fails because of the node,
+    /// to the other nodes by decreasing health score. Returns the result of the first request
+    /// which does not fail because of the node, or the error of the last one.
+    pub fn with_failover<T>(
+        &self,
+        mut request: impl FnMut(&Endpoint) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut last_error = None;
+
+        for index in self.candidates() {
+            match request(&self.endpoints[index]) {
+                Err(e) if is_node_error(&e) => {
+                    self.record_failure(index, &e);
+                    last_error = Some(e);
+                }
+                result => {
+                    self.record_success(index);
+                    return result;
+                }
+            }
+        }
+
+        Err(last_error.expect("there is at least one node"))
+    }
+
+    /// Raise the health score of the node with the given index, after a successful request.
+    pub fn record_success(&self, index: usize) {
+        let mut health = self.health();
+        health.scores[index] = (health.scores[index] + SUCCESS_REWARD).min(MAX_SCORE);
+    }
+
+    /// Lower the health score of the node with the given index, after a request failed because
+    /// of the node, and fail over to the healthiest node if it is now healthier than the active one.
+    pub fn record_failure(&self, index: usize, error: &Error) {
+        let mut health = self.health();
+        health.scores[index] = health.scores[index].saturating_sub(FAILURE_PENALTY);
+
+        debug!(
+            "[{}] request to node {} failed, lowering its health score to {}: {}",
+            self.chain_id, self.endpoints[index].rpc_addr, health.scores[index], error
+        );
+
+        let active = health.active;
+        let healthiest = self.rank(&health)[0];
+
+        if health.scores[healthiest] > health.scores[active] {
+            health.active = healthiest;
+            drop(health);
+
+            warn!(
+                "[{}] failing over from node {} to node {}",
+                self.chain_id, self.endpoints[active].rpc_addr, self.endpoints[healthiest].rpc_addr
+            );
+
+            self.report_active(healthiest);
+        }
+    }
+
+    /// The indices of the nodes to send a request to, in turn.
+    fn candidates(&self) -> Vec<usize> {
+        let health = self.health();
+        let active = health.active;
+
+        let mut candidates = vec![active];
+        candidates.extend(self.rank(&health).into_iter().filter(|i| *i != active));
+        candidates
+    }
+
+    /// The indices of the nodes by decreasing health score, and in the configured order
+    /// among nodes with the same score.
+    fn rank(&self, health: &Health) -> Vec<usize> {
+        let mut ranked = (0..self.endpoints.len()).collect::<Vec<_>>();
+        ranked.sort_by_key(|i| Reverse(health.scores[*i]));
+        ranked
+    }
+
+    fn health(&self) -> MutexGuard<'_, Health> {
+        self.health.lock().expect("poisoned lock")
+    }
+
+    #[allow(unused_variables)]
+    fn report_active(&self, index: usize) {
+        crate::telemetry!({
+            let endpoint = &self.endpoints[index];
+            let telemetry = ibc_telemetry::global();
+            telemetry.active_endpoint(&self.chain_id, "rpc", &endpoint.rpc_addr.to_string());
+            telemetry.active_endpoint(&self.chain_id, "grpc", &endpoint.grpc_addr.to_string());
+        });
+    }
+}
+
+/// Whether the given error was raised by the transport to a node, or because the node is
+/// unavailable or too slow to answer, rather than by the processing of a request which the node
+/// answered. In particular, the gRPC statuses reporting the failure of a request by the
+/// application, e.g., the failed simulation of a transaction, are not node errors.
+fn is_node_error(error: &Error) -> bool {
+    match error.kind() {
+        Kind::Rpc(_) => true,
+        Kind::Grpc => error.source().map_or(false, |source| {
+            source.is::<tonic::transport::Error>()
+                || source
+                    .downcast_ref::<tonic::Status>()
+                    .map_or(false, |status| {
+                        matches!(status.code(), Code::Unavailable | Code::DeadlineExceeded)
+                    })
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use test_env_log::test;
+    use tonic::{Code, Status};
+
+    use ibc::ics24_host::identifier::ChainId;
+
+    use crate::config::NodeEndpoints;
+    use crate::error::{Error, Kind};
+
+    use super::EndpointPool;
+
+    fn node(port: u16) -> NodeEndpoints {
+        NodeEndpoints {
+            rpc_addr: format!("http://127.0.0.1:{}", port).parse().unwrap(),
+            websocket_addr: format!("ws://127.0.0.1:{}/websocket", port)
+                .parse()
+                .unwrap(),
+            grpc_addr: format!("http://127.0.0.1:{}", port + 1).parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn fail_over_to_healthy_nodes() {
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let pool = EndpointPool::new(chain_id, &[node(26657), node(26757), node(26857)]).unwrap();
+
+        // Only the last node answers
+        let requested = RefCell::new(vec![]);
+        let request = |port: u16| {
+            let requested = &requested;
+            move |endpoint: &super::Endpoint| -> Result<u16, Error> {
+                let endpoint_port = endpoint.rpc_addr.port();
+                requested.borrow_mut().push(endpoint_port);
+                if endpoint_port == port {
+                    Ok(port)
+                } else {
+                    Err(Kind::Rpc(endpoint.rpc_addr.clone()).into())
+                }
+            }
+        };
+
+        assert_eq!(pool.with_failover(request(26857)).unwrap(), 26857);
+        assert_eq!(*requested.borrow(), vec![26657, 26757, 26857]);
+        assert_eq!(pool.active_index(), 2);
+
+        // The active node is requested first
+        requested.borrow_mut().clear();
+        assert_eq!(pool.with_failover(request(26857)).unwrap(), 26857);
+        assert_eq!(*requested.borrow(), vec![26857]);
+
+        // Errors which are not raised by the node are returned as is
+        let result =
+            pool.with_failover(|_| -> Result<(), Error> { Err(Kind::EmptyResponseValue.into()) });
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            Kind::EmptyResponseValue
+        ));
+        assert_eq!(pool.active_index(), 2);
+
+        // So are the failures of gRPC requests reported by the application
+        requested.borrow_mut().clear();
+        let grpc_request = |code: Code| {
+            let requested = &requested;
+            move |endpoint: &super::Endpoint| -> Result<(), Error> {
+                requested.borrow_mut().push(endpoint.rpc_addr.port());
+                Err(Kind::Grpc.context(Status::new(code, "failed")).into())
+            }
+        };
+        assert!(pool
+            .with_failover(grpc_request(Code::InvalidArgument))
+            .is_err());
+        assert_eq!(*requested.borrow(), vec![26857]);
+
+        // While the gRPC requests to an unavailable node fail over
+        requested.borrow_mut().clear();
+        assert!(pool.with_failover(grpc_request(Code::Unavailable)).is_err());
+        assert_eq!(requested.borrow().len(), 3);
+
+        // When every node fails, the last error is returned
+        requested.borrow_mut().clear();
+        assert!(pool.with_failover(request(0)).is_err());
+        assert_eq!(requested.borrow().len(), 3);
+    }
+}
@@ -0,0 +1,127 @@
+//! Queries of the gas price of a chain, either from the minimum gas price of its full node,
+//! or from an external HTTP endpoint.
+
+use std::time::Duration;
+
+use serde_derive::Deserialize;
+use tonic::codegen::http::Uri;
+
+use crate::error::{Error, Kind};
+
+use super::grpc::unary_query;
+
+const NODE_CONFIG_PATH: &str = "/cosmos.base.node.v1beta1.Service/Config";
+
+/// Timeouts of the connection to, and of the response of, the gas price HTTP endpoint,
+/// such that an unresponsive endpoint does not block the chain runtime.
+const ENDPOINT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const ENDPOINT_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Request of the `Config` query of the node service of the SDK.
+#[derive(Clone, PartialEq, prost::Message)]
+struct ConfigRequest {}
+
+/// Response to the `Config` query of the node service of the SDK.
+#[derive(Clone, PartialEq, prost::Message)]
+struct ConfigResponse {
+    /// The minimum gas prices accepted by the node, as a list of decimal coins
+    #[prost(string, tag = "1")]
+    minimum_gas_price: String,
+}
+
+#[derive(Deserialize)]
+struct EndpointResponse {
+    price: f64,
+}
+
+/// Query the minimum gas price in the given denomination accepted by the full node
+/// at the given gRPC address.
+pub async fn query_node_min_gas_price(grpc_addr: Uri, denom: &str) -> Result<f64, Error> {
+    let response: ConfigResponse =
+        unary_query(grpc_addr, NODE_CONFIG_PATH, ConfigRequest {}).await?;
+
+    let min_gas_price = response.minimum_gas_price;
+
+    let price = parse_dec_coins_amount(&min_gas_price, denom).ok_or_else(|| {
+        Kind::Grpc.context(format!(
+            "the minimum gas price of the node '{}' has no amount in {}",
+            min_gas_price, denom
+        ))
+    })?;
+
+    validate_price(price).map_err(|e| Kind::Grpc.context(e).into())
+}
+
+/// Query the gas price returned by the HTTP endpoint at the given URL.
+pub fn query_endpoint_gas_price(url: &str) -> Result<f64, Error> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(ENDPOINT_CONNECT_TIMEOUT)
+        .timeout_read(ENDPOINT_READ_TIMEOUT)
+        .build();
+
+    let response = agent
+        .get(url)
+        .call()
+        .map_err(|e| Kind::GasPrice(url.to_string()).context(e.to_string()))?;
+
+    let body = response
+        .into_string()
+        .map_err(|e| Kind::GasPrice(url.to_string()).context(e))?;
+
+    let response: EndpointResponse =
+        serde_json::from_str(&body).map_err(|e| Kind::GasPrice(url.to_string()).context(e))?;
+
+    validate_price(response.price).map_err(|e| Kind::GasPrice(url.to_string()).context(e).into())
+}
+
+/// Reject the gas prices which cannot be paid, ie. which are not finite and positive.
+fn validate_price(price: f64) -> Result<f64, String> {
+    if price.is_finite() && price > 0.0 {
+        Ok(price)
+    } else {
+        Err(format!("invalid gas price {}", price))
+    }
+}
+
+/// Parse the amount in the given denomination out of a list of decimal coins
+/// formatted by the SDK, eg. `0.025uatom,0.1stake`.
+fn parse_dec_coins_amount(dec_coins: &str, denom: &str) -> Option<f64> {
+    dec_coins.split(',').find_map(|coin| {
+        let coin = coin.trim();
+        let split = coin.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        let (amount, coin_denom) = coin.split_at(split);
+
+        if coin_denom == denom {
+            amount.parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use super::{parse_dec_coins_amount, validate_price};
+
+    #[test]
+    fn parse_min_gas_price() {
+        let min_gas_price = "0.025uatom,0.100000000000000000stake";
+
+        assert_eq!(parse_dec_coins_amount(min_gas_price, "uatom"), Some(0.025));
+        assert_eq!(parse_dec_coins_amount(min_gas_price, "stake"), Some(0.1));
+        assert_eq!(parse_dec_coins_amount(min_gas_price, "uosmo"), None);
+        assert_eq!(parse_dec_coins_amount("", "uatom"), None);
+    }
+
+    #[test]
+    fn validate_gas_price() {
+        assert_eq!(validate_price(0.025), Ok(0.025));
+
+        assert!(validate_price(0.0).is_err());
+        assert!(validate_price(-0.025).is_err());
+        assert!(validate_price(f64::NAN).is_err());
+        assert!(validate_price(f64::INFINITY).is_err());
+    }
+}
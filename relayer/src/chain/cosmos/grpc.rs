@@ -0,0 +1,72 @@
+//! Unary gRPC queries to services of the SDK whose clients are not generated in `ibc-proto`.
+
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::codegen::http::Uri;
+use tonic::transport::Endpoint;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+
+use crate::error::{Error, Kind};
+
+const BANK_BALANCE_PATH: &str = "/cosmos.bank.v1beta1.Query/Balance";
+
+/// Request of the `Balance` query of the bank module of the SDK.
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryBalanceRequest {
+    #[prost(string, tag = "1")]
+    address: String,
+    #[prost(string, tag = "2")]
+    denom: String,
+}
+
+/// Response to the `Balance` query of the bank module of the SDK.
+#[derive(Clone, PartialEq, prost::Message)]
+struct QueryBalanceResponse {
+    #[prost(message, optional, tag = "1")]
+    balance: Option<Coin>,
+}
+
+/// Perform the unary gRPC query at the given `path`, eg. `/cosmos.bank.v1beta1.Query/Balance`,
+/// against the node at the given gRPC address.
+pub async fn unary_query<Req, Res>(
+    grpc_addr: Uri,
+    path: &'static str,
+    request: Req,
+) -> Result<Res, Error>
+where
+    Req: prost::Message + Send + 'static,
+    Res: prost::Message + Default + Send + 'static,
+{
+    let channel = Endpoint::from(grpc_addr)
+        .connect()
+        .await
+        .map_err(|e| Kind::Grpc.context(e))?;
+
+    let mut client = tonic::client::Grpc::new(channel);
+    client.ready().await.map_err(|e| Kind::Grpc.context(e))?;
+
+    let response = client
+        .unary(
+            tonic::Request::new(request),
+            PathAndQuery::from_static(path),
+            tonic::codec::ProstCodec::default(),
+        )
+        .await
+        .map_err(|e| Kind::Grpc.context(e))?;
+
+    Ok(response.into_inner())
+}
+
+/// Query the balance in the given denomination of the account with the given address.
+pub async fn query_balance(grpc_addr: Uri, address: String, denom: String) -> Result<Coin, Error> {
+    let request = QueryBalanceRequest {
+        address,
+        denom: denom.clone(),
+    };
+    let response: QueryBalanceResponse = unary_query(grpc_addr, BANK_BALANCE_PATH, request).await?;
+
+    Ok(response.balance.unwrap_or(Coin {
+        denom,
+        amount: "0".to_string(),
+    }))
+}
@@ -46,10 +46,12 @@ use ibc_proto::ibc::core::{
 pub use prod::ProdChainHandle;
 
 use crate::{
+    chain::{AccountStatus, FeesPaid},
     connection::ConnectionMsgType,
     error::Error,
     event::monitor::{EventBatch, Result as MonitorResult},
     keyring::KeyEntry,
+    state::StoredOperationalData,
 };
 
 mod prod;
@@ -103,6 +105,7 @@ pub enum ChainRequest {
 
     SendMsgs {
         proto_msgs: Vec<prost_types::Any>,
+        signer: Option<Signer>,
         reply_to: ReplyTo<Vec<IbcEvent>>,
     },
 
@@ -110,6 +113,10 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Signer>,
     },
 
+    NextSigner {
+        reply_to: ReplyTo<Signer>,
+    },
+
     Key {
         reply_to: ReplyTo<KeyEntry>,
     },
@@ -123,6 +130,40 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Height>,
     },
 
+    QueryAccountStatus {
+        reply_to: ReplyTo<AccountStatus>,
+    },
+
+    QueryFeesPaid {
+        reply_to: ReplyTo<FeesPaid>,
+    },
+
+    QueryProcessedHeight {
+        reply_to: ReplyTo<Option<Height>>,
+    },
+
+    QueryResumeHeight {
+        reply_to: ReplyTo<Option<Height>>,
+    },
+
+    StoreProcessedHeight {
+        height: Height,
+        reply_to: ReplyTo<()>,
+    },
+
+    QueryStoredOperationalData {
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplyTo<Vec<StoredOperationalData>>,
+    },
+
+    StoreOperationalData {
+        port_id: PortId,
+        channel_id: ChannelId,
+        data: Vec<StoredOperationalData>,
+        reply_to: ReplyTo<()>,
+    },
+
     QueryClients {
         request: QueryClientStatesRequest,
         reply_to: ReplyTo<Vec<IdentifiedAnyClientState>>,
@@ -318,14 +359,59 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
     /// and return the list of events emitted by the chain after the transaction was committed.
     fn send_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<IbcEvent>, Error>;
 
+    /// Same as `send_msgs`, with the transactions signed by the key of the given `signer`,
+    /// which the messages were built with.
+    fn send_msgs_signed_by(
+        &self,
+        proto_msgs: Vec<prost_types::Any>,
+        signer: &Signer,
+    ) -> Result<Vec<IbcEvent>, Error>;
+
     fn get_signer(&self) -> Result<Signer, Error>;
 
+    /// Returns the signer to build the next messages with, taking turns among the keys
+    /// of the chain.
+    fn next_signer(&self) -> Result<Signer, Error>;
+
     fn get_key(&self) -> Result<KeyEntry, Error>;
 
     fn module_version(&self, port_id: &PortId) -> Result<String, Error>;
 
     fn query_latest_height(&self) -> Result<Height, Error>;
 
+    fn query_account_status(&self) -> Result<AccountStatus, Error>;
+
+    /// Query the fees paid by the transactions sent to the chain since its runtime started.
+    fn query_fees_paid(&self) -> Result<FeesPaid, Error>;
+
+    /// Query the height of the last batch of events of this chain processed by the supervisor,
+    /// as persisted in the state store of the chain, if any.
+    fn query_processed_height(&self) -> Result<Option<Height>, Error>;
+
+    /// Query the height of the last batch of events of this chain processed by the supervisor
+    /// before the relayer was restarted, from which the relay paths resume, if any.
+    fn query_resume_height(&self) -> Result<Option<Height>, Error>;
+
+    /// Persist the height of the last batch of events of this chain processed by the supervisor.
+    fn store_processed_height(&self, height: Height) -> Result<(), Error>;
+
+    /// Query the operational data persisted by the relay path over the given port and channel
+    /// of this chain.
+    fn query_stored_operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<StoredOperationalData>, Error>;
+
+    /// Persist the operational data scheduled by the relay path over the given port and channel
+    /// of this chain, replacing the previously persisted data.
+    fn store_operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        data: Vec<StoredOperationalData>,
+    ) -> Result<(), Error>;
+
     fn query_clients(
         &self,
         request: QueryClientStatesRequest,
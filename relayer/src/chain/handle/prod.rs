@@ -36,9 +36,11 @@ use ibc_proto::ibc::core::connection::v1::QueryClientConnectionsRequest;
 use ibc_proto::ibc::core::connection::v1::QueryConnectionsRequest;
 
 use crate::{
+    chain::{AccountStatus, FeesPaid},
     connection::ConnectionMsgType,
     error::{Error, Kind},
     keyring::KeyEntry,
+    state::StoredOperationalData,
 };
 
 use super::{reply_channel, ChainHandle, ChainRequest, ReplyTo, Subscription};
@@ -92,6 +94,19 @@ impl ChainHandle for ProdChainHandle {
     fn send_msgs(&self, proto_msgs: Vec<prost_types::Any>) -> Result<Vec<IbcEvent>, Error> {
         self.send(|reply_to| ChainRequest::SendMsgs {
             proto_msgs,
+            signer: None,
+            reply_to,
+        })
+    }
+
+    fn send_msgs_signed_by(
+        &self,
+        proto_msgs: Vec<prost_types::Any>,
+        signer: &Signer,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        self.send(|reply_to| ChainRequest::SendMsgs {
+            proto_msgs,
+            signer: Some(signer.clone()),
             reply_to,
         })
     }
@@ -100,6 +115,10 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::Signer { reply_to })
     }
 
+    fn next_signer(&self) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::NextSigner { reply_to })
+    }
+
     fn get_key(&self) -> Result<KeyEntry, Error> {
         self.send(|reply_to| ChainRequest::Key { reply_to })
     }
@@ -115,6 +134,52 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::QueryLatestHeight { reply_to })
     }
 
+    fn query_account_status(&self) -> Result<AccountStatus, Error> {
+        self.send(|reply_to| ChainRequest::QueryAccountStatus { reply_to })
+    }
+
+    fn query_fees_paid(&self) -> Result<FeesPaid, Error> {
+        self.send(|reply_to| ChainRequest::QueryFeesPaid { reply_to })
+    }
+
+    fn query_processed_height(&self) -> Result<Option<Height>, Error> {
+        self.send(|reply_to| ChainRequest::QueryProcessedHeight { reply_to })
+    }
+
+    fn query_resume_height(&self) -> Result<Option<Height>, Error> {
+        self.send(|reply_to| ChainRequest::QueryResumeHeight { reply_to })
+    }
+
+    fn store_processed_height(&self, height: Height) -> Result<(), Error> {
+        self.send(|reply_to| ChainRequest::StoreProcessedHeight { height, reply_to })
+    }
+
+    fn query_stored_operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<StoredOperationalData>, Error> {
+        self.send(|reply_to| ChainRequest::QueryStoredOperationalData {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            reply_to,
+        })
+    }
+
+    fn store_operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        data: Vec<StoredOperationalData>,
+    ) -> Result<(), Error> {
+        self.send(|reply_to| ChainRequest::StoreOperationalData {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            data,
+            reply_to,
+        })
+    }
+
     fn query_clients(
         &self,
         request: QueryClientStatesRequest,
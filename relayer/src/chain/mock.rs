@@ -12,7 +12,10 @@ use ibc::events::IbcEvent;
 use ibc::ics02_client::client_consensus::{AnyConsensusState, AnyConsensusStateWithHeight};
 use ibc::ics02_client::client_state::{AnyClientState, IdentifiedAnyClientState};
 use ibc::ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd};
+use ibc::ics03_connection::context::ConnectionReader;
+use ibc::ics03_connection::version::{get_compatible_versions, Version};
 use ibc::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::ics04_channel::context::ChannelReader;
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc::ics07_tendermint::client_state::{AllowUpdate, ClientState as TendermintClientState};
 use ibc::ics07_tendermint::consensus_state::ConsensusState as TendermintConsensusState;
@@ -38,7 +41,7 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryClientConnectionsRequest, QueryConnectionsRequest,
 };
 
-use crate::chain::Chain;
+use crate::chain::{AccountStatus, Chain, FeesPaid};
 use crate::config::ChainConfig;
 use crate::error::{Error, Kind};
 use crate::event::monitor::{EventReceiver, EventSender, TxMonitorCmd};
@@ -59,6 +62,19 @@ pub struct MockChain {
     event_receiver: EventReceiver,
 }
 
+impl MockChain {
+    /// Create a mock chain over the given context, eg. one with existing channels.
+    pub fn with_context(config: ChainConfig, context: MockContext) -> Self {
+        let (sender, receiver) = channel::unbounded();
+        MockChain {
+            config,
+            context,
+            _event_sender: sender,
+            event_receiver: receiver,
+        }
+    }
+}
+
 impl Chain for MockChain {
     type LightBlock = TmLightBlock;
     type Header = TendermintHeader;
@@ -66,18 +82,14 @@ impl Chain for MockChain {
     type ClientState = TendermintClientState;
 
     fn bootstrap(config: ChainConfig, _rt: Arc<Runtime>) -> Result<Self, Error> {
-        let (sender, receiver) = channel::unbounded();
-        Ok(MockChain {
-            config: config.clone(),
-            context: MockContext::new(
-                config.id.clone(),
-                HostType::SyntheticTendermint,
-                50,
-                Height::new(config.id.version(), 20),
-            ),
-            _event_sender: sender,
-            event_receiver: receiver,
-        })
+        let context = MockContext::new(
+            config.id.clone(),
+            HostType::SyntheticTendermint,
+            50,
+            Height::new(config.id.version(), 20),
+        );
+
+        Ok(Self::with_context(config, context))
     }
 
     fn init_light_client(&self) -> Result<Box<dyn LightClient<Self>>, Error> {
@@ -126,10 +138,30 @@ impl Chain for MockChain {
         unimplemented!()
     }
 
+    fn query_account_status(&self) -> Result<AccountStatus, Error> {
+        Ok(AccountStatus {
+            address: get_dummy_account_id().to_string(),
+            sequence: 0,
+            balance: "0".to_string(),
+            denom: self.config.gas_price.denom.clone(),
+        })
+    }
+
+    fn query_fees_paid(&self) -> Result<FeesPaid, Error> {
+        Ok(FeesPaid {
+            denom: self.config.gas_price.denom.clone(),
+            ..FeesPaid::default()
+        })
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         unimplemented!()
     }
 
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
+        Ok(get_compatible_versions())
+    }
+
     fn query_latest_height(&self) -> Result<Height, Error> {
         Ok(self.context.query_latest_height())
     }
@@ -166,10 +198,13 @@ impl Chain for MockChain {
 
     fn query_connection(
         &self,
-        _connection_id: &ConnectionId,
+        connection_id: &ConnectionId,
         _height: Height,
     ) -> Result<ConnectionEnd, Error> {
-        unimplemented!()
+        let connection_end = ConnectionReader::connection_end(&self.context, connection_id)
+            .ok_or_else(|| Kind::ConnectionNotFound(connection_id.clone()))?;
+
+        Ok(connection_end)
     }
 
     fn query_client_connections(
@@ -202,11 +237,14 @@ impl Chain for MockChain {
 
     fn query_channel(
         &self,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
         _height: Height,
     ) -> Result<ChannelEnd, Error> {
-        unimplemented!()
+        Ok(self
+            .context
+            .channel_end(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| Kind::Query("channel".into()).context("channel not found"))?)
     }
 
     fn query_channel_client_state(
@@ -246,9 +284,21 @@ impl Chain for MockChain {
 
     fn query_next_sequence_receive(
         &self,
-        _request: QueryNextSequenceReceiveRequest,
+        request: QueryNextSequenceReceiveRequest,
     ) -> Result<Sequence, Error> {
-        unimplemented!()
+        let port_id: PortId = request
+            .port_id
+            .parse()
+            .map_err(|e| Kind::Query("next sequence receive".into()).context(e))?;
+        let channel_id: ChannelId = request
+            .channel_id
+            .parse()
+            .map_err(|e| Kind::Query("next sequence receive".into()).context(e))?;
+
+        Ok(self
+            .context
+            .get_next_sequence_recv(&(port_id, channel_id))
+            .ok_or(Kind::EmptyResponseValue)?)
     }
 
     fn query_txs(&self, _request: QueryTxRequest) -> Result<Vec<IbcEvent>, Error> {
@@ -389,13 +439,13 @@ impl Chain for MockChain {
 // For integration tests with the modules
 #[cfg(test)]
 pub mod test_utils {
-    use std::collections::HashSet;
     use std::str::FromStr;
     use std::time::Duration;
 
     use ibc::ics24_host::identifier::ChainId;
 
-    use crate::config::{ChainConfig, ChainFilters, GasPrice};
+    use crate::config::filter::PacketFilterPolicy;
+    use crate::config::{ChainConfig, GasPrice};
 
     /// Returns a very minimal chain configuration, to be used in initializing `MockChain`s.
     pub fn get_basic_chain_config(id: &str) -> ChainConfig {
@@ -407,18 +457,25 @@ pub mod test_utils {
             rpc_timeout: crate::config::default::rpc_timeout(),
             account_prefix: "".to_string(),
             key_name: "".to_string(),
+            additional_key_names: vec![],
+            key_store_type: Default::default(),
+            hd_path: crate::config::default::hd_path(),
             store_prefix: "".to_string(),
             max_gas: None,
             gas_price: GasPrice::new(0.001, "uatom".to_string()),
+            dynamic_gas_price: None,
             gas_adjustment: None,
             max_msg_num: None,
             max_tx_size: None,
+            fee_granter: None,
             clock_drift: Duration::from_secs(5),
             trusting_period: Duration::from_secs(14 * 24 * 60 * 60), // 14 days
             trust_threshold: Default::default(),
-            filters: ChainFilters {
-                channels: HashSet::new(),
-            },
+            filters: PacketFilterPolicy::default(),
+            remote_signer: None,
+            address_type: Default::default(),
+            event_source: Default::default(),
+            fallback_nodes: vec![],
         }
     }
 }
@@ -50,11 +50,13 @@ use crate::{
     },
     keyring::KeyEntry,
     light_client::LightClient,
+    state::StoredOperationalData,
+    telemetry::Telemetry,
 };
 
 use super::{
     handle::{ChainHandle, ChainRequest, ProdChainHandle, ReplyTo, Subscription},
-    Chain,
+    AccountStatus, Chain, FeesPaid,
 };
 
 pub struct Threads {
@@ -99,6 +101,27 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         // Similar to `from_config`.
         let chain = C::bootstrap(config, rt.clone())?;
 
+        Self::spawn_chain(chain, rt)
+    }
+
+    /// Spawns a new runtime for a specific Chain implementation, which reports its metrics
+    /// to the given telemetry handle.
+    pub fn spawn_with_telemetry(
+        config: ChainConfig,
+        rt: Arc<TokioRuntime>,
+        telemetry: Telemetry,
+    ) -> Result<Box<dyn ChainHandle>, Error> {
+        let mut chain = C::bootstrap(config, rt.clone())?;
+        chain.set_telemetry(telemetry);
+
+        Self::spawn_chain(chain, rt)
+    }
+
+    /// Spawns a new runtime for the given, already bootstrapped, chain.
+    pub(crate) fn spawn_chain(
+        chain: C,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<Box<dyn ChainHandle>, Error> {
         // Start the light client
         let light_client = chain.init_light_client()?;
 
@@ -194,14 +217,18 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.subscribe(reply_to)?
                         },
 
-                        Ok(ChainRequest::SendMsgs { proto_msgs, reply_to }) => {
-                            self.send_msgs(proto_msgs, reply_to)?
+                        Ok(ChainRequest::SendMsgs { proto_msgs, signer, reply_to }) => {
+                            self.send_msgs(proto_msgs, signer, reply_to)?
                         },
 
                         Ok(ChainRequest::Signer { reply_to }) => {
                             self.get_signer(reply_to)?
                         }
 
+                        Ok(ChainRequest::NextSigner { reply_to }) => {
+                            self.next_signer(reply_to)?
+                        }
+
                         Ok(ChainRequest::Key { reply_to }) => {
                             self.get_key(reply_to)?
                         }
@@ -238,6 +265,34 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.query_latest_height(reply_to)?
                         }
 
+                        Ok(ChainRequest::QueryAccountStatus { reply_to }) => {
+                            self.query_account_status(reply_to)?
+                        }
+
+                        Ok(ChainRequest::QueryFeesPaid { reply_to }) => {
+                            self.query_fees_paid(reply_to)?
+                        }
+
+                        Ok(ChainRequest::QueryProcessedHeight { reply_to }) => {
+                            self.query_processed_height(reply_to)?
+                        }
+
+                        Ok(ChainRequest::QueryResumeHeight { reply_to }) => {
+                            self.query_resume_height(reply_to)?
+                        }
+
+                        Ok(ChainRequest::StoreProcessedHeight { height, reply_to }) => {
+                            self.store_processed_height(height, reply_to)?
+                        }
+
+                        Ok(ChainRequest::QueryStoredOperationalData { port_id, channel_id, reply_to }) => {
+                            self.query_stored_operational_data(port_id, channel_id, reply_to)?
+                        }
+
+                        Ok(ChainRequest::StoreOperationalData { port_id, channel_id, data, reply_to }) => {
+                            self.store_operational_data(port_id, channel_id, data, reply_to)?
+                        }
+
                         Ok(ChainRequest::QueryClients { request, reply_to }) => {
                             self.query_clients(request, reply_to)?
                         },
@@ -358,11 +413,31 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
     fn send_msgs(
         &mut self,
         proto_msgs: Vec<prost_types::Any>,
+        signer: Option<Signer>,
         reply_to: ReplyTo<Vec<IbcEvent>>,
     ) -> Result<(), Error> {
-        let result = self.chain.send_msgs(proto_msgs);
+        // Messages sent without a signer were built with the signer of the main key
+        let signer = match signer {
+            Some(signer) => Ok(signer),
+            None => self.chain.get_signer(),
+        };
+
+        let result = signer.and_then(|signer| self.chain.broadcast_msgs(proto_msgs, &signer));
+        let pending = match result {
+            Ok(pending) => pending,
+            Err(e) => {
+                reply_to.send(Err(e)).map_err(Kind::channel)?;
+                return Ok(());
+            }
+        };
 
-        reply_to.send(result).map_err(Kind::channel)?;
+        // Wait for the commit of the transactions in a separate thread, such that the runtime
+        // can keep serving requests, and in particular broadcast more transactions, meanwhile.
+        thread::spawn(move || {
+            if reply_to.send(pending()).is_err() {
+                error!("failed to reply with the result of sending messages");
+            }
+        });
 
         Ok(())
     }
@@ -375,6 +450,88 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn query_account_status(&self, reply_to: ReplyTo<AccountStatus>) -> Result<(), Error> {
+        let account_status = self.chain.query_account_status();
+
+        reply_to.send(account_status).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn query_fees_paid(&self, reply_to: ReplyTo<FeesPaid>) -> Result<(), Error> {
+        let fees_paid = self.chain.query_fees_paid();
+
+        reply_to.send(fees_paid).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn query_processed_height(&self, reply_to: ReplyTo<Option<Height>>) -> Result<(), Error> {
+        let result = match self.chain.state_store() {
+            Some(store) => store.processed_height(),
+            None => Ok(None),
+        };
+
+        reply_to.send(result).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn query_resume_height(&self, reply_to: ReplyTo<Option<Height>>) -> Result<(), Error> {
+        let resume_height = self
+            .chain
+            .state_store()
+            .and_then(|store| store.resume_height());
+
+        reply_to.send(Ok(resume_height)).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn store_processed_height(&self, height: Height, reply_to: ReplyTo<()>) -> Result<(), Error> {
+        let result = match self.chain.state_store() {
+            Some(store) => store.set_processed_height(height),
+            None => Ok(()),
+        };
+
+        reply_to.send(result).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn query_stored_operational_data(
+        &self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplyTo<Vec<StoredOperationalData>>,
+    ) -> Result<(), Error> {
+        let result = match self.chain.state_store() {
+            Some(store) => store.operational_data(&port_id, &channel_id),
+            None => Ok(vec![]),
+        };
+
+        reply_to.send(result).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
+    fn store_operational_data(
+        &self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        data: Vec<StoredOperationalData>,
+        reply_to: ReplyTo<()>,
+    ) -> Result<(), Error> {
+        let result = match self.chain.state_store() {
+            Some(store) => store.set_operational_data(&port_id, &channel_id, &data),
+            None => Ok(()),
+        };
+
+        reply_to.send(result).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
     fn get_signer(&mut self, reply_to: ReplyTo<Signer>) -> Result<(), Error> {
         let result = self.chain.get_signer();
 
@@ -383,6 +540,14 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn next_signer(&mut self, reply_to: ReplyTo<Signer>) -> Result<(), Error> {
+        let result = self.chain.next_signer();
+
+        reply_to.send(result).map_err(Kind::channel)?;
+
+        Ok(())
+    }
+
     fn get_key(&mut self, reply_to: ReplyTo<KeyEntry>) -> Result<(), Error> {
         let result = self.chain.get_key();
 
@@ -1,17 +1,27 @@
 //! Relayer configuration
 
+pub mod filter;
 pub mod reload;
 
-use std::collections::{HashMap, HashSet};
-use std::{fmt, fs, fs::File, io::Write, path::Path, time::Duration};
+use std::collections::HashMap;
+use std::{
+    fmt, fs,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use serde_derive::{Deserialize, Serialize};
 use tendermint_light_client::types::TrustThreshold;
 
-use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
 use ibc::timestamp::ZERO_DURATION;
 
 use crate::error;
+use crate::keyring::Store;
+
+use self::filter::PacketFilterPolicy;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GasPrice {
@@ -31,15 +41,66 @@ impl fmt::Display for GasPrice {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ChainFilters {
-    pub channels: HashSet<(PortId, ChannelId)>,
+/// A source of the gas price of a chain, which is queried periodically.
+/// The configured `gas_price` of the chain is used until the source is first queried
+/// successfully, and the denomination of the fee is always that of the `gas_price`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicGasPrice {
+    /// URL of an HTTP endpoint which returns the gas price as a JSON object `{ "price": 0.025 }`.
+    /// If not set, the minimum gas price of the full node is queried via gRPC instead.
+    pub endpoint: Option<String>,
+    /// The lowest gas price to pay, whatever the price returned by the source
+    pub floor: Option<f64>,
+    /// The highest gas price to pay, whatever the price returned by the source
+    pub ceiling: Option<f64>,
+    #[serde(
+        default = "default::dynamic_gas_price_refresh_interval",
+        with = "humantime_serde"
+    )]
+    pub refresh_interval: Duration,
+}
+
+impl DynamicGasPrice {
+    /// Bound the given gas price by the configured floor and ceiling, if any.
+    pub fn bound(&self, price: f64) -> f64 {
+        let price = self.floor.map_or(price, |floor| price.max(floor));
+        self.ceiling.map_or(price, |ceiling| price.min(ceiling))
+    }
+}
+
+/// How the events of a chain are collected by its event monitor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSourceMode {
+    /// Subscribe to the events of the chain over WebSocket, and poll the results of the
+    /// blocks produced while the WebSocket endpoint is unavailable
+    Push,
+    /// Only poll the results of every new block of the chain over RPC
+    Pull,
+}
+
+impl Default for EventSourceMode {
+    fn default() -> Self {
+        EventSourceMode::Push
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventSourceConfig {
+    #[serde(default)]
+    pub mode: EventSourceMode,
+    /// How often to poll the chain for new blocks, when polling
+    #[serde(default = "default::event_poll_interval", with = "humantime_serde")]
+    pub poll_interval: Duration,
 }
 
-impl Default for ChainFilters {
+impl Default for EventSourceConfig {
     fn default() -> Self {
         Self {
-            channels: HashSet::new(),
+            mode: EventSourceMode::default(),
+            poll_interval: default::event_poll_interval(),
         }
     }
 }
@@ -71,6 +132,26 @@ pub mod default {
     pub fn connection_delay() -> Duration {
         ZERO_DURATION
     }
+
+    pub fn remote_signer_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn hd_path() -> String {
+        "m/44'/118'/0'/0/0".to_string()
+    }
+
+    pub fn ethermint_pk_type() -> String {
+        "/ethermint.crypto.v1.ethsecp256k1.PubKey".to_string()
+    }
+
+    pub fn dynamic_gas_price_refresh_interval() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    pub fn event_poll_interval() -> Duration {
+        Duration::from_secs(1)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -104,6 +185,42 @@ impl Config {
     pub fn chains_map(&self) -> HashMap<&ChainId, &ChainConfig> {
         self.chains.iter().map(|c| (&c.id, c)).collect()
     }
+
+    /// Whether to relay on the given channel of the given chain, according to
+    /// the packet filter policy of the chain, if filtering is enabled.
+    pub fn relay_on_channel(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> bool {
+        self.relay_on(chain_id, |policy| {
+            policy.is_channel_allowed(port_id, channel_id)
+        })
+    }
+
+    /// Whether to relay on the given client hosted by the given chain, according to
+    /// the packet filter policy of the chain, if filtering is enabled.
+    pub fn relay_on_client(&self, chain_id: &ChainId, client_id: &ClientId) -> bool {
+        self.relay_on(chain_id, |policy| policy.is_client_allowed(client_id))
+    }
+
+    /// Whether to relay on the given connection of the given chain, according to
+    /// the packet filter policy of the chain, if filtering is enabled.
+    pub fn relay_on_connection(&self, chain_id: &ChainId, connection_id: &ConnectionId) -> bool {
+        self.relay_on(chain_id, |policy| {
+            policy.is_connection_allowed(connection_id)
+        })
+    }
+
+    fn relay_on(&self, chain_id: &ChainId, allowed: impl Fn(&PacketFilterPolicy) -> bool) -> bool {
+        if !self.global.filter {
+            return true;
+        }
+
+        self.find_chain(chain_id)
+            .map_or(false, |chain_config| allowed(&chain_config.filters))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -192,6 +309,39 @@ impl Default for TelemetryConfig {
     }
 }
 
+/// How the addresses of the accounts of a chain are derived from their public keys,
+/// which also determines how transactions are signed.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "derivation", rename_all = "lowercase", deny_unknown_fields)]
+pub enum AddressType {
+    /// The RIPEMD-160 hash of the SHA-256 hash of the compressed public key,
+    /// with transactions signed over their SHA-256 digest.
+    Cosmos,
+    /// The last 20 bytes of the Keccak-256 hash of the uncompressed public key,
+    /// with transactions signed over their Keccak-256 digest.
+    Ethermint {
+        /// The type URL of the public keys of the chain
+        #[serde(default = "default::ethermint_pk_type")]
+        pk_type: String,
+    },
+}
+
+impl Default for AddressType {
+    fn default() -> Self {
+        AddressType::Cosmos
+    }
+}
+
+/// Configuration of the external signer to which the signing of transactions is delegated.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// Path to the Unix socket on which the signer listens
+    pub socket: PathBuf,
+    #[serde(default = "default::remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ChainConfig {
@@ -203,11 +353,18 @@ pub struct ChainConfig {
     pub rpc_timeout: Duration,
     pub account_prefix: String,
     pub key_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_key_names: Vec<String>,
+    #[serde(default)]
+    pub key_store_type: Store,
+    #[serde(default = "default::hd_path")]
+    pub hd_path: String,
     pub store_prefix: String,
     pub max_gas: Option<u64>,
     pub gas_adjustment: Option<f64>,
     pub max_msg_num: Option<usize>,
     pub max_tx_size: Option<usize>,
+    pub fee_granter: Option<String>,
     #[serde(default = "default::clock_drift", with = "humantime_serde")]
     pub clock_drift: Duration,
     #[serde(default = "default::trusting_period", with = "humantime_serde")]
@@ -218,7 +375,42 @@ pub struct ChainConfig {
     pub trust_threshold: TrustThreshold,
     pub gas_price: GasPrice,
     #[serde(default)]
-    pub filters: ChainFilters,
+    pub dynamic_gas_price: Option<DynamicGasPrice>,
+    #[serde(default)]
+    pub filters: PacketFilterPolicy,
+    #[serde(default)]
+    pub remote_signer: Option<RemoteSignerConfig>,
+    #[serde(default)]
+    pub address_type: AddressType,
+    #[serde(default)]
+    pub event_source: EventSourceConfig,
+    /// Other full nodes of the chain, to fail over to when the node above is unhealthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_nodes: Vec<NodeEndpoints>,
+}
+
+impl ChainConfig {
+    /// The endpoints of the full nodes of the chain, starting with the primary node.
+    pub fn nodes(&self) -> Vec<NodeEndpoints> {
+        let primary = NodeEndpoints {
+            rpc_addr: self.rpc_addr.clone(),
+            websocket_addr: self.websocket_addr.clone(),
+            grpc_addr: self.grpc_addr.clone(),
+        };
+
+        std::iter::once(primary)
+            .chain(self.fallback_nodes.iter().cloned())
+            .collect()
+    }
+}
+
+/// The endpoints of a full node of a chain.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeEndpoints {
+    pub rpc_addr: tendermint_rpc::Url,
+    pub websocket_addr: tendermint_rpc::Url,
+    pub grpc_addr: tendermint_rpc::Url,
 }
 
 /// Attempt to load and parse the TOML config file as a `Config`.
@@ -256,7 +448,9 @@ pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<()
 
 #[cfg(test)]
 mod tests {
-    use super::{load, store_writer};
+    use std::time::Duration;
+
+    use super::{default, load, store_writer, DynamicGasPrice, EventSourceConfig, EventSourceMode};
     use test_env_log::test;
 
     #[test]
@@ -283,4 +477,35 @@ mod tests {
         let mut buffer = Vec::new();
         store_writer(&config, &mut buffer).unwrap();
     }
+    #[test]
+    fn bound_dynamic_gas_price() {
+        let dynamic = DynamicGasPrice {
+            endpoint: None,
+            floor: Some(0.01),
+            ceiling: Some(0.1),
+            refresh_interval: default::dynamic_gas_price_refresh_interval(),
+        };
+
+        assert_eq!(dynamic.bound(0.001), 0.01);
+        assert_eq!(dynamic.bound(0.05), 0.05);
+        assert_eq!(dynamic.bound(1.0), 0.1);
+
+        let unbounded = DynamicGasPrice {
+            floor: None,
+            ceiling: None,
+            ..dynamic
+        };
+        assert_eq!(unbounded.bound(1.0), 1.0);
+    }
+
+    #[test]
+    fn parse_event_source() {
+        let event_source: EventSourceConfig = toml::from_str("mode = 'pull'").unwrap();
+        assert_eq!(event_source.mode, EventSourceMode::Pull);
+        assert_eq!(event_source.poll_interval, default::event_poll_interval());
+
+        let event_source: EventSourceConfig = toml::from_str("poll_interval = '500ms'").unwrap();
+        assert_eq!(event_source.mode, EventSourceMode::Push);
+        assert_eq!(event_source.poll_interval, Duration::from_millis(500));
+    }
 }
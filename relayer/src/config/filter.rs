@@ -0,0 +1,244 @@
+//! Policies deciding which channels, clients and connections of a chain to relay on.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+
+/// Whether the patterns of a [`PacketFilterPolicy`] list the objects to relay on,
+/// or the objects to skip.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterMode {
+    Allow,
+    Deny,
+}
+
+impl Default for FilterMode {
+    fn default() -> Self {
+        FilterMode::Allow
+    }
+}
+
+/// The channels, clients and connections of a chain to relay on, when filtering is enabled.
+///
+/// In `allow` mode, only the channels matching one of the `channels` patterns are relayed on,
+/// while in `deny` mode, all the channels but those matching one of the patterns are.
+/// Clients and connections are filtered the same way, but only if patterns are given for them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PacketFilterPolicy {
+    #[serde(default)]
+    pub mode: FilterMode,
+    #[serde(default)]
+    pub channels: Vec<ChannelPattern>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients: Option<Vec<Wildcard>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Vec<Wildcard>>,
+}
+
+impl PacketFilterPolicy {
+    /// Whether to relay on the channel with the given port and channel identifiers.
+    pub fn is_channel_allowed(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let matches = self
+            .channels
+            .iter()
+            .any(|pattern| pattern.matches(port_id, channel_id));
+
+        self.allowed(matches)
+    }
+
+    /// Whether to relay on the client with the given identifier.
+    pub fn is_client_allowed(&self, client_id: &ClientId) -> bool {
+        match &self.clients {
+            Some(patterns) => self.allowed(Wildcard::any_matches(patterns, client_id.as_str())),
+            None => true,
+        }
+    }
+
+    /// Whether to relay on the connection with the given identifier.
+    pub fn is_connection_allowed(&self, connection_id: &ConnectionId) -> bool {
+        match &self.connections {
+            Some(patterns) => self.allowed(Wildcard::any_matches(patterns, connection_id.as_str())),
+            None => true,
+        }
+    }
+
+    fn allowed(&self, matches: bool) -> bool {
+        match self.mode {
+            FilterMode::Allow => matches,
+            FilterMode::Deny => !matches,
+        }
+    }
+}
+
+/// A pattern over identifiers, in which `*` matches any sequence of characters,
+/// e.g. `channel-*` or `ica*`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Wildcard(String);
+
+impl Wildcard {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self(pattern.into())
+    }
+
+    /// Whether the given identifier matches this pattern.
+    pub fn matches(&self, id: &str) -> bool {
+        let mut parts = self.0.split('*');
+
+        // The part before the first `*`, if any, must be a prefix of the identifier
+        let first = parts.next().unwrap_or_default();
+        let mut rest = match id.strip_prefix(first) {
+            Some(rest) => rest,
+            None => return false,
+        };
+
+        let parts = parts.collect::<Vec<_>>();
+        let (last, middle) = match parts.split_last() {
+            Some(split) => split,
+            // There is no `*` in the pattern
+            None => return rest.is_empty(),
+        };
+
+        // Match the parts between two `*` as early as possible
+        for part in middle {
+            match rest.find(part) {
+                Some(index) => rest = &rest[index + part.len()..],
+                None => return false,
+            }
+        }
+
+        // The part after the last `*` must be a suffix of the remainder
+        rest.ends_with(last)
+    }
+
+    fn any_matches(patterns: &[Wildcard], id: &str) -> bool {
+        patterns.iter().any(|pattern| pattern.matches(id))
+    }
+}
+
+impl fmt::Display for Wildcard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A pattern over channels, given either as a pair of port and channel patterns,
+/// e.g. `['transfer', 'channel-*']`, or as a path, e.g. `'transfer/*'`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawChannelPattern", into = "(Wildcard, Wildcard)")]
+pub struct ChannelPattern {
+    pub port: Wildcard,
+    pub channel: Wildcard,
+}
+
+impl ChannelPattern {
+    pub fn new(port: Wildcard, channel: Wildcard) -> Self {
+        Self { port, channel }
+    }
+
+    /// Whether the channel with the given port and channel identifiers matches this pattern.
+    pub fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.port.matches(port_id.as_str()) && self.channel.matches(channel_id.as_str())
+    }
+}
+
+impl From<ChannelPattern> for (Wildcard, Wildcard) {
+    fn from(pattern: ChannelPattern) -> Self {
+        (pattern.port, pattern.channel)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawChannelPattern {
+    Pair(Wildcard, Wildcard),
+    Path(String),
+}
+
+impl TryFrom<RawChannelPattern> for ChannelPattern {
+    type Error = String;
+
+    fn try_from(raw: RawChannelPattern) -> Result<Self, Self::Error> {
+        match raw {
+            RawChannelPattern::Pair(port, channel) => Ok(Self::new(port, channel)),
+            RawChannelPattern::Path(path) => {
+                let mut split = path.splitn(2, '/');
+                match (split.next(), split.next()) {
+                    (Some(port), Some(channel)) => {
+                        Ok(Self::new(Wildcard::new(port), Wildcard::new(channel)))
+                    }
+                    _ => Err(format!(
+                        "invalid channel pattern '{}', expected '<port>/<channel>'",
+                        path
+                    )),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use ibc::ics24_host::identifier::{ChannelId, ClientId, PortId};
+
+    use super::{FilterMode, PacketFilterPolicy, Wildcard};
+
+    #[test]
+    fn wildcard_matches() {
+        let matches = |pattern: &str, id: &str| Wildcard::new(pattern).matches(id);
+
+        assert!(matches("channel-0", "channel-0"));
+        assert!(!matches("channel-0", "channel-01"));
+        assert!(matches("channel-*", "channel-12"));
+        assert!(matches("ica*", "icahost"));
+        assert!(!matches("ica*", "transfer"));
+        assert!(matches("*", ""));
+        assert!(matches("*-tendermint-*", "07-tendermint-3"));
+        assert!(matches("a*b*c", "abbc"));
+        assert!(!matches("a*b*c", "acb"));
+        assert!(!matches("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn policy_from_toml() {
+        let policy: PacketFilterPolicy = toml::from_str(
+            r#"
+            mode = 'deny'
+            channels = [['transfer', 'channel-0'], 'ica*/*']
+            clients = ['07-tendermint-1*']
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(policy.mode, FilterMode::Deny);
+
+        let channel = |port: &str, channel: &str| {
+            policy.is_channel_allowed(
+                &port.parse::<PortId>().unwrap(),
+                &channel.parse::<ChannelId>().unwrap(),
+            )
+        };
+
+        assert!(!channel("transfer", "channel-0"));
+        assert!(channel("transfer", "channel-1"));
+        assert!(!channel("icahost", "channel-1"));
+
+        let client = |id: &str| policy.is_client_allowed(&id.parse::<ClientId>().unwrap());
+        assert!(!client("07-tendermint-12"));
+        assert!(client("07-tendermint-2"));
+
+        // Connections are not filtered without patterns
+        assert!(policy.is_connection_allowed(&"connection-0".parse().unwrap()));
+
+        // In allow mode, no channel is allowed without patterns
+        let allow_none = PacketFilterPolicy::default();
+        assert!(!allow_none.is_channel_allowed(&"transfer".parse().unwrap(), &ChannelId::new(0)));
+    }
+}
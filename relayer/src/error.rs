@@ -42,6 +42,10 @@ pub enum Kind {
     #[error("GRPC error")]
     Grpc,
 
+    /// Gas price endpoint error
+    #[error("failed to query the gas price from endpoint {0}")]
+    GasPrice(String),
+
     /// Light client instance error, typically raised by a `Client`
     #[error("Light client error for RPC address {0}")]
     LightClient(String),
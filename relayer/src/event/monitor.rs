@@ -1,4 +1,6 @@
-use std::{cmp::Ordering, sync::Arc};
+use std::{
+    cmp::Ordering, collections::HashMap, convert::TryFrom, sync::Arc, thread, time::Instant,
+};
 
 use crossbeam_channel as channel;
 use futures::{
@@ -11,19 +13,18 @@ use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
 use tracing::{debug, error, info, trace, warn};
 
+use tendermint::abci;
 use tendermint_rpc::{
-    event::Event as RpcEvent,
+    event::{Event as RpcEvent, EventData as RpcEventData, TxInfo, TxResult},
     query::{EventType, Query},
-    Error as RpcError, Result as RpcResult, SubscriptionClient, WebSocketClient,
-    WebSocketClientDriver,
+    Client, Error as RpcError, HttpClient, Result as RpcResult, SubscriptionClient,
+    WebSocketClient, WebSocketClientDriver,
 };
 
 use ibc::{events::IbcEvent, ics02_client::height::Height, ics24_host::identifier::ChainId};
 
-use crate::util::{
-    retry::{retry_count, retry_with_index, RetryResult},
-    stream::group_while,
-};
+use crate::config::{EventSourceConfig, EventSourceMode, NodeEndpoints};
+use crate::util::stream::group_while;
 
 mod retry_strategy {
     use crate::util::retry::clamp_total;
@@ -31,7 +32,7 @@ mod retry_strategy {
     use std::time::Duration;
 
     // Default parameters for the retrying mechanism
-    const MAX_DELAY: Duration = Duration::from_secs(60); // 1 minute
+    pub const MAX_DELAY: Duration = Duration::from_secs(60); // 1 minute
     const MAX_TOTAL_DELAY: Duration = Duration::from_secs(10 * 60); // 10 minutes
     const INITIAL_DELAY: Duration = Duration::from_secs(1); // 1 second
 
@@ -63,6 +64,21 @@ pub enum Error {
     #[error("failed to extract IBC events: {0}")]
     CollectEventsFailed(String),
 
+    #[error("failed to create RPC client: {0}")]
+    HttpClientCreationFailed(RpcError),
+
+    #[error("failed to query the latest height of the chain: {0}")]
+    QueryLatestHeightFailed(RpcError),
+
+    #[error("failed to query the block at height {0}: {1}")]
+    QueryBlockFailed(Height, RpcError),
+
+    #[error("failed to query the results of the block at height {0}: {1}")]
+    QueryBlockResultsFailed(Height, RpcError),
+
+    #[error("invalid height {0}: {1}")]
+    InvalidHeight(Height, String),
+
     #[error("event monitor failed to dispatch event batch to subscribers")]
     ChannelSendFailed,
 }
@@ -109,12 +125,32 @@ pub enum MonitorCmd {
 ///
 /// Those can be extending or overriden using
 /// [`EventMonitor::add_query`] and [`EventMonitor::set_queries`].
+///
+/// While the WebSocket endpoint is unavailable, or if the monitor is configured
+/// to pull events, the results of every new block are instead polled over RPC,
+/// and once reconnected, the blocks produced in the meantime are polled as well,
+/// so that no events are missed.
+///
+/// When the monitor fails to connect to the WebSocket endpoint of a node, or to
+/// poll its blocks, it fails over to the next node of the chain, if any.
 pub struct EventMonitor {
     chain_id: ChainId,
-    /// WebSocket to collect events from
-    client: WebSocketClient,
+    /// WebSocket to collect events from, if connected
+    client: Option<WebSocketClient>,
     /// Async task handle for the WebSocket client's driver
-    driver_handle: JoinHandle<()>,
+    driver_handle: Option<JoinHandle<()>>,
+    /// Endpoints of the nodes of the chain
+    nodes: Vec<NodeEndpoints>,
+    /// RPC clients to poll the results of blocks with, per node
+    rpc_clients: Vec<HttpClient>,
+    /// Index of the node the monitor collects events from
+    active_node: usize,
+    /// How events are collected
+    event_source: EventSourceConfig,
+    /// Height of the last batch of events sent to the handler
+    last_height: Option<Height>,
+    /// Height up to which the events pushed over WebSocket have already been polled
+    polled_height: Option<Height>,
     /// Channel to handler where the monitor for this chain sends the events
     tx_batch: channel::Sender<Result<EventBatch>>,
     /// Channel where to receive client driver errors
@@ -123,8 +159,6 @@ pub struct EventMonitor {
     tx_err: mpsc::UnboundedSender<tendermint_rpc::Error>,
     /// Channel where to receive commands
     rx_cmd: channel::Receiver<MonitorCmd>,
-    /// Node Address
-    node_addr: tendermint_rpc::Url,
     /// Queries
     event_queries: Vec<Query>,
     /// All subscriptions combined in a single stream
@@ -134,40 +168,73 @@ pub struct EventMonitor {
 }
 
 impl EventMonitor {
-    /// Create an event monitor, and connect to a node
+    /// Create an event monitor, and connect to the first available node among the given ones.
+    ///
+    /// In `push` mode, the monitor starts polling blocks over RPC if the
+    /// WebSocket endpoints of all the nodes are unavailable.
     pub fn new(
         chain_id: ChainId,
-        node_addr: tendermint_rpc::Url,
+        nodes: Vec<NodeEndpoints>,
+        event_source: EventSourceConfig,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, EventReceiver, TxMonitorCmd)> {
         let (tx_batch, rx_batch) = channel::unbounded();
         let (tx_cmd, rx_cmd) = channel::unbounded();
-
-        let ws_addr = node_addr.clone();
-        let (client, driver) = rt
-            .block_on(async move { WebSocketClient::new(ws_addr).await })
-            .map_err(Error::ClientCreationFailed)?;
-
         let (tx_err, rx_err) = mpsc::unbounded_channel();
-        let websocket_driver_handle = rt.spawn(run_driver(driver, tx_err.clone()));
+
+        let rpc_clients = nodes
+            .iter()
+            .map(|node| HttpClient::new(node.rpc_addr.clone()))
+            .collect::<RpcResult<Vec<_>>>()
+            .map_err(Error::HttpClientCreationFailed)?;
 
         // TODO: move them to config file(?)
         let event_queries = vec![Query::from(EventType::Tx), Query::from(EventType::NewBlock)];
 
-        let monitor = Self {
+        let mut monitor = Self {
             rt,
             chain_id,
-            client,
-            driver_handle: websocket_driver_handle,
+            client: None,
+            driver_handle: None,
+            nodes,
+            rpc_clients,
+            active_node: 0,
+            event_source,
+            last_height: None,
+            polled_height: None,
             event_queries,
             tx_batch,
             rx_err,
             tx_err,
             rx_cmd,
-            node_addr,
             subscriptions: Box::new(futures::stream::empty()),
         };
 
+        monitor.report_active_node();
+
+        if monitor.event_source.mode == EventSourceMode::Push {
+            for _ in 0..monitor.nodes.len() {
+                match monitor.try_reconnect() {
+                    Ok(()) => break,
+                    Err(e) => {
+                        warn!(
+                            chain.id = %monitor.chain_id,
+                            "failed to connect to WebSocket endpoint {}: {}",
+                            monitor.node_addr(), e
+                        );
+                        monitor.fail_over();
+                    }
+                }
+            }
+
+            if monitor.client.is_none() {
+                warn!(
+                    chain.id = %monitor.chain_id,
+                    "polling blocks until a WebSocket endpoint is available"
+                );
+            }
+        }
+
         Ok((monitor, rx_batch, tx_cmd))
     }
 
@@ -188,7 +255,15 @@ impl EventMonitor {
     }
 
     /// Clear the current subscriptions, and subscribe again to all queries.
+    ///
+    /// Does nothing while the monitor is not connected to the WebSocket endpoint,
+    /// in which case it subscribes once connected.
     pub fn subscribe(&mut self) -> Result<()> {
+        let client = match &self.client {
+            Some(client) => client,
+            None => return Ok(()),
+        };
+
         let mut subscriptions = vec![];
 
         for query in &self.event_queries {
@@ -196,7 +271,7 @@ impl EventMonitor {
 
             let subscription = self
                 .rt
-                .block_on(self.client.subscribe(query.clone()))
+                .block_on(client.subscribe(query.clone()))
                 .map_err(Error::ClientSubscriptionFailed)?;
 
             subscriptions.push(subscription);
@@ -209,41 +284,65 @@ impl EventMonitor {
         Ok(())
     }
 
+    /// The WebSocket endpoint of the active node
+    fn node_addr(&self) -> &tendermint_rpc::Url {
+        &self.nodes[self.active_node].websocket_addr
+    }
+
+    /// The RPC client to the active node
+    fn rpc_client(&self) -> &HttpClient {
+        &self.rpc_clients[self.active_node]
+    }
+
+    /// Fail over to the next node of the chain, if any.
+    fn fail_over(&mut self) {
+        if self.nodes.len() < 2 {
+            return;
+        }
+
+        let previous = self.active_node;
+        self.active_node = (self.active_node + 1) % self.nodes.len();
+
+        warn!(
+            chain.id = %self.chain_id,
+            "failing over from node {} to node {}",
+            self.nodes[previous].rpc_addr,
+            self.nodes[self.active_node].rpc_addr
+        );
+
+        self.report_active_node();
+    }
+
+    fn report_active_node(&self) {
+        crate::telemetry!(ibc_telemetry::global().active_endpoint(
+            &self.chain_id,
+            "websocket",
+            &self.node_addr().to_string()
+        ));
+    }
+
     fn try_reconnect(&mut self) -> Result<()> {
         trace!(chain.id = %self.chain_id,
             "trying to reconnect to WebSocket endpoint {}",
-            self.node_addr
+            self.node_addr()
         );
 
-        // Try to reconnect
-        let (mut client, driver) = self
+        let (client, driver) = self
             .rt
-            .block_on(WebSocketClient::new(self.node_addr.clone()))
+            .block_on(WebSocketClient::new(self.node_addr().clone()))
             .map_err(Error::ClientCreationFailed)?;
 
-        let mut driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
+        let driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
 
-        // Swap the new client with the previous one which failed,
-        // so that we can shut the latter down gracefully.
-        std::mem::swap(&mut self.client, &mut client);
-        std::mem::swap(&mut self.driver_handle, &mut driver_handle);
+        self.client = Some(client);
+        self.driver_handle = Some(driver_handle);
 
         trace!(
             chain.id = %self.chain_id,
             "reconnected to WebSocket endpoint {}",
-            self.node_addr,
+            self.node_addr(),
         );
 
-        // Shut down previous client
-        trace!(chain.id = %self.chain_id, "gracefully shutting down previous client");
-        let _ = client.close();
-
-        self.rt
-            .block_on(driver_handle)
-            .map_err(|e| Error::ClientTerminationFailed(Arc::new(e)))?;
-
-        trace!(chain.id = %self.chain_id, "previous client successfully shutdown");
-
         Ok(())
     }
 
@@ -253,38 +352,17 @@ impl EventMonitor {
         self.subscribe()
     }
 
-    /// Attempt to restart the WebSocket client using the given retry strategy.
-    ///
-    /// See the [`retry`](https://docs.rs/retry) crate and the
-    /// [`crate::util::retry`] module for more information.
-    fn restart(&mut self) {
-        let result = retry_with_index(retry_strategy::default(), |_| {
-            // Try to reconnect
-            if let Err(e) = self.try_reconnect() {
-                trace!(chain.id = %self.chain_id, "error when reconnecting: {}", e);
-                return RetryResult::Retry(());
-            }
-
-            // Try to resubscribe
-            if let Err(e) = self.try_resubscribe() {
-                trace!(chain.id = %self.chain_id, "error when reconnecting: {}", e);
-                return RetryResult::Retry(());
-            }
+    /// Shut the WebSocket client down, if any, and wait for its driver to finish.
+    fn disconnect(&mut self) {
+        self.subscriptions = Box::new(futures::stream::empty());
 
-            RetryResult::Ok(())
-        });
+        if let Some(client) = self.client.take() {
+            trace!(chain.id = %self.chain_id, "gracefully shutting down WebSocket client");
+            let _ = client.close();
+        }
 
-        match result {
-            Ok(()) => info!(
-                chain.id = %self.chain_id,
-                "successfully reconnected to WebSocket endpoint {}",
-                self.node_addr
-            ),
-            Err(retries) => error!(
-                chain.id = %self.chain_id,
-                "failed to reconnect to {} after {} retries",
-                self.node_addr, retry_count(&retries)
-            ),
+        if let Some(driver_handle) = self.driver_handle.take() {
+            let _ = self.rt.block_on(driver_handle);
         }
     }
 
@@ -293,10 +371,16 @@ impl EventMonitor {
     pub fn run(mut self) {
         debug!(chain.id = %self.chain_id, "starting event monitor");
 
-        // Continuously run the event loop, so that when it aborts
-        // because of WebSocket client restart, we pick up the work again.
+        // Continuously run the event loop, so that when it aborts because the WebSocket
+        // client failed, or reconnected, we pick up the work again in the other loop.
         loop {
-            match self.run_loop() {
+            let next = if self.client.is_some() {
+                self.run_loop()
+            } else {
+                self.poll_loop()
+            };
+
+            match next {
                 Next::Continue => continue,
                 Next::Abort => break,
             }
@@ -304,11 +388,8 @@ impl EventMonitor {
 
         debug!(chain.id = %self.chain_id, "event monitor is shutting down");
 
-        // Close the WebSocket connection
-        let _ = self.client.close();
-
-        // Wait for the WebSocket driver to finish
-        let _ = self.rt.block_on(self.driver_handle);
+        // Close the WebSocket connection and wait for its driver to finish
+        self.disconnect();
 
         trace!(chain.id = %self.chain_id, "event monitor has successfully shut down");
     }
@@ -348,12 +429,12 @@ impl EventMonitor {
                 Err(e) => {
                     error!(chain.id = %self.chain_id, "failed to collect events: {}", e);
 
-                    // Restart the event monitor, reconnect to the WebSocket endpoint,
-                    // and subscribe again to the queries.
-                    self.restart();
+                    // Shut the failed client down, the polling loop will poll the blocks
+                    // produced until we reconnect to the WebSocket endpoint.
+                    self.disconnect();
 
-                    // Abort this event loop, the `run` method will start a new one.
-                    // We can't just write `return self.run()` here because Rust
+                    // Abort this event loop, the `run` method will start the polling one.
+                    // We can't just write `return self.poll_loop()` here because Rust
                     // does not perform tail call optimization, and we would
                     // thus potentially blow up the stack after many restarts.
                     break;
@@ -364,16 +445,218 @@ impl EventMonitor {
         Next::Continue
     }
 
-    /// Collect the IBC events from the subscriptions
-    fn process_batch(&self, batch: EventBatch) -> Result<()> {
+    /// Polling loop, which polls the new blocks of the chain until the monitor
+    /// reconnects to the WebSocket endpoint, unless configured to pull events.
+    fn poll_loop(&mut self) -> Next {
+        let mut reconnect_delays = retry_strategy::default();
+        let mut reconnect_at = Instant::now();
+
+        loop {
+            if let Ok(cmd) = self.rx_cmd.try_recv() {
+                match cmd {
+                    MonitorCmd::Shutdown => return Next::Abort,
+                }
+            }
+
+            if self.event_source.mode == EventSourceMode::Push && Instant::now() >= reconnect_at {
+                match self.try_reconnect().and_then(|()| self.try_resubscribe()) {
+                    Ok(()) => {
+                        info!(
+                            chain.id = %self.chain_id,
+                            "successfully reconnected to WebSocket endpoint {}",
+                            self.node_addr()
+                        );
+
+                        // Poll the blocks produced up to the subscription, and skip their
+                        // events when they are also pushed over WebSocket.
+                        self.poll_blocks();
+                        self.polled_height = self.last_height;
+
+                        return Next::Continue;
+                    }
+                    Err(e) => {
+                        trace!(chain.id = %self.chain_id, "error when reconnecting: {}", e);
+                        self.disconnect();
+                        self.fail_over();
+
+                        let delay = reconnect_delays.next().unwrap_or(retry_strategy::MAX_DELAY);
+                        reconnect_at = Instant::now() + delay;
+                    }
+                }
+            }
+
+            self.poll_blocks();
+
+            thread::sleep(self.event_source.poll_interval);
+        }
+    }
+
+    /// Send the batches of events of the blocks produced since the last batch sent,
+    /// or of the latest block if no batch was sent yet.
+    fn poll_blocks(&mut self) {
+        let latest_height = match self.query_latest_height() {
+            Ok(height) => height,
+            Err(e) => {
+                warn!(chain.id = %self.chain_id, "{}", e);
+                self.fail_over();
+                return;
+            }
+        };
+
+        let mut height = match self.last_height {
+            Some(last_height) => last_height.increment(),
+            None => latest_height,
+        };
+
+        while height <= latest_height {
+            let batch = match self.fetch_batch(height) {
+                Ok(batch) => batch,
+                Err(e) => {
+                    // Retry from this height at the next poll, from the next node
+                    warn!(chain.id = %self.chain_id, "{}", e);
+                    self.fail_over();
+                    return;
+                }
+            };
+
+            if let Err(e) = self.process_batch(batch) {
+                // Retry from this height at the next poll
+                warn!(chain.id = %self.chain_id, "{}", e);
+                return;
+            }
+
+            height = height.increment();
+        }
+    }
+
+    fn query_latest_height(&self) -> Result<Height> {
+        let status = self
+            .rt
+            .block_on(self.rpc_client().status())
+            .map_err(Error::QueryLatestHeightFailed)?;
+
+        Ok(Height::new(
+            ChainId::chain_version(self.chain_id.as_str()),
+            status.sync_info.latest_block_height.value(),
+        ))
+    }
+
+    /// Collect the IBC events of the block at the given height from its results.
+    fn fetch_batch(&self, height: Height) -> Result<EventBatch> {
+        let tm_height = tendermint::block::Height::try_from(height.revision_height)
+            .map_err(|e| Error::InvalidHeight(height, e.to_string()))?;
+
+        let block = self
+            .rt
+            .block_on(self.rpc_client().block(tm_height))
+            .map_err(|e| Error::QueryBlockFailed(height, e))?
+            .block;
+
+        let block_results = self
+            .rt
+            .block_on(self.rpc_client().block_results(tm_height))
+            .map_err(|e| Error::QueryBlockResultsFailed(height, e))?;
+
+        let mut rpc_events = vec![RpcEvent {
+            query: Query::from(EventType::NewBlock).to_string(),
+            data: RpcEventData::NewBlock {
+                block: Some(block),
+                result_begin_block: None,
+                result_end_block: None,
+            },
+            events: None,
+        }];
+
+        // The events of failed transactions are not emitted
+        rpc_events.extend(
+            block_results
+                .txs_results
+                .unwrap_or_default()
+                .into_iter()
+                .enumerate()
+                .filter(|(_, tx_result)| tx_result.code.is_ok())
+                .map(|(index, tx_result)| tx_event(height, index, tx_result.events)),
+        );
+
+        let mut events = vec![];
+        for rpc_event in rpc_events {
+            let ibc_events = crate::event::rpc::get_all_events(&self.chain_id, rpc_event)
+                .map_err(Error::CollectEventsFailed)?;
+
+            events.extend(ibc_events.into_iter().map(|(_, event)| event));
+        }
+
+        sort_events(&mut events);
+
+        Ok(EventBatch {
+            chain_id: self.chain_id.clone(),
+            height,
+            events,
+        })
+    }
+
+    /// Send the given batch to the handler, unless it was already polled.
+    fn process_batch(&mut self, batch: EventBatch) -> Result<()> {
+        if matches!(self.polled_height, Some(polled_height) if batch.height <= polled_height) {
+            trace!(
+                chain.id = %self.chain_id,
+                "skipping events at height {}, already polled",
+                batch.height
+            );
+            return Ok(());
+        }
+
+        let height = batch.height;
+
         self.tx_batch
             .send(Ok(batch))
             .map_err(|_| Error::ChannelSendFailed)?;
 
+        self.last_height = Some(height);
+
         Ok(())
     }
 }
 
+/// Build the RPC event which the WebSocket endpoint pushes for a transaction,
+/// out of the events of its result in a block.
+fn tx_event(height: Height, index: usize, tx_events: Vec<abci::Event>) -> RpcEvent {
+    let mut events: HashMap<String, Vec<String>> = HashMap::new();
+    events.insert("tm.event".to_string(), vec!["Tx".to_string()]);
+    events.insert(
+        "tx.height".to_string(),
+        vec![height.revision_height.to_string()],
+    );
+
+    for event in &tx_events {
+        for attribute in &event.attributes {
+            events
+                .entry(format!("{}.{}", event.type_str, attribute.key))
+                .or_default()
+                .push(attribute.value.to_string());
+        }
+    }
+
+    // Only the events are used to build the IBC events
+    let tx_result = TxInfo {
+        height: height.revision_height as i64,
+        index: Some(index as i64),
+        tx: vec![],
+        result: TxResult {
+            log: None,
+            gas_wanted: None,
+            gas_used: None,
+            events: tx_events,
+        },
+    };
+
+    RpcEvent {
+        query: Query::from(EventType::Tx).to_string(),
+        data: RpcEventData::Tx { tx_result },
+        events: Some(events),
+    }
+}
+
 /// Collect the IBC events from an RPC event
 fn collect_events(chain_id: &ChainId, event: RpcEvent) -> impl Stream<Item = (Height, IbcEvent)> {
     let events = crate::event::rpc::get_all_events(chain_id, event).unwrap_or_default();
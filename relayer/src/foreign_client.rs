@@ -21,6 +21,7 @@ use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
 use ibc::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
 use ibc::ics24_host::identifier::{ChainId, ClientId};
 use ibc::query::QueryTxRequest;
+use ibc::signer::Signer;
 use ibc::timestamp::Timestamp;
 use ibc::tx_msg::Msg;
 use ibc::Height;
@@ -410,6 +411,25 @@ impl ForeignClient {
         &self,
         target_height: Height,
         trusted_height: Height,
+    ) -> Result<Vec<Any>, ForeignClientError> {
+        let signer = self.dst_chain().get_signer().map_err(|e| {
+            ForeignClientError::ClientUpdate(format!(
+                "failed getting signer for dst chain ({}) with error: {}",
+                self.dst_chain.id(),
+                e
+            ))
+        })?;
+
+        self.build_update_client_signed_by(target_height, trusted_height, signer)
+    }
+
+    /// Same as `build_update_client_with_trusted`, with the messages built with the given
+    /// signer of the destination chain.
+    pub fn build_update_client_signed_by(
+        &self,
+        target_height: Height,
+        trusted_height: Height,
+        signer: Signer,
     ) -> Result<Vec<Any>, ForeignClientError> {
         // Wait for source chain to reach `target_height`
         while self.src_chain().query_latest_height().map_err(|e| {
@@ -479,14 +499,6 @@ impl ForeignClient {
                 ))
             })?;
 
-        let signer = self.dst_chain().get_signer().map_err(|e| {
-            ForeignClientError::ClientUpdate(format!(
-                "failed getting signer for dst chain ({}) with error: {}",
-                self.dst_chain.id(),
-                e
-            ))
-        })?;
-
         let mut msgs = vec![];
 
         for header in support {
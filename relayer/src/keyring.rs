@@ -1,8 +1,17 @@
 use std::collections::HashMap;
 use std::ffi::OsStr;
-use std::fs::{self, File};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
 use std::path::{Path, PathBuf};
 
+#[cfg(unix)]
+use std::{
+    fs::Permissions,
+    os::unix::fs::{OpenOptionsExt, PermissionsExt},
+};
+
+use aes_gcm::aead::{Aead, NewAead};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
 use bech32::{ToBase32, Variant};
 use bip39::{Language, Mnemonic, Seed};
 use bitcoin::{
@@ -12,23 +21,43 @@ use bitcoin::{
 };
 use hdpath::StandardHDPath;
 use ibc::ics24_host::identifier::ChainId;
-use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rand::{rngs::OsRng, RngCore};
 use ripemd160::Ripemd160;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::config::AddressType;
 
 use errors::{Error, Kind};
 pub use pub_key::EncodedPubKey;
+pub use signer::{RemoteSigner, SignDigest, Signer};
 
 pub mod errors;
 mod pub_key;
+pub mod signer;
 
 pub type HDPath = StandardHDPath;
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-file";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
 
+/// Environment variable holding the passphrase of the encrypted key store
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "HERMES_KEYRING_PASSPHRASE";
+
+/// Environment variable holding the path to a file containing the passphrase
+/// of the encrypted key store
+pub const KEYSTORE_PASSPHRASE_FILE_ENV: &str = "HERMES_KEYRING_PASSPHRASE_FILE";
+
+// Parameters of the scrypt key derivation function used to derive the encryption
+// key of a key file from the passphrase of the encrypted key store.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
 // /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\
 // WARNING: Changing this struct in backward incompatible way
 //          will force users to re-import their keys.
@@ -42,7 +71,7 @@ pub struct KeyEntry {
     /// Private key
     pub private_key: ExtendedPrivKey,
 
-    /// Account Bech32 format - TODO allow hrp
+    /// Account Bech32 format, with the account prefix of the chain
     pub account: String,
 
     /// Address
@@ -191,8 +220,7 @@ impl KeyStore for Test {
         let mut filename = self.store.join(key_name);
         filename.set_extension(KEYSTORE_FILE_EXTENSION);
 
-        let file = File::create(filename)
-            .map_err(|_| Kind::KeyStore.context("error creating the key file"))?;
+        let file = create_key_file(&filename)?;
 
         serde_json::to_writer_pretty(file, &key_entry)
             .map_err(|_| Kind::KeyStore.context("error writing the key file"))?;
@@ -217,38 +245,309 @@ impl KeyStore for Test {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Passphrase of the encrypted key store, which is never displayed nor serialized.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self(passphrase)
+    }
+
+    /// Read the passphrase from the file at the given path, ignoring the trailing newline.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            Kind::KeyStore.context(format!(
+                "cannot read passphrase file at '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Ok(Self(
+            contents.trim_end_matches(&['\r', '\n'][..]).to_string(),
+        ))
+    }
+
+    /// Read the passphrase from the `HERMES_KEYRING_PASSPHRASE` environment variable if set,
+    /// or otherwise from the file whose path is given by `HERMES_KEYRING_PASSPHRASE_FILE`.
+    pub fn from_env() -> Result<Self, Error> {
+        if let Ok(passphrase) = std::env::var(KEYSTORE_PASSPHRASE_ENV) {
+            return Ok(Self(passphrase));
+        }
+
+        match std::env::var_os(KEYSTORE_PASSPHRASE_FILE_ENV) {
+            Some(path) => Self::from_file(Path::new(&path)),
+            None => Err(Kind::MissingPassphrase.into()),
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Passphrase(..)")
+    }
+}
+
+/// Key file of the encrypted key store, holding a key entry encrypted with AES-256-GCM
+/// under a key derived from the passphrase of the store with scrypt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct EncryptedKeyFile {
+    scrypt_log_n: u8,
+    scrypt_r: u32,
+    scrypt_p: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedKeyFile {
+    fn encrypt(key_entry: &KeyEntry, passphrase: &Passphrase) -> Result<Self, Error> {
+        let mut salt = [0u8; 32];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+
+        let cipher = Self::cipher(passphrase, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+
+        let plaintext = serde_json::to_vec(key_entry).map_err(|e| Kind::KeyStore.context(e))?;
+
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| Kind::KeyStore.context("failed to encrypt the key entry"))?;
+
+        Ok(Self {
+            scrypt_log_n: SCRYPT_LOG_N,
+            scrypt_r: SCRYPT_R,
+            scrypt_p: SCRYPT_P,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    fn decrypt(&self, passphrase: &Passphrase) -> Result<KeyEntry, Error> {
+        let decode = |field: &str| {
+            hex::decode(field).map_err(|e| Kind::KeyStore.context(format!("invalid hex: {}", e)))
+        };
+
+        let salt = decode(&self.salt)?;
+        let nonce = decode(&self.nonce)?;
+        let ciphertext = decode(&self.ciphertext)?;
+
+        if nonce.len() != 12 {
+            return Err(Kind::KeyStore
+                .context(format!("invalid nonce length: {}", nonce.len()))
+                .into());
+        }
+
+        let cipher = Self::cipher(
+            passphrase,
+            &salt,
+            self.scrypt_log_n,
+            self.scrypt_r,
+            self.scrypt_p,
+        )?;
+
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| Kind::InvalidPassphrase)?;
+
+        serde_json::from_slice(&plaintext).map_err(|e| Kind::KeyStore.context(e).into())
+    }
+
+    fn cipher(
+        passphrase: &Passphrase,
+        salt: &[u8],
+        log_n: u8,
+        r: u32,
+        p: u32,
+    ) -> Result<Aes256Gcm, Error> {
+        let params = scrypt::Params::new(log_n, r, p)
+            .map_err(|e| Kind::KeyStore.context(format!("invalid scrypt parameters: {}", e)))?;
+
+        let mut key = [0u8; 32];
+        scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+            .map_err(|e| Kind::KeyStore.context(format!("failed to derive key: {}", e)))?;
+
+        Ok(Aes256Gcm::new(Key::from_slice(&key)))
+    }
+}
+
+/// A key store which keeps each key in its own file, encrypted with the passphrase of the store.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Encrypted {
+    account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+}
+
+impl Encrypted {
+    pub fn new(account_prefix: String, store: PathBuf, passphrase: Passphrase) -> Self {
+        Self {
+            account_prefix,
+            store,
+            passphrase,
+        }
+    }
+}
+
+impl KeyStore for Encrypted {
+    fn get_key(&self, key_name: &str) -> Result<KeyEntry, Error> {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_FILE_EXTENSION);
+
+        if !key_file.as_path().exists() {
+            return Err(Kind::KeyStore
+                .context(format!("cannot find key file at '{}'", key_file.display()))
+                .into());
+        }
+
+        let file = File::open(&key_file).map_err(|_| {
+            Kind::KeyStore.context(format!("cannot open key file at '{}'", key_file.display()))
+        })?;
+
+        let encrypted: EncryptedKeyFile = serde_json::from_reader(file).map_err(|e| {
+            Kind::KeyStore.context(format!(
+                "invalid key file at '{}': {}",
+                key_file.display(),
+                e
+            ))
+        })?;
+
+        encrypted.decrypt(&self.passphrase)
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: KeyEntry) -> Result<(), Error> {
+        let mut filename = self.store.join(key_name);
+        filename.set_extension(KEYSTORE_FILE_EXTENSION);
+
+        let encrypted = EncryptedKeyFile::encrypt(&key_entry, &self.passphrase)?;
+
+        let file = create_key_file(&filename)?;
+
+        serde_json::to_writer_pretty(file, &encrypted)
+            .map_err(|_| Kind::KeyStore.context("error writing the key file"))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, KeyEntry)>, Error> {
+        let dir = fs::read_dir(&self.store)
+            .map_err(|e| Kind::KeyStore.context(format!("cannot list keys: {}", e)))?;
+
+        let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+
+        dir.into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// A key ring whose keys are held by a remote signer, see [`RemoteSigner`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    account_prefix: String,
+    signer: RemoteSigner,
+}
+
+impl Remote {
+    pub fn new(account_prefix: String, signer: RemoteSigner) -> Self {
+        Self {
+            account_prefix,
+            signer,
+        }
+    }
+
+    fn unavailable() -> Error {
+        Kind::RemoteSigner
+            .context("the keys held by a remote signer are not available to the relayer")
+            .into()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Store {
     Memory,
     Test,
+    File,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Store::Test
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum KeyRing {
     Memory(Memory),
     Test(Test),
+    File(Encrypted),
+    Remote(Remote),
 }
 
 impl KeyRing {
+    /// Open the key store of the given type for the given chain.
+    ///
+    /// The passphrase of the `File` key store is read from the environment, see
+    /// [`Passphrase::from_env`]. Use [`KeyRing::new_encrypted`] to provide it directly.
     pub fn new(store: Store, account_prefix: &str, chain_id: &ChainId) -> Result<Self, Error> {
         match store {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str()).map_err(|e| {
-                    Kind::KeyStore.context(format!("failed to compute keys folder path: {:?}", e))
-                })?;
-
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Kind::KeyStore.context(format!("failed to create keys folder: {:?}", e))
-                })?;
+                let keys_folder = create_keys_folder(chain_id, KEYSTORE_DISK_BACKEND)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::File => Self::new_encrypted(account_prefix, chain_id, Passphrase::from_env()?),
+        }
+    }
+
+    /// Open the encrypted key store of the given chain with the given passphrase.
+    pub fn new_encrypted(
+        account_prefix: &str,
+        chain_id: &ChainId,
+        passphrase: Passphrase,
+    ) -> Result<Self, Error> {
+        let keys_folder = create_keys_folder(chain_id, KEYSTORE_ENCRYPTED_BACKEND)?;
+
+        Ok(Self::File(Encrypted::new(
+            account_prefix.to_string(),
+            keys_folder,
+            passphrase,
+        )))
+    }
+
+    /// Create a key ring which delegates signing to the given remote signer.
+    pub fn new_remote(account_prefix: &str, signer: RemoteSigner) -> Self {
+        Self::Remote(Remote::new(account_prefix.to_string(), signer))
+    }
+
+    /// The signer of this key ring: the key store itself for local key stores,
+    /// which sign in-process, or the remote signer otherwise.
+    fn signer(&self) -> &dyn Signer {
+        match self {
+            KeyRing::Memory(m) => m,
+            KeyRing::Test(d) => d,
+            KeyRing::File(e) => e,
+            KeyRing::Remote(r) => &r.signer,
         }
     }
 
@@ -256,6 +555,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.get_key(key_name),
             KeyRing::Test(d) => d.get_key(key_name),
+            KeyRing::File(e) => e.get_key(key_name),
+            KeyRing::Remote(_) => Err(Remote::unavailable()),
         }
     }
 
@@ -263,6 +564,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.add_key(key_name, key_entry),
             KeyRing::Test(d) => d.add_key(key_name, key_entry),
+            KeyRing::File(e) => e.add_key(key_name, key_entry),
+            KeyRing::Remote(_) => Err(Remote::unavailable()),
         }
     }
 
@@ -270,6 +573,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.keys(),
             KeyRing::Test(d) => d.keys(),
+            KeyRing::File(e) => e.keys(),
+            KeyRing::Remote(_) => Err(Remote::unavailable()),
         }
     }
 
@@ -290,6 +595,7 @@ impl KeyRing {
         &self,
         mnemonic_words: &str,
         hd_path: &HDPath,
+        address_type: &AddressType,
     ) -> Result<KeyEntry, Error> {
         // Get the private key from the mnemonic
         let private_key = private_key_from_mnemonic(mnemonic_words, hd_path)?;
@@ -298,7 +604,7 @@ impl KeyRing {
         let public_key = ExtendedPubKey::from_private(&Secp256k1::new(), &private_key);
 
         // Get address from the public Key
-        let address = get_address(public_key);
+        let address = get_address(&public_key.public_key.to_bytes(), address_type)?;
 
         // Compute Bech32 account
         let account = bech32::encode(self.account_prefix(), address.to_base32(), Variant::Bech32)
@@ -312,23 +618,45 @@ impl KeyRing {
         })
     }
 
-    /// Sign a message
-    pub fn sign_msg(&self, key_name: &str, msg: Vec<u8>) -> Result<Vec<u8>, Error> {
-        let key = self.get_key(key_name)?;
+    /// Sign a message, over the digest used by chains with the given address type
+    pub fn sign_msg(
+        &self,
+        key_name: &str,
+        msg: Vec<u8>,
+        address_type: &AddressType,
+    ) -> Result<Vec<u8>, Error> {
+        let digest = match address_type {
+            AddressType::Cosmos => SignDigest::Sha256,
+            AddressType::Ethermint { .. } => SignDigest::Keccak256,
+        };
+
+        self.signer().sign(key_name, &msg, digest)
+    }
 
-        let private_key_bytes = key.private_key.private_key.to_bytes();
-        let signing_key = SigningKey::from_bytes(private_key_bytes.as_slice()).map_err(|_| {
-            Kind::InvalidKey.context("could not build signing key from private key bytes")
-        })?;
+    /// Get the compressed public key of the key with the given name
+    pub fn public_key(&self, key_name: &str) -> Result<Vec<u8>, Error> {
+        self.signer().public_key(key_name)
+    }
+
+    /// Get the address of the key with the given name
+    pub fn address(&self, key_name: &str, address_type: &AddressType) -> Result<Vec<u8>, Error> {
+        get_address(&self.public_key(key_name)?, address_type)
+    }
 
-        let signature: Signature = signing_key.sign(&msg);
-        Ok(signature.as_ref().to_vec())
+    /// Get the Bech32 account of the key with the given name
+    pub fn account(&self, key_name: &str, address_type: &AddressType) -> Result<String, Error> {
+        let address = self.address(key_name, address_type)?;
+
+        bech32::encode(self.account_prefix(), address.to_base32(), Variant::Bech32)
+            .map_err(|e| Kind::Bech32Account.context(e).into())
     }
 
     pub fn account_prefix(&self) -> &str {
         match self {
             KeyRing::Memory(m) => &m.account_prefix,
             KeyRing::Test(d) => &d.account_prefix,
+            KeyRing::File(e) => &e.account_prefix,
+            KeyRing::Remote(r) => &r.account_prefix,
         }
     }
 }
@@ -350,20 +678,34 @@ fn private_key_from_mnemonic(
     Ok(private_key)
 }
 
-/// Return an address from a Public Key
-fn get_address(pk: ExtendedPubKey) -> Vec<u8> {
-    let mut hasher = Sha256::new();
-    hasher.update(pk.public_key.to_bytes().as_slice());
+/// Return an address from a compressed Public Key
+fn get_address(public_key: &[u8], address_type: &AddressType) -> Result<Vec<u8>, Error> {
+    match address_type {
+        AddressType::Cosmos => {
+            let mut hasher = Sha256::new();
+            hasher.update(public_key);
+
+            // Read hash digest over the public key bytes & consume hasher
+            let pk_hash = hasher.finalize();
+
+            // Plug the hash result into the next crypto hash function.
+            let mut rip_hasher = Ripemd160::new();
+            rip_hasher.update(pk_hash);
+            let rip_result = rip_hasher.finalize();
 
-    // Read hash digest over the public key bytes & consume hasher
-    let pk_hash = hasher.finalize();
+            Ok(rip_result.to_vec())
+        }
+        AddressType::Ethermint { .. } => {
+            let public_key = k256::PublicKey::from_sec1_bytes(public_key)
+                .map_err(|_| Kind::InvalidKey.context("invalid secp256k1 public key"))?;
 
-    // Plug the hash result into the next crypto hash function.
-    let mut rip_hasher = Ripemd160::new();
-    rip_hasher.update(pk_hash);
-    let rip_result = rip_hasher.finalize();
+            // Hash the uncompressed public key, without its leading tag byte
+            let uncompressed = public_key.to_encoded_point(false);
+            let pk_hash = Keccak256::digest(&uncompressed.as_bytes()[1..]);
 
-    rip_result.to_vec()
+            Ok(pk_hash[12..].to_vec())
+        }
+    }
 }
 
 fn decode_bech32(input: &str) -> Result<Vec<u8>, Error> {
@@ -376,14 +718,147 @@ fn decode_bech32(input: &str) -> Result<Vec<u8>, Error> {
     Ok(bytes)
 }
 
-fn disk_store_path(folder_name: &str) -> Result<PathBuf, Error> {
+fn disk_store_path(folder_name: &str, backend: &str) -> Result<PathBuf, Error> {
     let home = dirs_next::home_dir()
         .ok_or_else(|| Kind::KeyStore.context("cannot retrieve home folder location"))?;
 
     let folder = Path::new(home.as_path())
         .join(KEYSTORE_DEFAULT_FOLDER)
         .join(folder_name)
-        .join(KEYSTORE_DISK_BACKEND);
+        .join(backend);
 
     Ok(folder)
 }
+
+/// Create the key file at the given path, or truncate it if it exists. On Unix, the file is
+/// only readable and writable by its owner.
+fn create_key_file(path: &Path) -> Result<File, Error> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let file = options
+        .open(path)
+        .map_err(|e| Kind::KeyStore.context(format!("error creating the key file: {}", e)))?;
+
+    // The mode only applies to new files, so restrict the permissions of existing files too
+    #[cfg(unix)]
+    file.set_permissions(Permissions::from_mode(0o600))
+        .map_err(|e| Kind::KeyStore.context(format!("error creating the key file: {}", e)))?;
+
+    Ok(file)
+}
+
+fn create_keys_folder(chain_id: &ChainId, backend: &str) -> Result<PathBuf, Error> {
+    let keys_folder = disk_store_path(chain_id.as_str(), backend).map_err(|e| {
+        Kind::KeyStore.context(format!("failed to compute keys folder path: {:?}", e))
+    })?;
+
+    // Create keys folder if it does not exist
+    fs::create_dir_all(&keys_folder)
+        .map_err(|e| Kind::KeyStore.context(format!("failed to create keys folder: {:?}", e)))?;
+
+    Ok(keys_folder)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use crate::config::AddressType;
+
+    use super::errors::Kind;
+    use super::{
+        get_address, Encrypted, EncryptedKeyFile, HDPath, KeyEntry, KeyRing, KeyStore, Passphrase,
+        Store,
+    };
+
+    const MNEMONIC: &str = "odor sibling reason pistol carry injury end mountain gauge velvet \
+        trouble beef casual find cigar before pen drift noise meat slush pistol chronic wool";
+
+    fn key_entry() -> KeyEntry {
+        let chain_id = "ibc-0".parse().unwrap();
+        let keyring = KeyRing::new(Store::Memory, "cosmos", &chain_id).unwrap();
+        let hd_path = "m/44'/118'/0'/0/0".parse::<HDPath>().unwrap();
+        keyring
+            .key_from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos)
+            .unwrap()
+    }
+
+    fn passphrase(passphrase: &str) -> Passphrase {
+        Passphrase::new(passphrase.to_string())
+    }
+
+    #[test]
+    fn encrypted_key_file_round_trip() {
+        let key = key_entry();
+        let encrypted = EncryptedKeyFile::encrypt(&key, &passphrase("secret")).unwrap();
+
+        assert_eq!(encrypted.decrypt(&passphrase("secret")).unwrap(), key);
+    }
+
+    #[test]
+    fn encrypted_key_file_wrong_passphrase() {
+        let encrypted = EncryptedKeyFile::encrypt(&key_entry(), &passphrase("secret")).unwrap();
+
+        let err = encrypted
+            .decrypt(&passphrase("not the secret"))
+            .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidPassphrase));
+    }
+
+    #[test]
+    fn encrypted_key_file_tampered_ciphertext() {
+        let mut encrypted = EncryptedKeyFile::encrypt(&key_entry(), &passphrase("secret")).unwrap();
+
+        let mut ciphertext = hex::decode(&encrypted.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        encrypted.ciphertext = hex::encode(ciphertext);
+
+        let err = encrypted.decrypt(&passphrase("secret")).unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidPassphrase));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn encrypted_key_store_files_are_private() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let store = std::env::temp_dir().join(format!("hermes-keys-{}", std::process::id()));
+        fs::create_dir_all(&store).unwrap();
+
+        let mut keys = Encrypted::new("cosmos".to_string(), store.clone(), passphrase("secret"));
+        let key = key_entry();
+        keys.add_key("relayer", key.clone()).unwrap();
+
+        let mode = fs::metadata(store.join("relayer.json"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        assert_eq!(keys.get_key("relayer").unwrap(), key);
+
+        fs::remove_dir_all(&store).unwrap();
+    }
+
+    #[test]
+    fn ethermint_address() {
+        // The public key of the secp256k1 private key `1`, whose Ethereum address is well-known
+        let public_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+
+        let address_type = AddressType::Ethermint {
+            pk_type: crate::config::default::ethermint_pk_type(),
+        };
+
+        assert_eq!(
+            hex::encode(get_address(&public_key, &address_type).unwrap()),
+            "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+}
@@ -35,6 +35,15 @@ pub enum Kind {
     #[error("key store error")]
     KeyStore,
 
+    #[error("missing passphrase for the encrypted key store")]
+    MissingPassphrase,
+
+    #[error("invalid passphrase for the encrypted key store, or corrupted key file")]
+    InvalidPassphrase,
+
+    #[error("remote signer error")]
+    RemoteSigner,
+
     #[error("invalid HD path: {0}")]
     InvalidHdPath(String),
 }
@@ -0,0 +1,309 @@
+//! Signing of messages with the keys of the key ring, either in-process with the keys
+//! held by a local key store, or by an external signer holding the keys.
+//!
+//! ## Remote signer protocol
+//!
+//! The relayer connects to the remote signer over a Unix socket, and sends each request as
+//! a single line of JSON, to which the signer replies with a single line of JSON.
+//! Byte strings are hex-encoded.
+//!
+//! - `{"method": "public_key", "key_name": "..."}` requests the compressed secp256k1 public
+//!   key of the given key, to which the signer replies with `{"public_key": "..."}`.
+//! - `{"method": "sign", "key_name": "...", "sign_bytes": "...", "digest": "..."}` requests the
+//!   ECDSA signature, over the `sha256` (the default) or `keccak256` digest of `sign_bytes`, with
+//!   the given key, to which the signer replies with the 64 bytes signature `r || s` as
+//!   `{"signature": "..."}`.
+//!
+//! Any request can be answered with `{"error": "..."}` instead.
+
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[cfg(unix)]
+use std::{
+    io::{BufRead, BufReader, Write},
+    os::unix::net::UnixStream,
+};
+
+use k256::ecdsa::{
+    signature::{DigestSigner, Signer as _},
+    Signature, SigningKey,
+};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use super::errors::{Error, Kind};
+use super::KeyStore;
+
+/// The digest of a message over which the message is signed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignDigest {
+    Sha256,
+    Keccak256,
+}
+
+impl Default for SignDigest {
+    fn default() -> Self {
+        SignDigest::Sha256
+    }
+}
+
+/// Signs messages with the keys of the key ring.
+pub trait Signer: Debug + Send + Sync {
+    /// Returns the compressed secp256k1 public key of the key with the given name.
+    fn public_key(&self, key_name: &str) -> Result<Vec<u8>, Error>;
+
+    /// Signs the given digest of the given message with the key with the given name,
+    /// and returns the 64 bytes signature `r || s`.
+    fn sign(&self, key_name: &str, msg: &[u8], digest: SignDigest) -> Result<Vec<u8>, Error>;
+}
+
+/// Every local key store signs in-process with the private keys it holds.
+impl<S> Signer for S
+where
+    S: KeyStore + Debug + Send + Sync,
+{
+    fn public_key(&self, key_name: &str) -> Result<Vec<u8>, Error> {
+        let key = self.get_key(key_name)?;
+        Ok(key.public_key.public_key.to_bytes())
+    }
+
+    fn sign(&self, key_name: &str, msg: &[u8], digest: SignDigest) -> Result<Vec<u8>, Error> {
+        let key = self.get_key(key_name)?;
+
+        let private_key_bytes = key.private_key.private_key.to_bytes();
+        let signing_key = SigningKey::from_bytes(private_key_bytes.as_slice()).map_err(|_| {
+            Kind::InvalidKey.context("could not build signing key from private key bytes")
+        })?;
+
+        let signature: Signature = match digest {
+            SignDigest::Sha256 => signing_key.sign(msg),
+            SignDigest::Keccak256 => signing_key.sign_digest(Keccak256::new().chain(msg)),
+        };
+
+        Ok(signature.as_ref().to_vec())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum SignerRequest {
+    PublicKey {
+        key_name: String,
+    },
+    Sign {
+        key_name: String,
+        sign_bytes: String,
+        #[serde(default)]
+        digest: SignDigest,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SignerResponse {
+    PublicKey(String),
+    Signature(String),
+    Error(String),
+}
+
+/// A signer which forwards the messages to sign to an external signer listening
+/// on a Unix socket, so that the private keys never enter the relayer process.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemoteSigner {
+    socket: PathBuf,
+    timeout: Duration,
+}
+
+impl RemoteSigner {
+    pub fn new(socket: PathBuf, timeout: Duration) -> Self {
+        Self { socket, timeout }
+    }
+
+    #[cfg(unix)]
+    fn request(&self, request: &SignerRequest) -> Result<SignerResponse, Error> {
+        let stream = UnixStream::connect(&self.socket).map_err(|e| {
+            Kind::RemoteSigner.context(format!(
+                "cannot connect to the remote signer at '{}': {}",
+                self.socket.display(),
+                e
+            ))
+        })?;
+
+        stream
+            .set_read_timeout(Some(self.timeout))
+            .and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+            .map_err(|e| Kind::RemoteSigner.context(e))?;
+
+        let mut line = serde_json::to_vec(request).map_err(|e| Kind::RemoteSigner.context(e))?;
+        line.push(b'\n');
+
+        (&stream)
+            .write_all(&line)
+            .map_err(|e| Kind::RemoteSigner.context(e))?;
+
+        let mut response = String::new();
+        BufReader::new(&stream)
+            .read_line(&mut response)
+            .map_err(|e| Kind::RemoteSigner.context(e))?;
+
+        let response = serde_json::from_str(&response).map_err(|e| {
+            Kind::RemoteSigner.context(format!("invalid response from the remote signer: {}", e))
+        })?;
+
+        match response {
+            SignerResponse::Error(e) => Err(Kind::RemoteSigner.context(e).into()),
+            response => Ok(response),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn request(&self, _request: &SignerRequest) -> Result<SignerResponse, Error> {
+        Err(Kind::RemoteSigner
+            .context("remote signers are only supported on Unix")
+            .into())
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public_key(&self, key_name: &str) -> Result<Vec<u8>, Error> {
+        let request = SignerRequest::PublicKey {
+            key_name: key_name.to_string(),
+        };
+
+        match self.request(&request)? {
+            SignerResponse::PublicKey(public_key) => decode_hex(&public_key),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    fn sign(&self, key_name: &str, msg: &[u8], digest: SignDigest) -> Result<Vec<u8>, Error> {
+        let request = SignerRequest::Sign {
+            key_name: key_name.to_string(),
+            sign_bytes: hex::encode(msg),
+            digest,
+        };
+
+        let signature = match self.request(&request)? {
+            SignerResponse::Signature(signature) => decode_hex(&signature)?,
+            response => return Err(unexpected(response)),
+        };
+
+        if signature.len() != 64 {
+            return Err(Kind::RemoteSigner
+                .context(format!("invalid signature length: {}", signature.len()))
+                .into());
+        }
+
+        Ok(signature)
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(s).map_err(|e| {
+        Kind::RemoteSigner
+            .context(format!("invalid hex in the remote signer response: {}", e))
+            .into()
+    })
+}
+
+fn unexpected(response: SignerResponse) -> Error {
+    Kind::RemoteSigner
+        .context(format!(
+            "unexpected response from the remote signer: {:?}",
+            response
+        ))
+        .into()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::convert::TryFrom;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use test_env_log::test;
+
+    use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+    use crate::config::AddressType;
+    use crate::keyring::{HDPath, KeyRing, KeyStore, Memory, Store};
+
+    use super::{RemoteSigner, SignDigest, Signer, SignerRequest, SignerResponse};
+
+    const MNEMONIC: &str = "odor sibling reason pistol carry injury end mountain gauge velvet \
+        trouble beef casual find cigar before pen drift noise meat slush pistol chronic wool";
+
+    /// Serves the requests of the given number of connections with the keys of the given store,
+    /// standing in for an external signer.
+    fn serve(listener: UnixListener, store: Memory, connections: usize) {
+        for stream in listener.incoming().take(connections) {
+            let stream = stream.unwrap();
+            let mut line = String::new();
+            BufReader::new(&stream).read_line(&mut line).unwrap();
+
+            let response = match serde_json::from_str(&line).unwrap() {
+                SignerRequest::PublicKey { key_name } => match store.public_key(&key_name) {
+                    Ok(public_key) => SignerResponse::PublicKey(hex::encode(public_key)),
+                    Err(e) => SignerResponse::Error(e.to_string()),
+                },
+                SignerRequest::Sign {
+                    key_name,
+                    sign_bytes,
+                    digest,
+                } => match store.sign(&key_name, &hex::decode(sign_bytes).unwrap(), digest) {
+                    Ok(signature) => SignerResponse::Signature(hex::encode(signature)),
+                    Err(e) => SignerResponse::Error(e.to_string()),
+                },
+            };
+
+            let mut response = serde_json::to_vec(&response).unwrap();
+            response.push(b'\n');
+            (&stream).write_all(&response).unwrap();
+        }
+    }
+
+    #[test]
+    fn remote_signer() {
+        let chain_id = "ibc-0".parse().unwrap();
+        let keyring = KeyRing::new(Store::Memory, "cosmos", &chain_id).unwrap();
+        let hd_path = "m/44'/118'/0'/0/0".parse::<HDPath>().unwrap();
+        let key = keyring
+            .key_from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos)
+            .unwrap();
+
+        let mut store = Memory::new("cosmos".to_string());
+        store.add_key("relayer", key).unwrap();
+
+        let socket =
+            std::env::temp_dir().join(format!("hermes-signer-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket);
+
+        let listener = UnixListener::bind(&socket).unwrap();
+        let server = thread::spawn({
+            let store = store.clone();
+            move || serve(listener, store, 3)
+        });
+
+        let signer = RemoteSigner::new(socket.clone(), Duration::from_secs(5));
+        let msg = b"sign bytes".to_vec();
+
+        let public_key = signer.public_key("relayer").unwrap();
+        assert_eq!(public_key, store.public_key("relayer").unwrap());
+
+        let signature = signer.sign("relayer", &msg, SignDigest::Sha256).unwrap();
+        let verifying_key = VerifyingKey::from_sec1_bytes(&public_key).unwrap();
+        let signature = Signature::try_from(signature.as_slice()).unwrap();
+        assert!(verifying_key.verify(&msg, &signature).is_ok());
+
+        // Errors of the remote signer are reported to the caller
+        assert!(signer.sign("unknown", &msg, SignDigest::Sha256).is_err());
+
+        server.join().unwrap();
+        std::fs::remove_file(&socket).unwrap();
+    }
+}
@@ -0,0 +1,271 @@
+//! Persistent storage for the light blocks trusted or verified by the Tendermint light client.
+
+use std::path::PathBuf;
+
+use tendermint_light_client::types::{Height as TMHeight, LightBlock, Status};
+
+use ibc::ics24_host::identifier::ChainId;
+
+use crate::error::{self, Error};
+use crate::util::sled::{self as sled_util, KeyValueDb};
+
+pub const LIGHT_STORE_DEFAULT_FOLDER: &str = ".hermes/light_store/";
+
+/// A store of light blocks backed by a `sled` database, keyed per chain and per status.
+///
+/// Only trusted and verified blocks are persisted, as unverified and failed blocks are only
+/// meaningful within a single verification.
+///
+/// Light blocks are keyed by their height as a `u64`, whose CBOR encoding preserves the
+/// ordering of heights, so that iterating over a status yields blocks by ascending height.
+#[derive(Clone, Debug)]
+pub struct SledLightStore {
+    db: sled::Db,
+    trusted: KeyValueDb<u64, LightBlock>,
+    verified: KeyValueDb<u64, LightBlock>,
+}
+
+impl SledLightStore {
+    /// Open the light store of the given chain, located under the Hermes home folder.
+    pub fn open(chain_id: &ChainId) -> Result<Self, Error> {
+        let home = dirs_next::home_dir()
+            .ok_or_else(|| error::Kind::Store.context("unable to retrieve the home directory"))?;
+
+        let path = home
+            .join(LIGHT_STORE_DEFAULT_FOLDER)
+            .join(chain_id.to_string());
+
+        Self::open_at(path, chain_id)
+    }
+
+    /// Open the light store of the given chain in a database located at `path`.
+    pub fn open_at(path: impl Into<PathBuf>, chain_id: &ChainId) -> Result<Self, Error> {
+        let db = sled::open(path.into()).map_err(|e| error::Kind::Store.context(e))?;
+        Ok(Self::new(db, chain_id))
+    }
+
+    /// Create a light store for the given chain which is deleted when dropped.
+    pub fn temporary(chain_id: &ChainId) -> Result<Self, Error> {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(|e| error::Kind::Store.context(e))?;
+
+        Ok(Self::new(db, chain_id))
+    }
+
+    fn new(db: sled::Db, chain_id: &ChainId) -> Self {
+        Self {
+            db,
+            trusted: sled_util::key_value(format!("light_store/{}/trusted/", chain_id)),
+            verified: sled_util::key_value(format!("light_store/{}/verified/", chain_id)),
+        }
+    }
+
+    fn db(&self, status: Status) -> Option<&KeyValueDb<u64, LightBlock>> {
+        match status {
+            Status::Trusted => Some(&self.trusted),
+            Status::Verified => Some(&self.verified),
+            Status::Unverified | Status::Failed => None,
+        }
+    }
+
+    /// Get the light block at the given height with the given status, if any.
+    pub fn get(&self, height: TMHeight, status: Status) -> Result<Option<LightBlock>, Error> {
+        match self.db(status) {
+            Some(db) => db.fetch(&self.db, &height.value()),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the light block at the given height if it is either trusted or verified.
+    pub fn get_trusted_or_verified(&self, height: TMHeight) -> Result<Option<LightBlock>, Error> {
+        match self.get(height, Status::Trusted)? {
+            Some(light_block) => Ok(Some(light_block)),
+            None => self.get(height, Status::Verified),
+        }
+    }
+
+    /// Store the given light block with the given status, replacing the block stored
+    /// at the same height with another status, if any.
+    ///
+    /// Blocks which are neither trusted nor verified are not persisted.
+    pub fn update(&self, light_block: &LightBlock, status: Status) -> Result<(), Error> {
+        let height = light_block.height().value();
+
+        for other in &[Status::Trusted, Status::Verified] {
+            if *other != status {
+                self.remove(light_block.height(), *other)?;
+            }
+        }
+
+        match self.db(status) {
+            Some(db) => db.insert(&self.db, &height, light_block),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove the light block at the given height with the given status, if any.
+    pub fn remove(&self, height: TMHeight, status: Status) -> Result<(), Error> {
+        match self.db(status) {
+            Some(db) => db.remove(&self.db, &height.value()),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove the trusted and verified light blocks by ascending height for as long as
+    /// `expired` holds, eg. for the blocks which are too old to be trusted anymore.
+    /// Returns the number of removed blocks.
+    pub fn prune_while(&self, expired: impl Fn(&LightBlock) -> bool) -> Result<usize, Error> {
+        let mut pruned = 0;
+
+        for db in &[&self.trusted, &self.verified] {
+            let heights: Vec<u64> = db
+                .iter(&self.db)
+                .take_while(|light_block| expired(light_block))
+                .map(|light_block| light_block.height().value())
+                .collect();
+
+            for height in heights {
+                db.remove(&self.db, &height)?;
+                pruned += 1;
+            }
+        }
+
+        Ok(pruned)
+    }
+
+    /// Get the highest trusted or verified light block whose height lies within the
+    /// inclusive range `[from, to]`, if any.
+    pub fn highest_trusted_or_verified_within(
+        &self,
+        from: TMHeight,
+        to: TMHeight,
+    ) -> Option<LightBlock> {
+        if from > to {
+            return None;
+        }
+
+        let highest_within = |db: &KeyValueDb<u64, LightBlock>| {
+            db.range(&self.db, &from.value(), &to.value())
+                .ok()
+                .and_then(|mut light_blocks| light_blocks.next_back())
+        };
+
+        let trusted = highest_within(&self.trusted);
+        let verified = highest_within(&self.verified);
+
+        match (trusted, verified) {
+            (Some(trusted), Some(verified)) if verified.height() > trusted.height() => {
+                Some(verified)
+            }
+            (Some(trusted), _) => Some(trusted),
+            (None, verified) => verified,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use test_env_log::test;
+
+    use tendermint_light_client::types::{Height as TMHeight, LightBlock, Status};
+
+    use ibc::ics24_host::identifier::ChainId;
+    use ibc::mock::host::HostBlock;
+
+    use super::SledLightStore;
+
+    fn light_block(chain_id: &ChainId, height: u64) -> LightBlock {
+        let block = HostBlock::generate_tm_block(chain_id.clone(), height);
+        LightBlock::new(
+            block.signed_header,
+            block.validators,
+            block.next_validators,
+            block.provider,
+        )
+    }
+
+    fn height(height: u64) -> TMHeight {
+        TMHeight::try_from(height).unwrap()
+    }
+
+    #[test]
+    fn highest_trusted_or_verified_within() {
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let store = SledLightStore::temporary(&chain_id).unwrap();
+
+        for h in &[5, 30, 300] {
+            store
+                .update(&light_block(&chain_id, *h), Status::Verified)
+                .unwrap();
+        }
+        store
+            .update(&light_block(&chain_id, 2), Status::Trusted)
+            .unwrap();
+        store
+            .update(&light_block(&chain_id, 7), Status::Unverified)
+            .unwrap();
+
+        let highest = |from, to| {
+            store
+                .highest_trusted_or_verified_within(height(from), height(to))
+                .map(|light_block| light_block.height().value())
+        };
+
+        assert_eq!(highest(1, 1000), Some(300));
+        assert_eq!(highest(1, 299), Some(30));
+        assert_eq!(highest(1, 29), Some(5));
+        assert_eq!(highest(1, 4), Some(2));
+        assert_eq!(highest(6, 29), None);
+        assert_eq!(highest(301, 1000), None);
+    }
+
+    #[test]
+    fn prune_while() {
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let store = SledLightStore::temporary(&chain_id).unwrap();
+
+        for h in &[3, 5, 30] {
+            store
+                .update(&light_block(&chain_id, *h), Status::Verified)
+                .unwrap();
+        }
+        for h in &[2, 10] {
+            store
+                .update(&light_block(&chain_id, *h), Status::Trusted)
+                .unwrap();
+        }
+
+        let pruned = store
+            .prune_while(|light_block| light_block.height().value() < 8)
+            .unwrap();
+        assert_eq!(pruned, 3);
+
+        for h in &[2, 3, 5] {
+            assert_eq!(store.get_trusted_or_verified(height(*h)).unwrap(), None);
+        }
+        for h in &[10, 30] {
+            assert!(store.get_trusted_or_verified(height(*h)).unwrap().is_some());
+        }
+    }
+
+    #[test]
+    fn update_replaces_status() {
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let store = SledLightStore::temporary(&chain_id).unwrap();
+        let block = light_block(&chain_id, 10);
+
+        store.update(&block, Status::Verified).unwrap();
+        assert!(store.get(height(10), Status::Verified).unwrap().is_some());
+
+        store.update(&block, Status::Trusted).unwrap();
+        assert!(store.get(height(10), Status::Verified).unwrap().is_none());
+        assert_eq!(
+            store.get_trusted_or_verified(height(10)).unwrap(),
+            Some(block)
+        );
+    }
+}
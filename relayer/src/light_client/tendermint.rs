@@ -1,11 +1,17 @@
 use std::convert::TryFrom;
+use std::time::Duration;
 
 use itertools::Itertools;
 
 use tendermint_light_client::{
-    components::{self, io::AtHeight},
+    components::{
+        self,
+        clock::{Clock, SystemClock},
+        io::AtHeight,
+    },
     light_client::{LightClient as TmLightClient, Options as TmOptions},
     operations,
+    predicates::{ProdPredicates, VerificationPredicates},
     state::State as LightClientState,
     store::{memory::MemoryStore, LightStore},
     types::Height as TMHeight,
@@ -28,7 +34,7 @@ use ibc::{
     },
     ics24_host::identifier::ChainId,
 };
-use tracing::trace;
+use tracing::{trace, warn};
 
 use crate::error::Kind;
 use crate::{
@@ -37,12 +43,14 @@ use crate::{
     error::{self, Error},
 };
 
+use super::store::SledLightStore;
 use super::Verified;
 
 pub struct LightClient {
     chain_id: ChainId,
     peer_id: PeerId,
     io: components::io::ProdIo,
+    store: SledLightStore,
 }
 
 impl super::LightClient<CosmosSdkChain> for LightClient {
@@ -68,16 +76,35 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
         let target_height = TMHeight::try_from(target.revision_height)
             .map_err(|e| error::Kind::InvalidHeight.context(e))?;
 
-        let client = self.prepare_client(client_state)?;
-        let mut state = self.prepare_state(trusted)?;
+        let options = self.options(client_state)?;
+        let trusting_period = options.trusting_period;
+
+        let client = self.prepare_client(options);
+        let (mut state, start) = self.prepare_state(trusted, target_height)?;
+
+        // Verify the most recent block known to be valid first, so that the bisection towards
+        // the target can start from there rather than from the trusted height.
+        let mut start_trace = vec![];
+        if let Some(start_height) = start {
+            match client.verify_to_target(start_height, &mut state) {
+                Ok(_) => start_trace = state.get_trace(start_height),
+                Err(e) => warn!(
+                    "failed to verify stored light block at height {} on chain {}: {}",
+                    start_height, self.chain_id, e
+                ),
+            }
+        }
 
         // Verify the target header
         let target = client
             .verify_to_target(target_height, &mut state)
             .map_err(|e| error::Kind::LightClient(self.chain_id.to_string()).context(e))?;
 
+        self.persist_state(&state, trusting_period);
+
         // Collect the verification trace for the target block
-        let target_trace = state.get_trace(target.height());
+        let mut target_trace = state.get_trace(target.height());
+        target_trace.extend(start_trace);
 
         // Compute the minimal supporting set, sorted by ascending height
         let supporting = target_trace
@@ -176,19 +203,29 @@ impl LightClient {
 
         let io = components::io::ProdIo::new(peer_id, rpc_client, Some(config.rpc_timeout));
 
+        // The light store may already be in use, eg. by another instance of the relayer,
+        // in which case verified blocks are only kept for the lifetime of this client.
+        let store = match SledLightStore::open(&config.id) {
+            Ok(store) => store,
+            Err(e) => {
+                warn!(
+                    "failed to open the light store of chain {}, falling back to a temporary store: {}",
+                    config.id, e
+                );
+                SledLightStore::temporary(&config.id)?
+            }
+        };
+
         Ok(Self {
             chain_id: config.id.clone(),
             peer_id,
             io,
+            store,
         })
     }
 
-    fn prepare_client(&self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
-        let clock = components::clock::SystemClock;
-        let hasher = operations::hasher::ProdHasher;
-        let verifier = components::verifier::ProdVerifier::default();
-        let scheduler = components::scheduler::basic_bisecting_schedule;
-
+    /// The options of the light client verifying headers for the given client state.
+    fn options(&self, client_state: &AnyClientState) -> Result<TmOptions, Error> {
         let client_state =
             downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
                 error::Kind::ClientTypeMismatch {
@@ -197,33 +234,103 @@ impl LightClient {
                 }
             })?;
 
-        let params = TmOptions {
+        Ok(TmOptions {
             trust_threshold: client_state.trust_level,
             trusting_period: client_state.trusting_period,
             clock_drift: client_state.max_clock_drift,
-        };
+        })
+    }
 
-        Ok(TmLightClient::new(
+    fn prepare_client(&self, options: TmOptions) -> TmLightClient {
+        let clock = SystemClock;
+        let hasher = operations::hasher::ProdHasher;
+        let verifier = components::verifier::ProdVerifier::default();
+        let scheduler = components::scheduler::basic_bisecting_schedule;
+
+        TmLightClient::new(
             self.peer_id,
-            params,
+            options,
             clock,
             scheduler,
             verifier,
             hasher,
             self.io.clone(),
-        ))
+        )
     }
 
-    fn prepare_state(&self, trusted: ibc::Height) -> Result<LightClientState, Error> {
+    /// Build the verification state for the given trusted and target heights.
+    ///
+    /// The trusted block is taken from the light store if it was previously verified, and
+    /// fetched from the chain otherwise. Also returns the height of the most recent block
+    /// of the light store between the trusted and target heights, if any, which is added
+    /// to the state as unverified so that it can be verified without being fetched again.
+    fn prepare_state(
+        &self,
+        trusted: ibc::Height,
+        target_height: TMHeight,
+    ) -> Result<(LightClientState, Option<TMHeight>), Error> {
         let trusted_height = TMHeight::try_from(trusted.revision_height)
             .map_err(|e| error::Kind::InvalidHeight.context(e))?;
 
-        let trusted_block = self.fetch_light_block(AtHeight::At(trusted_height))?;
+        let trusted_block = match self.store.get_trusted_or_verified(trusted_height)? {
+            Some(trusted_block) => trusted_block,
+            None => self.fetch_light_block(AtHeight::At(trusted_height))?,
+        };
 
         let mut store = MemoryStore::new();
         store.insert(trusted_block, Status::Trusted);
 
-        Ok(LightClientState::new(store))
+        let start = self
+            .store
+            .highest_trusted_or_verified_within(trusted_height.increment(), target_height)
+            .map(|start_block| {
+                let start_height = start_block.height();
+                store.insert(start_block, Status::Unverified);
+                start_height
+            });
+
+        Ok((LightClientState::new(store), start))
+    }
+
+    /// Persist the trusted and verified blocks of the given verification state into
+    /// the light store, so that they can be reused by subsequent verifications.
+    ///
+    /// The blocks of the light store which are outside of the given trusting period are
+    /// removed meanwhile, so that the store does not grow without bound. As the time of
+    /// blocks increases with their height, only the lowest blocks need to be inspected.
+    fn persist_state(&self, state: &LightClientState, trusting_period: Duration) {
+        for status in &[Status::Trusted, Status::Verified] {
+            for light_block in state.light_store.all(*status) {
+                if let Err(e) = self.store.update(&light_block, *status) {
+                    warn!(
+                        "failed to persist light block at height {} of chain {}: {}",
+                        light_block.height(),
+                        self.chain_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        let now = SystemClock.now();
+        let expired = |light_block: &LightBlock| {
+            ProdPredicates
+                .is_within_trust_period(&light_block.signed_header.header, trusting_period, now)
+                .is_err()
+        };
+
+        match self.store.prune_while(expired) {
+            Ok(0) => {}
+            Ok(pruned) => trace!(
+                "pruned {} expired light blocks of chain {}",
+                pruned,
+                self.chain_id
+            ),
+            Err(e) => warn!(
+                "failed to prune the light store of chain {}: {}",
+                self.chain_id, e
+            ),
+        }
     }
 
     fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, Error> {
@@ -1,12 +1,13 @@
 #![allow(clippy::borrowed_box)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::thread;
 use std::time::Instant;
 
 use itertools::Itertools;
 use prost_types::Any;
+use serde_derive::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, error, info, trace, warn};
 
@@ -15,11 +16,17 @@ use ibc::{
     events::{IbcEvent, IbcEventType, PrettyEvents},
     ics03_connection::connection::State as ConnectionState,
     ics04_channel::{
-        channel::{ChannelEnd, Order, QueryPacketEventDataRequest, State as ChannelState},
+        channel::{
+            ChannelEnd, Order, QueryPacketEventDataRequest, QueryPacketEventRangeRequest,
+            State as ChannelState,
+        },
         events::{SendPacket, WriteAcknowledgement},
         msgs::{
-            acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
-            recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
+            acknowledgement::MsgAcknowledgement,
+            chan_close_confirm::MsgChannelCloseConfirm,
+            recv_packet::MsgRecvPacket,
+            timeout::{self, MsgTimeout},
+            timeout_on_close::MsgTimeoutOnClose,
         },
         packet::{Packet, PacketMsgType, Sequence},
     },
@@ -42,6 +49,7 @@ use crate::connection::ConnectionError;
 use crate::error::Error;
 use crate::event::monitor::EventBatch;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
+use crate::state::StoredOperationalData;
 use crate::transfer::PacketError;
 
 const MAX_RETRIES: usize = 5;
@@ -82,7 +90,7 @@ pub enum LinkError {
     SendError(Box<IbcEvent>),
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum OperationalDataTarget {
     Source,
     Destination,
@@ -206,16 +214,43 @@ pub struct RelayPath {
     src_operational_data: Vec<OperationalData>,
     // The operational data targeting the destination chain comprises mostly RecvPacket and Ack msgs.
     dst_operational_data: Vec<OperationalData>,
+    // Marks whether the operational data changed since they were last persisted
+    // in the state store of the source chain.
+    schedule_changed: bool,
+    // The signers of the messages of this path, on the source and destination chain.
+    // These are picked once among the keys of each chain, such that the transactions of
+    // different paths can be signed by different keys.
+    src_signer: Signer,
+    dst_signer: Signer,
 }
 
 impl RelayPath {
-    pub fn new(channel: Channel) -> Self {
-        Self {
+    pub fn new(channel: Channel) -> Result<Self, LinkError> {
+        let src_signer = channel.src_chain().next_signer().map_err(|e| {
+            LinkError::Failed(format!(
+                "could not retrieve signer from src chain {} with error: {}",
+                channel.src_chain().id(),
+                e
+            ))
+        })?;
+
+        let dst_signer = channel.dst_chain().next_signer().map_err(|e| {
+            LinkError::Failed(format!(
+                "could not retrieve signer from dst chain {} with error: {}",
+                channel.dst_chain().id(),
+                e
+            ))
+        })?;
+
+        Ok(Self {
             channel,
             clear_packets: true,
             src_operational_data: vec![],
             dst_operational_data: vec![],
-        }
+            schedule_changed: false,
+            src_signer,
+            dst_signer,
+        })
     }
 
     pub fn src_chain(&self) -> &Box<dyn ChainHandle> {
@@ -286,24 +321,12 @@ impl RelayPath {
             .map_err(|e| ChannelError::QueryError(self.src_chain().id(), e))?)
     }
 
-    fn src_signer(&self) -> Result<Signer, LinkError> {
-        self.src_chain().get_signer().map_err(|e| {
-            LinkError::Failed(format!(
-                "could not retrieve signer from src chain {} with error: {}",
-                self.src_chain().id(),
-                e
-            ))
-        })
+    fn src_signer(&self) -> &Signer {
+        &self.src_signer
     }
 
-    fn dst_signer(&self) -> Result<Signer, LinkError> {
-        self.dst_chain().get_signer().map_err(|e| {
-            LinkError::Failed(format!(
-                "could not retrieve signer from dst chain {} with error: {}",
-                self.dst_chain().id(),
-                e
-            ))
-        })
+    fn dst_signer(&self) -> &Signer {
+        &self.dst_signer
     }
 
     pub fn dst_latest_height(&self) -> Result<Height, LinkError> {
@@ -312,6 +335,16 @@ impl RelayPath {
             .map_err(|e| LinkError::QueryError(self.dst_chain().id(), e))
     }
 
+    /// Queries the next sequence the destination chain expects to receive on the channel.
+    fn dst_next_sequence_recv(&self) -> Result<Sequence, LinkError> {
+        self.dst_chain()
+            .query_next_sequence_receive(QueryNextSequenceReceiveRequest {
+                port_id: self.dst_port_id().to_string(),
+                channel_id: self.dst_channel_id()?.to_string(),
+            })
+            .map_err(|e| LinkError::QueryError(self.dst_chain().id(), e))
+    }
+
     fn unordered_channel(&self) -> bool {
         self.channel.ordering == Order::Unordered
     }
@@ -323,14 +356,14 @@ impl RelayPath {
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_dst_client();
         client
-            .build_update_client(height)
+            .build_update_client_signed_by(height, Height::zero(), self.dst_signer().clone())
             .map_err(LinkError::ClientError)
     }
 
     pub fn build_update_client_on_src(&self, height: Height) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_src_client();
         client
-            .build_update_client(height)
+            .build_update_client_signed_by(height, Height::zero(), self.src_signer().clone())
             .map_err(LinkError::ClientError)
     }
 
@@ -346,7 +379,7 @@ impl RelayPath {
             port_id: self.dst_port_id().clone(),
             channel_id: src_channel_id.clone(),
             proofs,
-            signer: self.dst_signer()?,
+            signer: self.dst_signer().clone(),
         };
 
         Ok(new_msg.to_any())
@@ -412,11 +445,137 @@ impl RelayPath {
         Err(LinkError::OldPacketClearingFailed)
     }
 
+    /// Schedules the relaying of the packets sent, and of the acknowledgements written, on the
+    /// source chain above `resume_height` and up to the given [`Height`], i.e., those whose
+    /// events the relayer missed while it was stopped.
+    fn relay_missed_packets(
+        &mut self,
+        resume_height: Height,
+        height: Height,
+    ) -> Result<(), LinkError> {
+        if resume_height >= height {
+            return Ok(());
+        }
+
+        for _ in 0..MAX_RETRIES {
+            let events = self
+                .missed_packet_events(IbcEventType::SendPacket, resume_height, height)
+                .and_then(|mut events| {
+                    events.extend(self.missed_packet_events(
+                        IbcEventType::WriteAck,
+                        resume_height,
+                        height,
+                    )?);
+                    Ok(events)
+                });
+
+            if let Ok(mut events) = events {
+                for event in events.iter_mut() {
+                    event.set_height(height);
+                }
+
+                return self.events_to_operational_data(events);
+            }
+        }
+        Err(LinkError::OldPacketClearingFailed)
+    }
+
+    /// Returns the SendPacket or WriteAcknowledgement events of this path emitted on the source
+    /// chain above `resume_height` and up to the given [`Height`], which are neither scheduled
+    /// already nor relayed in the meantime.
+    fn missed_packet_events(
+        &self,
+        event_id: IbcEventType,
+        resume_height: Height,
+        height: Height,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        let src = (self.src_port_id().clone(), self.src_channel_id()?.clone());
+        let dst = (self.dst_port_id().clone(), self.dst_channel_id()?.clone());
+
+        // Acknowledgements are written on the source chain for the packets sent by the destination
+        let ((source_port_id, source_channel_id), (destination_port_id, destination_channel_id)) =
+            match event_id {
+                IbcEventType::WriteAck => (dst.clone(), src),
+                _ => (src, dst.clone()),
+            };
+
+        let events = self
+            .src_chain()
+            .query_txs(QueryTxRequest::PacketRange(QueryPacketEventRangeRequest {
+                event_id: event_id.clone(),
+                source_port_id,
+                source_channel_id,
+                destination_port_id,
+                destination_channel_id,
+                from_height: resume_height,
+                to_height: height,
+            }))
+            .map_err(|e| LinkError::QueryError(self.src_chain().id(), e))?;
+
+        let packet_sequence = |event: &IbcEvent| match event {
+            IbcEvent::SendPacket(send_ev) => Some(u64::from(send_ev.packet.sequence)),
+            IbcEvent::WriteAcknowledgement(ack_ev) => Some(u64::from(ack_ev.packet.sequence)),
+            _ => None,
+        };
+
+        let scheduled = self.scheduled_sequences(event_id.clone());
+        let sequences: Vec<u64> = events
+            .iter()
+            .filter_map(packet_sequence)
+            .filter(|sequence| !scheduled.contains(&Sequence::from(*sequence)))
+            .collect();
+
+        if sequences.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Keep the packets not received, or whose acknowledgement is not received, on destination
+        let (port_id, channel_id) = (dst.0.to_string(), dst.1.to_string());
+        let pending = match event_id {
+            IbcEventType::WriteAck => {
+                self.dst_chain()
+                    .query_unreceived_acknowledgement(QueryUnreceivedAcksRequest {
+                        port_id,
+                        channel_id,
+                        packet_ack_sequences: sequences,
+                    })
+            }
+            _ => self
+                .dst_chain()
+                .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                    port_id,
+                    channel_id,
+                    packet_commitment_sequences: sequences,
+                }),
+        }
+        .map_err(|e| LinkError::QueryError(self.dst_chain().id(), e))?;
+
+        info!(
+            "[{}] found {} {} event(s) emitted on {} since height {} and pending on {}",
+            self,
+            pending.len(),
+            event_id.as_str(),
+            self.src_chain().id(),
+            resume_height,
+            self.dst_chain().id(),
+        );
+
+        Ok(events
+            .into_iter()
+            .filter(|event| packet_sequence(event).map_or(false, |seq| pending.contains(&seq)))
+            .collect())
+    }
+
     /// Queries the source chain at the given [`Height`]
     /// to find any packets or acknowledgements that are pending,
     /// and fetches the relevant packet event data. Finally, this
     /// method also schedules the corresponding operational data,
     /// so that the relayer will later relay the pending packets.
+    ///
+    /// If the relayer was restarted, the events up to the height from which the path resumes
+    /// were relayed already, or are scheduled along with the restored operational data, so only
+    /// the events emitted since then are searched for. Otherwise, all the pending packets of the
+    /// channel are searched for.
     pub fn clear_packets(&mut self, above_height: Height) -> Result<(), LinkError> {
         info!(
             "[{}] clearing pending packets from events before height {}",
@@ -431,7 +590,10 @@ impl RelayPath {
             ))
         })?;
 
-        self.relay_pending_packets(clear_height)?;
+        match self.src_chain().query_resume_height()? {
+            Some(resume_height) => self.relay_missed_packets(resume_height, clear_height)?,
+            None => self.relay_pending_packets(clear_height)?,
+        }
 
         info!(
             "[{}] finished scheduling the clearing of pending packets",
@@ -443,8 +605,10 @@ impl RelayPath {
 
     /// Generate & schedule operational data from the input `batch` of IBC events.
     pub fn update_schedule(&mut self, batch: EventBatch) -> Result<(), LinkError> {
-        // With the first batch of events, also trigger the clearing of old packets.
+        // With the first batch of events, also restore the operational data persisted before
+        // the relayer was restarted, and trigger the clearing of old packets.
         if self.clear_packets {
+            self.restore_schedule(batch.height)?;
             self.clear_packets(batch.height)?;
 
             // Disable further clearing of old packet.
@@ -456,7 +620,103 @@ impl RelayPath {
         let events = self.filter_events(&batch.events);
 
         // Transform the events into operational data items
-        self.events_to_operational_data(events)
+        self.events_to_operational_data(events)?;
+
+        self.persist_schedule();
+
+        Ok(())
+    }
+
+    /// Schedules anew the operational data persisted by this path before the relayer was
+    /// restarted, from their events, skipping the events which were handled in the meantime.
+    /// As with the clearing of packets, the proofs are queried at the height before the given
+    /// [`Height`].
+    fn restore_schedule(&mut self, above_height: Height) -> Result<(), LinkError> {
+        let stored = self
+            .src_chain()
+            .query_stored_operational_data(self.src_port_id(), self.src_channel_id()?)?;
+
+        if stored.is_empty() {
+            return Ok(());
+        }
+
+        let restore_height = above_height.decrement().map_err(|e| {
+            LinkError::Failed(format!(
+                "Cannot restore operational data @height {}, because this height cannot be decremented: {}",
+                above_height,
+                e.to_string()
+            ))
+        })?;
+
+        for od in stored {
+            info!(
+                "[{}] restoring op. data with {} event(s) for {} (height {}) at height {}",
+                self,
+                od.events.len(),
+                od.target,
+                od.proofs_height.increment(),
+                restore_height.increment(),
+            );
+
+            let mut events = od.events;
+            for event in events.iter_mut() {
+                event.set_height(restore_height);
+            }
+
+            self.events_to_operational_data(events)?;
+        }
+
+        // Persist the schedule even if all the restored events were handled
+        self.schedule_changed = true;
+
+        Ok(())
+    }
+
+    /// Persists the scheduled operational data in the state store of the source chain,
+    /// if they changed since they were last persisted.
+    fn persist_schedule(&mut self) {
+        if !self.schedule_changed {
+            return;
+        }
+
+        let data = self
+            .src_operational_data
+            .iter()
+            .chain(self.dst_operational_data.iter())
+            .map(|od| StoredOperationalData {
+                target: od.target,
+                proofs_height: od.proofs_height,
+                events: od.events(),
+            })
+            .collect();
+
+        let result = self.src_channel_id().and_then(|src_channel_id| {
+            self.src_chain()
+                .store_operational_data(self.src_port_id(), src_channel_id, data)
+                .map_err(LinkError::from)
+        });
+
+        match result {
+            Ok(()) => self.schedule_changed = false,
+            Err(e) => warn!("[{}] failed to persist the scheduled op. data: {}", self, e),
+        }
+    }
+
+    /// Returns the sequences of the packets whose events of the given type are already
+    /// scheduled, eg. after being restored, and thus need not be searched for again.
+    fn scheduled_sequences(&self, event_type: IbcEventType) -> HashSet<Sequence> {
+        self.src_operational_data
+            .iter()
+            .chain(self.dst_operational_data.iter())
+            .flat_map(|od| od.batch.iter())
+            .filter_map(|msg| match (&event_type, &msg.event) {
+                (IbcEventType::SendPacket, IbcEvent::SendPacket(e)) => Some(e.packet.sequence),
+                (IbcEventType::WriteAck, IbcEvent::WriteAcknowledgement(e)) => {
+                    Some(e.packet.sequence)
+                }
+                _ => None,
+            })
+            .collect()
     }
 
     /// Produces and schedules operational data for this relaying path based on the input events.
@@ -574,19 +834,17 @@ impl RelayPath {
             }
 
             // Collect timeout messages, to be sent to the source chain
+            // For ordered channels, only one of them is kept when scheduling, see
+            // `schedule_operational_data`.
             if let Some(msg) = src_msg {
-                // For Ordered channels a single timeout event should be sent as this closes the channel.
-                // Otherwise a multi message transaction will fail.
-                if self.unordered_channel() || src_od.batch.is_empty() {
-                    debug!(
-                        "[{}] {} <= {} from {}",
-                        self,
-                        self.src_chain().id(),
-                        msg.type_url,
-                        event
-                    );
-                    src_od.batch.push(TransitMessage { event, msg });
-                }
+                debug!(
+                    "[{}] {} <= {} from {}",
+                    self,
+                    self.src_chain().id(),
+                    msg.type_url,
+                    event
+                );
+                src_od.batch.push(TransitMessage { event, msg });
             }
         }
 
@@ -751,14 +1009,14 @@ impl RelayPath {
             return Ok(RelaySummary::empty());
         }
 
-        let target = match odata.target {
-            OperationalDataTarget::Source => self.src_chain(),
-            OperationalDataTarget::Destination => self.dst_chain(),
+        let (target, signer) = match odata.target {
+            OperationalDataTarget::Source => (self.src_chain(), self.src_signer()),
+            OperationalDataTarget::Destination => (self.dst_chain(), self.dst_signer()),
         };
 
         let msgs = odata.assemble_msgs(self)?;
 
-        let tx_events = target.send_msgs(msgs)?;
+        let tx_events = target.send_msgs_signed_by(msgs, signer)?;
         info!("[{}] result {}\n", self, PrettyEvents(&tx_events));
 
         let ev = tx_events
@@ -854,7 +1112,9 @@ impl RelayPath {
                 i + 1, MAX_RETRIES,
             );
 
-            let dst_tx_events = self.dst_chain().send_msgs(dst_update)?;
+            let dst_tx_events = self
+                .dst_chain()
+                .send_msgs_signed_by(dst_update, self.dst_signer())?;
             info!("[{}] result {}\n", self, PrettyEvents(&dst_tx_events));
 
             dst_err_ev = dst_tx_events
@@ -895,7 +1155,9 @@ impl RelayPath {
                 dst_chain_height,
             );
 
-            let src_tx_events = self.src_chain().send_msgs(src_update)?;
+            let src_tx_events = self
+                .src_chain()
+                .send_msgs_signed_by(src_update, self.src_signer())?;
             info!("[{}] result {}\n", self, PrettyEvents(&src_tx_events));
 
             src_err_ev = src_tx_events
@@ -949,11 +1211,13 @@ impl RelayPath {
             packet_commitment_sequences: commit_sequences.clone(),
         };
 
+        let scheduled = self.scheduled_sequences(IbcEventType::SendPacket);
         let sequences: Vec<Sequence> = self
             .dst_chain()
             .query_unreceived_packets(request)?
             .into_iter()
             .map(From::from)
+            .filter(|sequence| !scheduled.contains(sequence))
             .collect();
 
         if sequences.is_empty() {
@@ -1045,12 +1309,14 @@ impl RelayPath {
             packet_ack_sequences: acked_sequences.clone(),
         };
 
+        let scheduled = self.scheduled_sequences(IbcEventType::WriteAck);
         let sequences: Vec<Sequence> = self
             .dst_chain()
             .query_unreceived_acknowledgement(request)
             .map_err(|e| LinkError::QueryError(self.dst_chain().id(), e))?
             .into_iter()
             .map(From::from)
+            .filter(|sequence| !scheduled.contains(sequence))
             .collect();
 
         if sequences.is_empty() {
@@ -1165,7 +1431,7 @@ impl RelayPath {
             )
             .map_err(|e| LinkError::PacketProofsConstructor(self.src_chain().id(), e))?;
 
-        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
+        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer().clone());
 
         trace!(
             "[{}] built recv_packet msg {}, proofs at height {}",
@@ -1198,7 +1464,7 @@ impl RelayPath {
             packet,
             event.ack.clone(),
             proofs.clone(),
-            self.dst_signer()?,
+            self.dst_signer().clone(),
         );
 
         trace!(
@@ -1216,17 +1482,11 @@ impl RelayPath {
         packet: &Packet,
         height: Height,
     ) -> Result<Option<Any>, LinkError> {
-        let dst_channel_id = self.dst_channel_id()?;
-
         let (packet_type, next_sequence_received) = if self.ordered_channel() {
-            let next_seq = self
-                .dst_chain()
-                .query_next_sequence_receive(QueryNextSequenceReceiveRequest {
-                    port_id: self.dst_port_id().to_string(),
-                    channel_id: dst_channel_id.to_string(),
-                })
-                .map_err(|e| ChannelError::QueryError(self.dst_chain().id(), e))?;
-            (PacketMsgType::TimeoutOrdered, next_seq)
+            (
+                PacketMsgType::TimeoutOrdered,
+                self.dst_next_sequence_recv()?,
+            )
         } else {
             (PacketMsgType::TimeoutUnordered, packet.sequence)
         };
@@ -1246,7 +1506,7 @@ impl RelayPath {
             packet.clone(),
             next_sequence_received,
             proofs.clone(),
-            self.src_signer()?,
+            self.src_signer().clone(),
         );
 
         trace!(
@@ -1264,22 +1524,33 @@ impl RelayPath {
         packet: &Packet,
         height: Height,
     ) -> Result<Option<Any>, LinkError> {
+        // As for timeouts, ordered channels prove the next sequence to be received instead
+        // of the absence of a receipt for the packet.
+        let (packet_type, next_sequence_received) = if self.ordered_channel() {
+            (
+                PacketMsgType::TimeoutOnCloseOrdered,
+                self.dst_next_sequence_recv()?,
+            )
+        } else {
+            (PacketMsgType::TimeoutOnClose, packet.sequence)
+        };
+
         let (_, proofs) = self
             .dst_chain()
             .build_packet_proofs(
-                PacketMsgType::TimeoutOnClose,
+                packet_type,
                 &packet.destination_port,
                 &packet.destination_channel,
-                packet.sequence,
+                next_sequence_received,
                 height,
             )
             .map_err(|e| LinkError::PacketProofsConstructor(self.dst_chain().id(), e))?;
 
         let msg = MsgTimeoutOnClose::new(
             packet.clone(),
-            packet.sequence,
+            next_sequence_received,
             proofs.clone(),
-            self.src_signer()?,
+            self.src_signer().clone(),
         );
 
         trace!(
@@ -1326,7 +1597,7 @@ impl RelayPath {
     /// Checks if there are any operational data items ready, and if so performs the relaying
     /// of corresponding packets to the target chain.
     pub fn execute_schedule(&mut self) -> Result<RelaySummary, LinkError> {
-        let (src_ods, dst_ods) = self.try_fetch_scheduled_operational_data();
+        let (src_ods, dst_ods) = self.fetch_ready_operational_data()?;
 
         let mut summary = RelaySummary::empty();
 
@@ -1338,9 +1609,138 @@ impl RelayPath {
             summary.extend(self.relay_from_operational_data(od)?);
         }
 
+        // Once the channel end on destination is closed, eg. after a timeout on an ordered
+        // channel, the packets still pending can only be timed out on close.
+        let channel_closed = summary
+            .events
+            .iter()
+            .any(|e| matches!(e, IbcEvent::CloseConfirmChannel(_)));
+
+        if channel_closed {
+            info!(
+                "[{}] channel closed on destination, scheduling the timeout of pending packets",
+                self
+            );
+            self.build_recv_packet_and_timeout_msgs(None)?;
+        }
+
+        self.persist_schedule();
+
         Ok(summary)
     }
 
+    /// Whether there are operational data scheduled, targeting either chain.
+    pub fn has_scheduled_operational_data(&self) -> bool {
+        !self.src_operational_data.is_empty() || !self.dst_operational_data.is_empty()
+    }
+
+    /// Pulls out the operational data with elapsed delay period, like
+    /// `try_fetch_scheduled_operational_data`, and on ordered channels, keeps in the schedule
+    /// the packets which cannot be received yet, see `split_in_order`.
+    fn fetch_ready_operational_data(
+        &mut self,
+    ) -> Result<(Vec<OperationalData>, Vec<OperationalData>), LinkError> {
+        if self.unordered_channel() || self.dst_operational_data.is_empty() {
+            return Ok(self.try_fetch_scheduled_operational_data());
+        }
+
+        let next_sequence = self.dst_next_sequence_recv()?;
+
+        let (src_ods, dst_ods) = self.try_fetch_scheduled_operational_data();
+        let (ready_ods, held_ods) = self.split_in_order(dst_ods, next_sequence);
+
+        if !held_ods.is_empty() {
+            let awaited_sequence = ready_ods
+                .iter()
+                .flat_map(|od| od.batch.iter())
+                .filter(|msg| matches!(msg.event, IbcEvent::SendPacket(_)))
+                .filter_map(|msg| packet_sequence(&msg.event))
+                .max()
+                .map_or(next_sequence, |sequence| sequence.increment());
+
+            info!(
+                "[{}] holding {} packet(s) until sequence {} is received on destination",
+                self,
+                held_ods.iter().map(|od| od.batch.len()).sum::<usize>(),
+                awaited_sequence,
+            );
+
+            // The held op. data are the oldest ones, keep them first in the schedule
+            self.dst_operational_data.splice(0..0, held_ods);
+            self.schedule_changed = true;
+        }
+
+        Ok((src_ods, ready_ods))
+    }
+
+    /// On an ordered channel, the destination chain only receives packets in the order of
+    /// their sequences, starting with the given `next_sequence`. Splits the given op. data
+    /// targeting the destination chain into the ones to send now, with the packets
+    /// to receive in sequence (ordered across op. data), and the ones to hold, with the packets
+    /// following a gap in the sequences. The packets already received are dropped.
+    fn split_in_order(
+        &self,
+        ods: Vec<OperationalData>,
+        mut next_sequence: Sequence,
+    ) -> (Vec<OperationalData>, Vec<OperationalData>) {
+        let mut ready_ods = vec![];
+        let mut pending = ods;
+
+        // The packets of an op. data may fill the gap before the packets of an older one,
+        // so go over the op. data until no more packet can be received.
+        loop {
+            let mut progress = false;
+            let mut held_ods = vec![];
+
+            for od in pending {
+                // Both parts keep the proofs height and the scheduled time of the op. data
+                let mut ready = OperationalData {
+                    batch: vec![],
+                    ..od
+                };
+                let mut held = ready.clone();
+
+                let (mut packets, mut others): (Vec<_>, Vec<_>) = od
+                    .batch
+                    .into_iter()
+                    .partition(|msg| matches!(msg.event, IbcEvent::SendPacket(_)));
+
+                // Acknowledgments are also processed in sequence on ordered channels
+                others.sort_by_key(|msg| packet_sequence(&msg.event));
+                packets.sort_by_key(|msg| packet_sequence(&msg.event));
+
+                ready.batch = others;
+
+                for msg in packets {
+                    let sequence = packet_sequence(&msg.event).unwrap_or_default();
+
+                    if sequence < next_sequence {
+                        debug!("[{}] dropping already received {}", self, msg.event);
+                    } else if sequence == next_sequence {
+                        next_sequence = next_sequence.increment();
+                        progress = true;
+                        ready.batch.push(msg);
+                    } else {
+                        held.batch.push(msg);
+                    }
+                }
+
+                if !ready.batch.is_empty() {
+                    ready_ods.push(ready);
+                }
+                if !held.batch.is_empty() {
+                    held_ods.push(held);
+                }
+            }
+
+            pending = held_ods;
+
+            if !progress || pending.is_empty() {
+                return (ready_ods, pending);
+            }
+        }
+    }
+
     /// Refreshes the scheduled batches.
     /// Verifies if any sendPacket messages timed-out. If so, moves them from destination op. data
     /// to source operational data, and adjusts the events and messages accordingly.
@@ -1405,6 +1805,12 @@ impl RelayPath {
             odata.batch = retain_batch;
         }
 
+        let batch_len =
+            |ods: &[OperationalData]| ods.iter().map(|od| od.batch.len()).sum::<usize>();
+        if batch_len(all_dst_odata.as_slice()) != batch_len(self.dst_operational_data.as_slice()) {
+            self.schedule_changed = true;
+        }
+
         // Replace the original operational data with the updated one
         self.dst_operational_data = all_dst_odata;
         // Possibly some op. data became empty (if no events were kept).
@@ -1414,6 +1820,7 @@ impl RelayPath {
         // Handle timed-out events
         if timed_out.is_empty() {
             // Nothing timed out in the meantime
+            self.persist_schedule();
             return Ok(());
         }
 
@@ -1433,6 +1840,8 @@ impl RelayPath {
             self.schedule_operational_data(new_od)?;
         }
 
+        self.persist_schedule();
+
         Ok(())
     }
 
@@ -1440,6 +1849,10 @@ impl RelayPath {
     /// If the relaying path has non-zero packet delays, this method also updates the client on the
     /// target chain with the appropriate headers.
     fn schedule_operational_data(&mut self, mut od: OperationalData) -> Result<(), LinkError> {
+        if od.target == OperationalDataTarget::Source && self.ordered_channel() {
+            self.retain_single_timeout(&mut od);
+        }
+
         if od.batch.is_empty() {
             info!(
                 "[{}] ignoring operational data for {} because it has no messages",
@@ -1472,9 +1885,46 @@ impl RelayPath {
             OperationalDataTarget::Destination => self.dst_operational_data.push(od),
         };
 
+        self.schedule_changed = true;
+
         Ok(())
     }
 
+    /// On an ordered channel, a timeout closes the channel, and any later timeout fails, so that
+    /// only one timeout is kept in the schedule: the one of the lowest sequence, if none is
+    /// already scheduled. Timeouts on close are kept, as they can be submitted once the
+    /// channel is closed.
+    fn retain_single_timeout(&self, od: &mut OperationalData) {
+        let is_timeout = |msg: &TransitMessage| msg.msg.type_url == timeout::TYPE_URL;
+
+        let timeout_scheduled = self
+            .src_operational_data
+            .iter()
+            .flat_map(|od| od.batch.iter())
+            .any(is_timeout);
+
+        let first_timeout = od
+            .batch
+            .iter()
+            .filter(|msg| is_timeout(msg))
+            .min_by_key(|msg| packet_sequence(&msg.event))
+            .and_then(|msg| packet_sequence(&msg.event));
+
+        od.batch.retain(|msg| {
+            let retain = !is_timeout(msg)
+                || (!timeout_scheduled && packet_sequence(&msg.event) == first_timeout);
+
+            if !retain {
+                debug!(
+                    "[{}] dropping timeout for {} on ordered channel, the channel will be closed by another timeout",
+                    self, msg.event
+                );
+            }
+
+            retain
+        });
+    }
+
     /// Pulls out the operational elements with elapsed delay period and that can
     /// now be processed. Does not block: if no OD fulfilled the delay period (or none is
     /// scheduled), returns immediately with `vec![]`.
@@ -1502,6 +1952,10 @@ impl RelayPath {
 
         self.dst_operational_data = self.dst_operational_data[dst_ods.len()..].to_owned();
 
+        if !src_ods.is_empty() || !dst_ods.is_empty() {
+            self.schedule_changed = true;
+        }
+
         (src_ods, dst_ods)
     }
 
@@ -1547,6 +2001,8 @@ impl RelayPath {
                 OperationalDataTarget::Destination => self.dst_operational_data.remove(0),
             };
 
+            self.schedule_changed = true;
+
             Some(op)
         } else {
             None
@@ -1593,6 +2049,16 @@ impl fmt::Display for RelayPath {
     }
 }
 
+/// Returns the sequence of the packet an event is about, for the events of the packets
+/// relayed by a path.
+fn packet_sequence(event: &IbcEvent) -> Option<Sequence> {
+    match event {
+        IbcEvent::SendPacket(e) => Some(e.packet.sequence),
+        IbcEvent::WriteAcknowledgement(e) => Some(e.packet.sequence),
+        _ => None,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct LinkParameters {
     pub src_port_id: PortId,
@@ -1605,12 +2071,12 @@ pub struct Link {
 }
 
 impl Link {
-    pub fn new(channel: Channel) -> Self {
+    pub fn new(channel: Channel) -> Result<Self, LinkError> {
         let flipped = channel.flipped();
-        Self {
-            a_to_b: RelayPath::new(channel),
-            b_to_a: RelayPath::new(flipped),
-        }
+        Ok(Self {
+            a_to_b: RelayPath::new(channel)?,
+            b_to_a: RelayPath::new(flipped)?,
+        })
     }
 
     pub fn is_closed(&self) -> Result<bool, LinkError> {
@@ -1723,7 +2189,7 @@ impl Link {
         }
 
         let channel = Channel {
-            ordering: Default::default(),
+            ordering: *a_channel.ordering(),
             a_side: ChannelSide::new(
                 a_chain,
                 a_connection.client_id().clone(),
@@ -1742,7 +2208,7 @@ impl Link {
             version: None,
         };
 
-        Ok(Link::new(channel))
+        Link::new(channel)
     }
 
     pub fn build_and_send_recv_packet_messages(&mut self) -> Result<Vec<IbcEvent>, LinkError> {
@@ -1794,3 +2260,273 @@ impl RelaySummary {
         self.events.extend(other.events)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use prost_types::Any;
+    use test_env_log::test;
+    use tokio::runtime::Runtime as TokioRuntime;
+
+    use ibc::events::IbcEvent;
+    use ibc::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc::ics03_connection::version::get_compatible_versions;
+    use ibc::ics04_channel::channel::{
+        ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+    };
+    use ibc::ics04_channel::events::SendPacket;
+    use ibc::ics04_channel::msgs::{recv_packet, timeout, timeout_on_close};
+    use ibc::ics04_channel::packet::{Packet, Sequence};
+    use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+    use ibc::mock::context::MockContext;
+    use ibc::mock::host::HostType;
+    use ibc::timestamp::ZERO_DURATION;
+    use ibc::Height;
+
+    use crate::chain::mock::test_utils::get_basic_chain_config;
+    use crate::chain::mock::MockChain;
+    use crate::chain::runtime::ChainRuntime;
+
+    use super::{Link, LinkParameters, OperationalData, OperationalDataTarget, TransitMessage};
+
+    /// Returns the context of a mock chain with an open channel `channel-0` on port `transfer`,
+    /// whose next sequence to receive is `next_sequence_recv`.
+    fn context_with_channel(
+        chain_id: &ChainId,
+        ordering: Order,
+        next_sequence_recv: Sequence,
+    ) -> MockContext {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::default();
+        let connection_id = ConnectionId::default();
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(connection_id.clone()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        );
+
+        let channel_end = ChannelEnd::new(
+            ChannelState::Open,
+            ordering,
+            ChannelCounterparty::new(port_id.clone(), Some(channel_id.clone())),
+            vec![connection_id.clone()],
+            "ics20-1".to_string(),
+        );
+
+        MockContext::new(
+            chain_id.clone(),
+            HostType::SyntheticTendermint,
+            50,
+            Height::new(chain_id.version(), 20),
+        )
+        .with_connection(connection_id, connection_end)
+        .with_channel(port_id.clone(), channel_id.clone(), channel_end)
+        .with_recv_sequence(port_id, channel_id, next_sequence_recv)
+    }
+
+    /// Returns a link over a channel between two mock chains, whose destination chain
+    /// expects to receive the packet of sequence `next_sequence_recv`.
+    fn link(ordering: Order, next_sequence_recv: u64) -> Link {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let spawn = |id: &str, next_sequence_recv: Sequence| {
+            let config = get_basic_chain_config(id);
+            let context = context_with_channel(&config.id, ordering, next_sequence_recv);
+            ChainRuntime::<MockChain>::spawn_chain(
+                MockChain::with_context(config, context),
+                rt.clone(),
+            )
+            .unwrap()
+        };
+
+        let a_chain = spawn("chain_a", Sequence::from(1));
+        let b_chain = spawn("chain_b", Sequence::from(next_sequence_recv));
+
+        Link::new_from_opts(
+            a_chain,
+            b_chain,
+            LinkParameters {
+                src_port_id: PortId::from_str("transfer").unwrap(),
+                src_channel_id: ChannelId::default(),
+            },
+        )
+        .unwrap()
+    }
+
+    /// Returns op. data with messages of the given type for the packets of the given sequences.
+    fn operational_data(target: OperationalDataTarget, msgs: &[(&str, u64)]) -> OperationalData {
+        let mut od = OperationalData::new(Height::new(0, 10), target);
+
+        for (type_url, sequence) in msgs {
+            let packet = Packet {
+                sequence: Sequence::from(*sequence),
+                ..Default::default()
+            };
+
+            od.batch.push(TransitMessage {
+                event: IbcEvent::SendPacket(SendPacket {
+                    height: Height::new(0, 10),
+                    packet,
+                }),
+                msg: Any {
+                    type_url: type_url.to_string(),
+                    value: vec![],
+                },
+            });
+        }
+
+        od
+    }
+
+    fn recv_packets(sequences: &[u64]) -> OperationalData {
+        let msgs = sequences
+            .iter()
+            .map(|sequence| (recv_packet::TYPE_URL, *sequence))
+            .collect::<Vec<_>>();
+
+        operational_data(OperationalDataTarget::Destination, &msgs)
+    }
+
+    /// Returns the type and the packet sequence of each message in the given op. data.
+    fn batches(ods: &[OperationalData]) -> Vec<Vec<(String, u64)>> {
+        ods.iter()
+            .map(|od| {
+                od.batch
+                    .iter()
+                    .map(|msg| {
+                        let sequence = super::packet_sequence(&msg.event).unwrap();
+                        (msg.msg.type_url.clone(), u64::from(sequence))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn sequences(ods: &[OperationalData]) -> Vec<Vec<u64>> {
+        batches(ods)
+            .into_iter()
+            .map(|batch| batch.into_iter().map(|(_, sequence)| sequence).collect())
+            .collect()
+    }
+
+    #[test]
+    fn link_uses_channel_ordering() {
+        let ordered = link(Order::Ordered, 1);
+        assert_eq!(ordered.a_to_b.channel().ordering, Order::Ordered);
+        assert_eq!(ordered.b_to_a.channel().ordering, Order::Ordered);
+
+        let unordered = link(Order::Unordered, 1);
+        assert_eq!(unordered.a_to_b.channel().ordering, Order::Unordered);
+    }
+
+    #[test]
+    fn ordered_channel_relays_packets_in_sequence() {
+        let mut link = link(Order::Ordered, 2);
+        let path = &mut link.a_to_b;
+
+        // The packet of sequence 1 was already received
+        path.schedule_operational_data(recv_packets(&[4, 2, 1, 3]))
+            .unwrap();
+
+        let (src_ods, dst_ods) = path.fetch_ready_operational_data().unwrap();
+
+        assert!(src_ods.is_empty());
+        assert_eq!(sequences(&dst_ods), vec![vec![2, 3, 4]]);
+        assert!(!path.has_scheduled_operational_data());
+    }
+
+    #[test]
+    fn ordered_channel_holds_packets_after_gap() {
+        let mut link = link(Order::Ordered, 2);
+        let path = &mut link.a_to_b;
+
+        path.schedule_operational_data(recv_packets(&[5, 2]))
+            .unwrap();
+        path.schedule_operational_data(recv_packets(&[3])).unwrap();
+
+        let (_, dst_ods) = path.fetch_ready_operational_data().unwrap();
+
+        // The packet of sequence 5 waits for the one of sequence 4
+        assert_eq!(sequences(&dst_ods), vec![vec![2], vec![3]]);
+        assert_eq!(sequences(&path.dst_operational_data), vec![vec![5]]);
+
+        // Until then, it stays in the schedule
+        let (_, dst_ods) = path.fetch_ready_operational_data().unwrap();
+
+        assert!(dst_ods.is_empty());
+        assert_eq!(sequences(&path.dst_operational_data), vec![vec![5]]);
+    }
+
+    #[test]
+    fn unordered_channel_relays_packets_as_scheduled() {
+        let mut link = link(Order::Unordered, 2);
+        let path = &mut link.a_to_b;
+
+        path.schedule_operational_data(recv_packets(&[5, 2, 1]))
+            .unwrap();
+
+        let (_, dst_ods) = path.fetch_ready_operational_data().unwrap();
+
+        assert_eq!(sequences(&dst_ods), vec![vec![5, 2, 1]]);
+        assert!(!path.has_scheduled_operational_data());
+    }
+
+    #[test]
+    fn ordered_channel_schedules_single_timeout() {
+        let mut link = link(Order::Ordered, 1);
+        let path = &mut link.a_to_b;
+
+        path.schedule_operational_data(operational_data(
+            OperationalDataTarget::Source,
+            &[
+                (timeout::TYPE_URL, 3),
+                (timeout::TYPE_URL, 2),
+                (timeout_on_close::TYPE_URL, 4),
+            ],
+        ))
+        .unwrap();
+
+        // The timeout of the lowest sequence closes the channel, the others would fail
+        assert_eq!(
+            batches(&path.src_operational_data),
+            vec![vec![
+                (timeout::TYPE_URL.to_string(), 2),
+                (timeout_on_close::TYPE_URL.to_string(), 4),
+            ]]
+        );
+
+        // No other timeout is scheduled while one is
+        path.schedule_operational_data(operational_data(
+            OperationalDataTarget::Source,
+            &[(timeout::TYPE_URL, 1)],
+        ))
+        .unwrap();
+
+        assert_eq!(path.src_operational_data.len(), 1);
+    }
+
+    #[test]
+    fn unordered_channel_schedules_all_timeouts() {
+        let mut link = link(Order::Unordered, 1);
+        let path = &mut link.a_to_b;
+
+        path.schedule_operational_data(operational_data(
+            OperationalDataTarget::Source,
+            &[(timeout::TYPE_URL, 3), (timeout::TYPE_URL, 2)],
+        ))
+        .unwrap();
+
+        assert_eq!(sequences(&path.src_operational_data), vec![vec![3, 2]]);
+    }
+}
@@ -43,7 +43,8 @@ pub mod profiling {
 
 /// Measure the time until the current scope ends.
 ///
-/// Only enabled when the "profiling" feature is enabled.
+/// The time is logged when the "profiling" feature is enabled, and recorded
+/// as the latency of the operation when the "telemetry" feature is enabled.
 ///
 /// ## Example
 ///
@@ -65,5 +66,7 @@ macro_rules! time {
     ($($arg:tt)*) => {
         #[cfg(feature = "profiling")]
         let _timer = $crate::macros::profiling::Timer::new(format!($($arg)*));
+        #[cfg(feature = "telemetry")]
+        let _latency_timer = $crate::telemetry::LatencyTimer::new(format!($($arg)*));
     };
 }
@@ -12,6 +12,7 @@ use crate::{
     chain::{handle::ChainHandle, runtime::ChainRuntime, CosmosSdkChain},
     config::Config,
     supervisor::RwArc,
+    telemetry::Telemetry,
 };
 
 /// Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`.
@@ -21,15 +22,18 @@ pub struct Registry {
     config: RwArc<Config>,
     handles: HashMap<ChainId, Box<dyn ChainHandle>>,
     rt: Arc<TokioRuntime>,
+    telemetry: Telemetry,
 }
 
 impl Registry {
-    /// Construct a new [`Registry`] using the provided [`Config`]
-    pub fn new(config: RwArc<Config>) -> Self {
+    /// Construct a new [`Registry`] using the provided [`Config`], whose chain runtimes
+    /// report their metrics to the given telemetry handle.
+    pub fn new(config: RwArc<Config>, telemetry: Telemetry) -> Self {
         Self {
             config,
             handles: HashMap::new(),
             rt: Arc::new(TokioRuntime::new().unwrap()),
+            telemetry,
         }
     }
 
@@ -64,7 +68,12 @@ impl Registry {
     /// Returns whether or not the runtime was actually spawned.
     pub fn spawn(&mut self, chain_id: &ChainId) -> Result<bool, BoxError> {
         if !self.handles.contains_key(chain_id) {
-            let handle = spawn_chain_runtime(&self.config, chain_id, self.rt.clone())?;
+            let handle = spawn_chain_runtime(
+                &self.config,
+                chain_id,
+                self.rt.clone(),
+                self.telemetry.clone(),
+            )?;
             self.handles.insert(chain_id.clone(), handle);
             trace!("spawned chain runtime for chain {}", chain_id);
             Ok(true)
@@ -83,12 +92,14 @@ impl Registry {
     }
 }
 
-/// Spawns a chain runtime from the configuration and given a chain identifier.
+/// Spawns a chain runtime from the configuration and given a chain identifier,
+/// which reports its metrics to the given telemetry handle.
 /// Returns the corresponding handle if successful.
 pub fn spawn_chain_runtime(
     config: &RwArc<Config>,
     chain_id: &ChainId,
     rt: Arc<TokioRuntime>,
+    telemetry: Telemetry,
 ) -> Result<Box<dyn ChainHandle>, BoxError> {
     let chain_config = config
         .read()
@@ -97,7 +108,7 @@ pub fn spawn_chain_runtime(
         .cloned()
         .ok_or_else(|| format!("missing chain for id ({}) in configuration file", chain_id))?;
 
-    let handle = ChainRuntime::<CosmosSdkChain>::spawn(chain_config, rt)?;
+    let handle = ChainRuntime::<CosmosSdkChain>::spawn_with_telemetry(chain_config, rt, telemetry)?;
 
     Ok(handle)
 }
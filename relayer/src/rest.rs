@@ -0,0 +1,258 @@
+//! A JSON REST API over the state of the relayer, served by the telemetry server.
+//!
+//! Every response is a JSON object `{"status": "success", "result": ...}`, or
+//! `{"status": "error", "result": "<error message>"}` with an error status code.
+//!
+//! - `GET /version`: the version of the relayer
+//! - `GET /chains`: the identifiers of the chains in the configuration
+//! - `GET /workers`: the workers spawned by the supervisor, per type of object
+//! - `GET /chain/{id}/status`: the latest height of the chain, the height of the last
+//!   events of the chain processed by the relayer, and the address, account sequence and
+//!   balance of the relayer on the chain
+//! - `POST /chain/{id}/clear_packets`: clear the pending packets sent by the chain
+//! - `POST /reload`: reload the configuration file
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crossbeam_channel::Sender;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use ibc::ics24_host::identifier::ChainId;
+use ibc::Height;
+
+use crate::chain::AccountStatus;
+use crate::config::reload::ConfigReload;
+use crate::config::Config;
+use crate::supervisor::cmd::SupervisorCmd;
+use crate::supervisor::RwArc;
+
+/// The maximum time to wait for the supervisor to reply to a command
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The status of a chain, as returned by `GET /chain/{id}/status`
+#[derive(Clone, Debug, Serialize)]
+pub struct ChainStatus {
+    pub chain_id: ChainId,
+    pub height: Height,
+    pub processed_height: Option<Height>,
+    pub account: AccountStatus,
+}
+
+/// Serves the REST API, by reading the configuration of the relayer, and
+/// sending [`SupervisorCmd`]s to the [`crate::supervisor::Supervisor`].
+#[derive(Clone, Debug)]
+pub struct RestApi {
+    config: RwArc<Config>,
+    tx_cmd: Sender<SupervisorCmd>,
+    reload: Option<ConfigReload>,
+}
+
+impl RestApi {
+    /// Create the REST API of the supervisor listening on `tx_cmd`.
+    /// The configuration can only be reloaded through the API if a [`ConfigReload`] is given.
+    pub fn new(
+        config: RwArc<Config>,
+        tx_cmd: Sender<SupervisorCmd>,
+        reload: Option<ConfigReload>,
+    ) -> Self {
+        Self {
+            config,
+            tx_cmd,
+            reload,
+        }
+    }
+
+    /// Handle the request with the given method and path.
+    ///
+    /// Returns the status code and the JSON body of the response, or `None`
+    /// if the request does not match any endpoint.
+    pub fn respond(&self, method: &str, path: &str) -> Option<(u16, Value)> {
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        let response = match (method, segments.as_slice()) {
+            ("GET", ["version"]) => success(json!({
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+            })),
+            ("GET", ["chains"]) => self.chains(),
+            ("GET", ["workers"]) => self.workers(),
+            ("GET", ["chain", chain_id, "status"]) => match parse_chain_id(chain_id) {
+                Ok(chain_id) => self.chain_status(chain_id),
+                Err(response) => response,
+            },
+            ("POST", ["chain", chain_id, "clear_packets"]) => match parse_chain_id(chain_id) {
+                Ok(chain_id) => self.clear_packets(chain_id),
+                Err(response) => response,
+            },
+            ("POST", ["reload"]) => self.reload(),
+            _ => return None,
+        };
+
+        Some(response)
+    }
+
+    fn chains(&self) -> (u16, Value) {
+        let chains = self
+            .config
+            .read()
+            .expect("poisoned lock")
+            .chains
+            .iter()
+            .map(|chain| chain.id.clone())
+            .collect::<Vec<_>>();
+
+        success(chains)
+    }
+
+    fn workers(&self) -> (u16, Value) {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        if let Err(e) = self.tx_cmd.send(SupervisorCmd::DumpState(tx)) {
+            return error(503, e);
+        }
+
+        match rx.recv_timeout(REPLY_TIMEOUT) {
+            Ok(state) => success(state),
+            Err(e) => error(503, e),
+        }
+    }
+
+    fn chain_status(&self, chain_id: ChainId) -> (u16, Value) {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        if let Err(e) = self
+            .tx_cmd
+            .send(SupervisorCmd::GetChain(chain_id.clone(), tx))
+        {
+            return error(503, e);
+        }
+
+        let chain = match rx.recv_timeout(REPLY_TIMEOUT) {
+            Ok(Some(chain)) => chain,
+            Ok(None) => return error(404, format!("chain {} is not running", chain_id)),
+            Err(e) => return error(503, e),
+        };
+
+        let status = chain.query_latest_height().and_then(|height| {
+            let processed_height = chain.query_processed_height()?;
+            let account = chain.query_account_status()?;
+            Ok(ChainStatus {
+                chain_id,
+                height,
+                processed_height,
+                account,
+            })
+        });
+
+        match status {
+            Ok(status) => success(status),
+            Err(e) => error(500, e),
+        }
+    }
+
+    fn clear_packets(&self, chain_id: ChainId) -> (u16, Value) {
+        if !self
+            .config
+            .read()
+            .expect("poisoned lock")
+            .has_chain(&chain_id)
+        {
+            return error(404, format!("chain {} is not configured", chain_id));
+        }
+
+        match self.tx_cmd.send(SupervisorCmd::ClearPackets(chain_id)) {
+            Ok(()) => success(Value::Null),
+            Err(e) => error(503, e),
+        }
+    }
+
+    fn reload(&self) -> (u16, Value) {
+        let reload = match &self.reload {
+            Some(reload) => reload,
+            None => return error(503, "the path of the configuration file is unknown"),
+        };
+
+        match reload.reload() {
+            Ok(changed) => success(json!({ "changed": changed })),
+            Err(e) => error(500, e),
+        }
+    }
+}
+
+#[cfg(feature = "telemetry")]
+impl ibc_telemetry::Api for RestApi {
+    fn handle(&self, method: &str, path: &str) -> Option<(u16, String)> {
+        self.respond(method, path)
+            .map(|(status, body)| (status, body.to_string()))
+    }
+}
+
+fn parse_chain_id(chain_id: &str) -> Result<ChainId, (u16, Value)> {
+    ChainId::from_str(chain_id).map_err(|e| error(400, e))
+}
+
+fn success(result: impl Serialize) -> (u16, Value) {
+    match serde_json::to_value(result) {
+        Ok(result) => (200, json!({ "status": "success", "result": result })),
+        Err(e) => error(500, e),
+    }
+}
+
+fn error(status: u16, e: impl Display) -> (u16, Value) {
+    (
+        status,
+        json!({ "status": "error", "result": e.to_string() }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, RwLock};
+    use std::thread;
+
+    use serde_json::json;
+    use test_env_log::test;
+
+    use crate::config::Config;
+    use crate::supervisor::cmd::SupervisorCmd;
+    use crate::supervisor::dump_state::SupervisorState;
+
+    use super::RestApi;
+
+    #[test]
+    fn routes() {
+        let config = Arc::new(RwLock::new(Config::default()));
+        let (tx_cmd, rx_cmd) = crossbeam_channel::unbounded();
+        let api = RestApi::new(config, tx_cmd, None);
+
+        // Stand in for the supervisor
+        let supervisor = thread::spawn(move || {
+            if let Ok(SupervisorCmd::DumpState(reply_to)) = rx_cmd.recv() {
+                reply_to.send(SupervisorState::default()).unwrap();
+            }
+        });
+
+        let (status, body) = api.respond("GET", "/workers").unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body["result"]["chains"], json!([]));
+        supervisor.join().unwrap();
+
+        let (status, body) = api.respond("GET", "/chains").unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body, json!({ "status": "success", "result": [] }));
+
+        let (status, _) = api.respond("POST", "/chain/ibc-0/clear_packets").unwrap();
+        assert_eq!(status, 404);
+
+        let (status, body) = api.respond("POST", "/reload").unwrap();
+        assert_eq!(status, 503);
+        assert_eq!(body["status"], "error");
+
+        assert!(api.respond("GET", "/version").is_some());
+        assert!(api.respond("GET", "/reload").is_none());
+        assert!(api.respond("GET", "/unknown").is_none());
+    }
+}
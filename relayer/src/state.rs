@@ -0,0 +1,225 @@
+//! Persistent storage for the state of the relayer on a chain, from which the relayer
+//! resumes its work after a restart.
+
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc::events::IbcEvent;
+use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc::Height;
+
+use crate::error::{self, Error};
+use crate::link::OperationalDataTarget;
+use crate::util::sled::{self as sled_util, KeyValueDb, SingleDb};
+
+pub const STATE_STORE_DEFAULT_FOLDER: &str = ".hermes/state/";
+
+/// An operational data scheduled by a relay path, persisted as the events from which
+/// its messages are generated, as the proofs of the messages are only valid until
+/// the client on the target chain is updated.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoredOperationalData {
+    pub target: OperationalDataTarget,
+    pub proofs_height: Height,
+    pub events: Vec<IbcEvent>,
+}
+
+/// A store of the state of the relayer on a chain, backed by a `sled` database:
+///
+/// - the height of the last batch of events of the chain processed by the supervisor, as well
+///   as that height when the store was opened, from which the relay paths resume,
+/// - the operational data scheduled by the relay paths whose source is the chain, and
+///   not relayed yet, keyed by the port and channel of the path on the chain,
+/// - the hashes of the transactions submitted to the chain, and not committed yet.
+#[derive(Clone, Debug)]
+pub struct StateStore {
+    db: sled::Db,
+    processed_height: SingleDb<Height>,
+    resume_height: Option<Height>,
+    operational_data: KeyValueDb<(PortId, ChannelId), Vec<StoredOperationalData>>,
+    pending_txs: KeyValueDb<String, String>,
+}
+
+impl StateStore {
+    /// Open the state store of the given chain, located under the Hermes home folder.
+    pub fn open(chain_id: &ChainId) -> Result<Self, Error> {
+        let home = dirs_next::home_dir()
+            .ok_or_else(|| error::Kind::Store.context("unable to retrieve the home directory"))?;
+
+        let path = home
+            .join(STATE_STORE_DEFAULT_FOLDER)
+            .join(chain_id.to_string());
+
+        Self::open_at(path, chain_id)
+    }
+
+    /// Open the state store of the given chain in a database located at `path`.
+    pub fn open_at(path: impl Into<PathBuf>, chain_id: &ChainId) -> Result<Self, Error> {
+        let db = sled::open(path.into()).map_err(|e| error::Kind::Store.context(e))?;
+        Self::new(db, chain_id)
+    }
+
+    /// Create a state store for the given chain which is deleted when dropped.
+    pub fn temporary(chain_id: &ChainId) -> Result<Self, Error> {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(|e| error::Kind::Store.context(e))?;
+
+        Self::new(db, chain_id)
+    }
+
+    fn new(db: sled::Db, chain_id: &ChainId) -> Result<Self, Error> {
+        let processed_height: SingleDb<Height> =
+            sled_util::single(format!("state/{}/processed_height", chain_id));
+        let resume_height = processed_height.get(&db)?;
+
+        Ok(Self {
+            db,
+            processed_height,
+            resume_height,
+            operational_data: sled_util::key_value(format!("state/{}/operational_data/", chain_id)),
+            pending_txs: sled_util::key_value(format!("state/{}/pending_txs/", chain_id)),
+        })
+    }
+
+    /// Get the height of the last batch of events processed by the supervisor, if any.
+    pub fn processed_height(&self) -> Result<Option<Height>, Error> {
+        self.processed_height.get(&self.db)
+    }
+
+    /// Get the height of the last batch of events processed by the supervisor before the store
+    /// was opened, i.e., before the relayer was restarted, if any. The events up to this height
+    /// were relayed, or persisted along with the operational data, hence the relay paths resume
+    /// from it.
+    pub fn resume_height(&self) -> Option<Height> {
+        self.resume_height
+    }
+
+    /// Record the height of the last batch of events processed by the supervisor.
+    pub fn set_processed_height(&self, height: Height) -> Result<(), Error> {
+        self.processed_height.set(&self.db, &height)
+    }
+
+    /// Get the operational data scheduled by the relay path over the given port and channel.
+    pub fn operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<StoredOperationalData>, Error> {
+        let key = (port_id.clone(), channel_id.clone());
+        Ok(self
+            .operational_data
+            .fetch(&self.db, &key)?
+            .unwrap_or_default())
+    }
+
+    /// Replace the operational data scheduled by the relay path over the given port and channel.
+    pub fn set_operational_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        data: &[StoredOperationalData],
+    ) -> Result<(), Error> {
+        let key = (port_id.clone(), channel_id.clone());
+
+        if data.is_empty() {
+            self.operational_data.remove(&self.db, &key)
+        } else {
+            self.operational_data.insert(&self.db, &key, &data.to_vec())
+        }
+    }
+
+    /// Get the hashes of the transactions submitted to the chain and not committed yet.
+    pub fn pending_txs(&self) -> Vec<String> {
+        self.pending_txs.iter(&self.db).collect()
+    }
+
+    /// Record the hash of a transaction submitted to the chain.
+    pub fn add_pending_tx(&self, hash: &str) -> Result<(), Error> {
+        self.pending_txs
+            .insert(&self.db, &hash.to_string(), &hash.to_string())
+    }
+
+    /// Forget the hash of a transaction once it is committed, or given up on.
+    pub fn remove_pending_tx(&self, hash: &str) -> Result<(), Error> {
+        self.pending_txs.remove(&self.db, &hash.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_env_log::test;
+
+    use ibc::events::IbcEvent;
+    use ibc::ics04_channel::events::SendPacket;
+    use ibc::ics04_channel::packet::Packet;
+    use ibc::ics24_host::identifier::{ChainId, ChannelId, PortId};
+    use ibc::Height;
+
+    use crate::link::OperationalDataTarget;
+
+    use super::{StateStore, StoredOperationalData};
+
+    #[test]
+    fn persist_relayer_state() {
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let store = StateStore::temporary(&chain_id).unwrap();
+
+        assert_eq!(store.processed_height().unwrap(), None);
+        store.set_processed_height(Height::new(0, 10)).unwrap();
+        store.set_processed_height(Height::new(0, 12)).unwrap();
+        assert_eq!(store.processed_height().unwrap(), Some(Height::new(0, 12)));
+
+        // The relay paths resume from the height processed when the store was opened
+        assert_eq!(store.resume_height(), None);
+        let reopened = StateStore::new(store.db.clone(), &chain_id).unwrap();
+        assert_eq!(reopened.resume_height(), Some(Height::new(0, 12)));
+
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let other_channel_id = ChannelId::new(1);
+
+        let data = vec![StoredOperationalData {
+            target: OperationalDataTarget::Destination,
+            proofs_height: Height::new(0, 11),
+            events: vec![IbcEvent::SendPacket(SendPacket {
+                height: Height::new(0, 11),
+                packet: Packet {
+                    data: vec![1, 2, 3],
+                    ..Default::default()
+                },
+            })],
+        }];
+
+        store
+            .set_operational_data(&port_id, &channel_id, &data)
+            .unwrap();
+
+        let stored = store.operational_data(&port_id, &channel_id).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].target, OperationalDataTarget::Destination);
+        match &stored[0].events[..] {
+            [IbcEvent::SendPacket(send_packet)] => assert_eq!(send_packet.packet.data, [1, 2, 3]),
+            events => panic!("unexpected events {:?}", events),
+        }
+        assert!(store
+            .operational_data(&port_id, &other_channel_id)
+            .unwrap()
+            .is_empty());
+
+        store
+            .set_operational_data(&port_id, &channel_id, &[])
+            .unwrap();
+        assert!(store
+            .operational_data(&port_id, &channel_id)
+            .unwrap()
+            .is_empty());
+
+        store.add_pending_tx("AB12").unwrap();
+        store.add_pending_tx("CD34").unwrap();
+        store.remove_pending_tx("AB12").unwrap();
+        assert_eq!(store.pending_txs(), vec!["CD34".to_string()]);
+    }
+}
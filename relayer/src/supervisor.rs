@@ -10,11 +10,7 @@ use itertools::Itertools;
 
 use tracing::{debug, error, info};
 
-use ibc::{
-    events::IbcEvent,
-    ics24_host::identifier::{ChainId, ChannelId},
-    Height,
-};
+use ibc::{events::IbcEvent, ics24_host::identifier::ChainId, Height};
 
 use crate::{
     chain::handle::ChainHandle,
@@ -30,7 +26,6 @@ use crate::{
 
 mod error;
 pub use error::Error;
-use ibc::ics24_host::identifier::PortId;
 
 pub mod dump_state;
 use dump_state::SupervisorState;
@@ -67,7 +62,7 @@ pub struct Supervisor {
 impl Supervisor {
     /// Create a [`Supervisor`] which will listen for events on all the chains in the [`Config`].
     pub fn new(config: RwArc<Config>, telemetry: Telemetry) -> (Self, Sender<SupervisorCmd>) {
-        let registry = Registry::new(config.clone());
+        let registry = Registry::new(config.clone(), telemetry.clone());
         let (worker_msg_tx, worker_msg_rx) = crossbeam_channel::unbounded();
         let workers = WorkerMap::new(worker_msg_tx, telemetry.clone());
 
@@ -85,43 +80,18 @@ impl Supervisor {
         (supervisor, cmd_tx)
     }
 
-    fn relay_on_channel(
-        &self,
-        chain_id: &ChainId,
-        port_id: &PortId,
-        channel_id: &ChannelId,
-    ) -> bool {
-        !self.config.read().expect("poisoned lock").global.filter
-            || self
-                .config
-                .read()
-                .expect("poisoned lock")
-                .find_chain(chain_id)
-                .map_or_else(
-                    || false,
-                    |chain_config| {
-                        chain_config
-                            .filters
-                            .channels
-                            .contains(&(port_id.clone(), channel_id.clone()))
-                    },
-                )
-    }
-
     fn relay_on_object(&self, chain_id: &ChainId, object: &Object) -> bool {
-        if !self.config.read().expect("poisoned lock").global.filter {
-            return true;
-        }
+        let config = self.config.read().expect("poisoned lock");
 
         match object {
-            Object::Client(_) => true,
+            Object::Client(c) => config.relay_on_client(chain_id, &c.dst_client_id),
+            Object::Connection(c) => config.relay_on_connection(chain_id, &c.src_connection_id),
             Object::Channel(c) => {
-                self.relay_on_channel(chain_id, c.src_port_id(), c.src_channel_id())
+                config.relay_on_channel(chain_id, c.src_port_id(), c.src_channel_id())
             }
             Object::Packet(u) => {
-                self.relay_on_channel(chain_id, u.src_port_id(), &u.src_channel_id())
+                config.relay_on_channel(chain_id, u.src_port_id(), u.src_channel_id())
             }
-            Object::Connection(_) => true,
         }
     }
 
@@ -352,7 +322,37 @@ impl Supervisor {
         match cmd {
             SupervisorCmd::UpdateConfig(update) => self.update_config(update),
             SupervisorCmd::DumpState(reply_to) => self.dump_state(reply_to),
+            SupervisorCmd::GetChain(chain_id, reply_to) => self.get_chain(&chain_id, reply_to),
+            SupervisorCmd::ClearPackets(chain_id) => self.clear_packets(&chain_id),
+        }
+    }
+
+    /// Send back a handle to the runtime of the given chain, if it is running,
+    /// through the given channel.
+    fn get_chain(&self, chain_id: &ChainId, reply_to: Sender<Option<BoxHandle>>) -> CmdEffect {
+        let chain = self
+            .registry
+            .chains()
+            .find(|chain| &chain.id() == chain_id)
+            .cloned();
+
+        let _ = reply_to.try_send(chain);
+
+        CmdEffect::Nothing
+    }
+
+    /// Ask the packet workers relaying packets sent by the given chain
+    /// to clear their pending packets.
+    fn clear_packets(&self, chain_id: &ChainId) -> CmdEffect {
+        info!(chain.id=%chain_id, "clearing pending packets");
+
+        for worker in self.workers.packet_workers(chain_id) {
+            if let Err(e) = worker.clear_pending_packets() {
+                error!(chain.id=%chain_id, "failed to notify packet worker: {}", e);
+            }
         }
+
+        CmdEffect::Nothing
     }
 
     /// Dump the state of the supervisor into a [`SupervisorState`] value,
@@ -517,7 +517,7 @@ impl Supervisor {
             if !self.relay_on_object(&src_chain.id(), &object) {
                 info!(
                     "skipping events for '{}'. \
-                    reason: filtering is enabled and channel does not match any enabled channels",
+                    reason: filtering is enabled and the object is excluded by the packet filter policy",
                     object.short_name()
                 );
 
@@ -546,6 +546,14 @@ impl Supervisor {
             }
         }
 
+        // Persist the height up to which the events of the chain were dispatched to the workers
+        if let Err(e) = src_chain.store_processed_height(height) {
+            error!(
+                "[{}] failed to persist the processed height {}: {}",
+                chain_id, height, e
+            );
+        }
+
         Ok(())
     }
 }
@@ -1,7 +1,7 @@
 use crossbeam_channel::Sender;
 use ibc::ics24_host::identifier::ChainId;
 
-use crate::config::ChainConfig;
+use crate::{chain::handle::ChainHandle, config::ChainConfig};
 
 use super::dump_state::SupervisorState;
 
@@ -16,6 +16,8 @@ pub enum ConfigUpdate {
 pub enum SupervisorCmd {
     UpdateConfig(ConfigUpdate),
     DumpState(Sender<SupervisorState>),
+    GetChain(ChainId, Sender<Option<Box<dyn ChainHandle>>>),
+    ClearPackets(ChainId),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -191,6 +191,21 @@ impl<'a> SpawnContext<'a> {
 
         let chain_id = chain.id();
 
+        let relay_on_client = self
+            .config
+            .read()
+            .expect("poisoned lock")
+            .relay_on_client(&chain_id, &client.client_id);
+
+        if !relay_on_client {
+            debug!(
+                "skipping workers for client {} on chain {}, reason: excluded by the packet filter policy",
+                client.client_id, chain_id
+            );
+
+            return;
+        }
+
         let conns_req = QueryClientConnectionsRequest {
             client_id: client.client_id.to_string(),
         };
@@ -220,6 +235,21 @@ impl<'a> SpawnContext<'a> {
     ) {
         let chain_id = chain.id();
 
+        let relay_on_connection = self
+            .config
+            .read()
+            .expect("poisoned lock")
+            .relay_on_connection(&chain_id, &connection_id);
+
+        if !relay_on_connection {
+            debug!(
+                "skipping workers for connection {} on chain {}, reason: excluded by the packet filter policy",
+                connection_id, chain_id
+            );
+
+            return;
+        }
+
         let connection_end = match chain.query_connection(&connection_id, Height::zero()) {
             Ok(connection_end) => connection_end,
             Err(e) => {
@@ -410,11 +440,23 @@ impl<'a> SpawnContext<'a> {
         connection: &IdentifiedConnectionEnd,
         channel: IdentifiedChannelEnd,
     ) -> Result<(), Error> {
-        let handshake_enabled = self
-            .config
-            .read()
-            .expect("poisoned lock")
-            .handshake_enabled();
+        let (handshake_enabled, relay_on_channel) = {
+            let config = self.config.read().expect("poisoned lock");
+            (
+                config.handshake_enabled(),
+                config.relay_on_channel(&chain.id(), &channel.port_id, &channel.channel_id),
+            )
+        };
+
+        if !relay_on_channel {
+            debug!(
+                "skipping workers for channel {} on chain {}, reason: excluded by the packet filter policy",
+                channel.channel_id,
+                chain.id()
+            );
+
+            return Ok(());
+        }
 
         let counterparty_chain = self
             .registry
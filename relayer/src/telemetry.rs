@@ -10,6 +10,31 @@ pub struct TelemetryDisabled;
 #[cfg(not(feature = "telemetry"))]
 pub type Telemetry = TelemetryDisabled;
 
+/// Records the time between when this value is allocated and when it is dropped
+/// as the latency of the given operation, in the telemetry state of the process.
+#[cfg(feature = "telemetry")]
+pub struct LatencyTimer {
+    operation: String,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "telemetry")]
+impl LatencyTimer {
+    pub fn new(operation: String) -> Self {
+        Self {
+            operation,
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "telemetry")]
+impl Drop for LatencyTimer {
+    fn drop(&mut self) {
+        ibc_telemetry::global().query_latency(&self.operation, self.start.elapsed());
+    }
+}
+
 /// A macro to send metric updates via a telemetry handle,
 /// only if the `telemetry` feature is enabled.
 /// Otherwise, it compiles to a no-op.
@@ -95,4 +95,44 @@ where
 
         Ok(())
     }
+
+    pub fn remove(&self, db: &sled::Db, key: &K) -> Result<(), error::Error> {
+        let key_bytes = serde_cbor::to_vec(&key).map_err(|e| error::Kind::Store.context(e))?;
+
+        let prefixed_key_bytes = self.prefixed_key(key_bytes);
+
+        db.remove(prefixed_key_bytes)
+            .map(|_| ())
+            .map_err(|e| error::Kind::Store.context(e))?;
+
+        Ok(())
+    }
+
+    /// Iterate over the values stored under this prefix, in the lexicographic order of their
+    /// CBOR-encoded keys. Entries which cannot be read or decoded are skipped.
+    pub fn iter(&self, db: &sled::Db) -> impl DoubleEndedIterator<Item = V> {
+        db.scan_prefix(&self.prefix)
+            .filter_map(|entry| entry.ok())
+            .filter_map(|(_, bytes)| serde_cbor::from_slice(&bytes).ok())
+    }
+
+    /// Iterate over the values whose CBOR-encoded keys lie within the inclusive range
+    /// `[from, to]`, in the lexicographic order of their CBOR-encoded keys.
+    /// Entries which cannot be read or decoded are skipped.
+    pub fn range(
+        &self,
+        db: &sled::Db,
+        from: &K,
+        to: &K,
+    ) -> Result<impl DoubleEndedIterator<Item = V>, error::Error> {
+        let from_bytes = serde_cbor::to_vec(from).map_err(|e| error::Kind::Store.context(e))?;
+        let to_bytes = serde_cbor::to_vec(to).map_err(|e| error::Kind::Store.context(e))?;
+
+        let range = self.prefixed_key(from_bytes)..=self.prefixed_key(to_bytes);
+
+        Ok(db
+            .range(range)
+            .filter_map(|entry| entry.ok())
+            .filter_map(|(_, bytes)| serde_cbor::from_slice(&bytes).ok()))
+    }
 }
@@ -103,6 +103,8 @@ impl ChannelWorker {
                         })
                     }
 
+                    WorkerCmd::ClearPendingPackets => continue,
+
                     WorkerCmd::Shutdown => {
                         info!(channel = %self.channel.short_name(), "shutting down Channel worker");
                         return Ok(());
@@ -121,6 +121,7 @@ impl ClientWorker {
             }
             WorkerCmd::Shutdown => true,
             WorkerCmd::NewBlock { .. } => false,
+            WorkerCmd::ClearPendingPackets => false,
         }
     }
 
@@ -11,6 +11,9 @@ pub enum WorkerCmd {
     /// A batch of [`NewBlock`] events need to be relayed
     NewBlock { height: Height, new_block: NewBlock },
 
+    /// Clear the pending packets of a channel, regardless of the packet clearing interval
+    ClearPendingPackets,
+
     /// Shutdown the worker
     Shutdown,
 }
@@ -107,6 +107,8 @@ impl ConnectionWorker {
                         })
                     }
 
+                    WorkerCmd::ClearPendingPackets => continue,
+
                     WorkerCmd::Shutdown => {
                         info!(connection = %self.connection.short_name(), "shutting down Connection worker");
                         return Ok(());
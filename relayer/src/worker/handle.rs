@@ -56,6 +56,12 @@ impl WorkerHandle {
         Ok(())
     }
 
+    /// Ask the worker to clear the pending packets of its channel.
+    pub fn clear_pending_packets(&self) -> Result<(), BoxError> {
+        self.tx.send(WorkerCmd::ClearPendingPackets)?;
+        Ok(())
+    }
+
     /// Shutdown the worker.
     pub fn shutdown(&self) -> Result<(), BoxError> {
         self.tx.send(WorkerCmd::Shutdown)?;
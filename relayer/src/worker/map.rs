@@ -67,6 +67,18 @@ impl WorkerMap {
         })
     }
 
+    /// Returns all the packet [`Worker`]s relaying packets sent by the chain
+    /// with the given [`ChainId`].
+    pub fn packet_workers<'a>(
+        &'a self,
+        src_chain_id: &'a ChainId,
+    ) -> impl Iterator<Item = &'a WorkerHandle> {
+        self.workers.iter().filter_map(move |(o, w)| match o {
+            Object::Packet(p) if &p.src_chain_id == src_chain_id => Some(w),
+            _ => None,
+        })
+    }
+
     /// Get a handle to the worker in charge of handling events associated
     /// with the given [`Object`].
     ///
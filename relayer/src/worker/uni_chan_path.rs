@@ -4,9 +4,11 @@ use anomaly::BoxError;
 use crossbeam_channel::Receiver;
 use tracing::{error, info, warn};
 
+use ibc::{events::IbcEvent, ics04_channel::channel::Order};
+
 use crate::{
     chain::handle::ChainHandlePair,
-    link::{Link, LinkParameters, RelaySummary},
+    link::{Link, LinkError, LinkParameters, RelaySummary},
     object::Packet,
     telemetry,
     telemetry::Telemetry,
@@ -64,6 +66,9 @@ impl PacketWorker {
             return Ok(());
         }
 
+        // Whether the channel may be closing, after a timeout on an ordered channel
+        let mut channel_closing = false;
+
         loop {
             const BACKOFF: Duration = Duration::from_millis(200);
 
@@ -79,8 +84,28 @@ impl PacketWorker {
             });
 
             match result {
-                Ok(Step::Success(_summary)) => {
-                    telemetry!(self.packet_metrics(&_summary));
+                Ok(Step::Success(summary)) => {
+                    telemetry!(self.packet_metrics(&summary));
+
+                    channel_closing |= link.a_to_b.channel().ordering == Order::Ordered
+                        && summary.events.iter().any(|e| {
+                            matches!(
+                                e,
+                                IbcEvent::TimeoutPacket(_)
+                                    | IbcEvent::TimeoutOnClosePacket(_)
+                                    | IbcEvent::CloseConfirmChannel(_)
+                            )
+                        });
+
+                    // Once both ends of the channel are closed and the pending packets
+                    // are timed out, there is nothing left to relay on this path.
+                    if channel_closing
+                        && !link.a_to_b.has_scheduled_operational_data()
+                        && link.is_closed()?
+                    {
+                        info!(path = %self.path.short_name(), "channel is closed, exiting");
+                        return Ok(());
+                    }
                 }
 
                 Ok(Step::Shutdown) => {
@@ -99,6 +124,8 @@ impl PacketWorker {
         if let Some(cmd) = cmd {
             let result = match cmd {
                 WorkerCmd::IbcEvents { batch } => {
+                    telemetry!(self.send_packet_metrics(&batch));
+
                     // Update scheduled batches.
                     link.a_to_b.update_schedule(batch)
                 }
@@ -118,6 +145,14 @@ impl PacketWorker {
                     }
                 }
 
+                // Clear the pending packets up to the latest height of the source chain.
+                WorkerCmd::ClearPendingPackets => link
+                    .a_to_b
+                    .src_chain()
+                    .query_latest_height()
+                    .map_err(LinkError::from)
+                    .and_then(|height| link.a_to_b.clear_packets(height)),
+
                 WorkerCmd::Shutdown => {
                     return RetryResult::Ok(Step::Shutdown);
                 }
@@ -168,6 +203,53 @@ impl PacketWorker {
         self.receive_packet_metrics(summary);
         self.acknowledgment_metrics(summary);
         self.timeout_metrics(summary);
+        self.relay_latency_metrics(summary);
+    }
+
+    #[cfg(feature = "telemetry")]
+    fn send_packet_metrics(&self, batch: &crate::event::monitor::EventBatch) {
+        use ibc::events::IbcEvent::SendPacket;
+
+        for event in &batch.events {
+            if let SendPacket(send_packet) = event {
+                let packet = &send_packet.packet;
+                self.telemetry.send_packet_observed(
+                    &self.path.src_chain_id,
+                    &packet.source_channel,
+                    &packet.source_port,
+                    packet.sequence,
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "telemetry")]
+    fn relay_latency_metrics(&self, summary: &RelaySummary) {
+        use ibc::events::IbcEvent::{TimeoutPacket, WriteAcknowledgement};
+
+        for event in &summary.events {
+            match event {
+                WriteAcknowledgement(write_ack) => {
+                    let packet = &write_ack.packet;
+                    self.telemetry.receive_packet_committed(
+                        &self.path.src_chain_id,
+                        &packet.source_channel,
+                        &packet.source_port,
+                        packet.sequence,
+                    );
+                }
+                TimeoutPacket(timeout) => {
+                    let packet = &timeout.packet;
+                    self.telemetry.forget_packet(
+                        &self.path.src_chain_id,
+                        &packet.source_channel,
+                        &packet.source_port,
+                        packet.sequence,
+                    );
+                }
+                _ => {}
+            }
+        }
     }
 
     #[cfg(feature = "telemetry")]
@@ -8,20 +8,35 @@ use std::{
     thread::JoinHandle,
 };
 
+use once_cell::sync::Lazy;
+
+pub use crate::server::Api;
 pub use crate::state::TelemetryState;
 
+static GLOBAL_STATE: Lazy<Arc<TelemetryState>> = Lazy::new(|| Arc::new(TelemetryState::default()));
+
+/// Returns the telemetry state of the process.
+///
+/// There is a single telemetry state per process, as the Prometheus exporter
+/// it is built upon installs itself as the global OpenTelemetry meter provider.
+pub fn global() -> &'static Arc<TelemetryState> {
+    &GLOBAL_STATE
+}
+
+/// Returns a handle to the telemetry state of the process.
 pub fn new_state() -> Arc<TelemetryState> {
-    Arc::new(TelemetryState::default())
+    global().clone()
 }
 
 pub fn spawn<A>(
     address: A,
     state: Arc<TelemetryState>,
+    api: Option<Arc<dyn Api>>,
 ) -> Result<(SocketAddr, JoinHandle<()>), Box<dyn Error + Send + Sync>>
 where
     A: ToSocketAddrs + Send + 'static,
 {
-    let server = server::listen(address, state);
+    let server = server::listen(address, state, api);
 
     match server {
         Ok(server) => {
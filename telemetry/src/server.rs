@@ -5,6 +5,15 @@ use rouille::{Request, Response, Server};
 
 use crate::state::TelemetryState;
 
+/// A JSON REST API served by the telemetry server alongside the metrics.
+pub trait Api: Send + Sync {
+    /// Handle the request with the given method and path, eg. `GET` and `/chains`.
+    ///
+    /// Returns the status code and the JSON body of the response, or `None`
+    /// if the request does not match any endpoint of the API.
+    fn handle(&self, method: &str, path: &str) -> Option<(u16, String)>;
+}
+
 enum Route {
     Metrics,
     Other,
@@ -23,6 +32,7 @@ impl Route {
 pub fn listen(
     address: impl ToSocketAddrs,
     telemetry_state: Arc<TelemetryState>,
+    api: Option<Arc<dyn Api>>,
 ) -> Result<Server<impl Fn(&Request) -> Response>, Box<dyn Error + Send + Sync>> {
     let server = Server::new(address, move |request| {
         match Route::from_request(request) {
@@ -36,9 +46,14 @@ pub fn listen(
                 rouille::Response::from_data(encoder.format_type().to_string(), buffer)
             }
 
-            // Any other route
-            // Return an empty response with a 404 status code.
-            Route::Other => rouille::Response::empty_404(),
+            // Any other route is handled by the REST API, if any.
+            // Return an empty response with a 404 status code if it does not match any endpoint.
+            Route::Other => api
+                .as_ref()
+                .and_then(|api| api.handle(request.method(), &request.url()))
+                .map_or_else(rouille::Response::empty_404, |(status, body)| {
+                    rouille::Response::from_data("application/json", body).with_status_code(status)
+                }),
         }
     })?;
 
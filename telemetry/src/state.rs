@@ -1,15 +1,87 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 use opentelemetry::{
     global,
-    metrics::{Counter, UpDownCounter},
+    metrics::{Counter, UpDownCounter, ValueObserver, ValueRecorder},
     KeyValue,
 };
 use opentelemetry_prometheus::PrometheusExporter;
 
+use ibc::ics04_channel::packet::Sequence;
 use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
 use prometheus::proto::MetricFamily;
 
+/// The boundaries of the buckets of all histograms, which record durations in milliseconds
+/// as well as amounts of gas.
+const HISTOGRAM_BOUNDARIES: [f64; 16] = [
+    10.0,
+    50.0,
+    100.0,
+    250.0,
+    500.0,
+    1_000.0,
+    2_500.0,
+    5_000.0,
+    10_000.0,
+    30_000.0,
+    60_000.0,
+    100_000.0,
+    300_000.0,
+    1_000_000.0,
+    3_000_000.0,
+    10_000_000.0,
+];
+
+/// The time after which the observation of the `SendPacket` event of a packet which is
+/// neither received nor forgotten, eg. because it is relayed by another relayer, is dropped.
+const SEND_PACKET_OBSERVATION_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A packet sent on a channel, identified by its source chain, channel, port and sequence.
+type PacketKey = (ChainId, ChannelId, PortId, Sequence);
+
+/// The time at which the `SendPacket` events of the packets not relayed yet were observed.
+/// The observations older than `SEND_PACKET_OBSERVATION_TTL` are dropped at most once per
+/// TTL, such that each observation is kept for less than twice the TTL.
+#[derive(Debug)]
+struct SendPacketObservations {
+    observed: HashMap<PacketKey, Instant>,
+    pruned_at: Instant,
+}
+
+impl SendPacketObservations {
+    fn new() -> Self {
+        Self {
+            observed: HashMap::new(),
+            pruned_at: Instant::now(),
+        }
+    }
+
+    fn observe(&mut self, key: PacketKey) {
+        let now = Instant::now();
+
+        if now.duration_since(self.pruned_at) >= SEND_PACKET_OBSERVATION_TTL {
+            self.observed
+                .retain(|_, observed| now.duration_since(*observed) < SEND_PACKET_OBSERVATION_TTL);
+            self.pruned_at = now;
+        }
+
+        self.observed.entry(key).or_insert(now);
+    }
+
+    fn forget(&mut self, key: &PacketKey) -> Option<Instant> {
+        self.observed.remove(key)
+    }
+}
+
+/// The balance of a wallet, identified by its chain, account and denomination.
+type BalanceKey = (ChainId, String, String);
+
+/// The type of an endpoint of the nodes of a chain, eg. `rpc`, identified by its chain.
+type EndpointKey = (ChainId, String);
+
 #[derive(Copy, Clone, Debug)]
 pub enum WorkerType {
     Client,
@@ -50,6 +122,43 @@ pub struct TelemetryState {
 
     /// Number of timeout packets relayed, per channel
     timeout_packets: Counter<u64>,
+
+    /// Number of transactions retried after an account sequence mismatch, per chain
+    tx_sequence_mismatch_retries: Counter<u64>,
+
+    /// Time between the observation of a `SendPacket` event and the commit of the
+    /// corresponding `RecvPacket` message, per channel
+    packet_relay_latency: ValueRecorder<u64>,
+
+    /// The time at which the `SendPacket` events of the packets not relayed yet were observed
+    send_packets_observed: Mutex<SendPacketObservations>,
+
+    /// Time between the broadcast of transactions and their commit, per chain
+    tx_confirmation_time: ValueRecorder<u64>,
+
+    /// Gas estimated by the simulation of transactions, per chain
+    tx_gas_estimated: ValueRecorder<u64>,
+
+    /// Gas used by committed transactions, per chain
+    tx_gas_used: ValueRecorder<u64>,
+
+    /// Fees paid by the relayer, per chain and denomination
+    tx_fees_paid: Counter<u64>,
+
+    /// Latency of the operations measured by the relayer, such as queries, per operation
+    query_latency: ValueRecorder<u64>,
+
+    /// The last known balances of the relayer wallets, per chain, account and denomination
+    wallet_balances: Arc<RwLock<HashMap<BalanceKey, f64>>>,
+
+    /// Observes the balances of the relayer wallets, kept alive for as long as the state
+    _wallet_balance_observer: ValueObserver<f64>,
+
+    /// The address of the endpoint in use, per chain and type of endpoint
+    active_endpoints: Arc<RwLock<HashMap<EndpointKey, String>>>,
+
+    /// Observes the endpoints in use, kept alive for as long as the state
+    _active_endpoint_observer: ValueObserver<u64>,
 }
 
 impl TelemetryState {
@@ -132,13 +241,158 @@ impl TelemetryState {
 
         self.timeout_packets.add(count, labels);
     }
+
+    /// Number of transactions retried after an account sequence mismatch, per chain
+    pub fn tx_sequence_mismatch_retries(&self, chain: &ChainId, count: u64) {
+        let labels = &[KeyValue::new("chain", chain.to_string())];
+
+        self.tx_sequence_mismatch_retries.add(count, labels);
+    }
+
+    /// Record the observation of the `SendPacket` event of a packet,
+    /// unless the packet was already observed.
+    pub fn send_packet_observed(
+        &self,
+        src_chain: &ChainId,
+        src_channel: &ChannelId,
+        src_port: &PortId,
+        sequence: Sequence,
+    ) {
+        let key = (
+            src_chain.clone(),
+            src_channel.clone(),
+            src_port.clone(),
+            sequence,
+        );
+
+        self.send_packets_observed
+            .lock()
+            .expect("poisoned lock")
+            .observe(key);
+    }
+
+    /// Record the time elapsed since the observation of the `SendPacket` event of a packet
+    /// whose `RecvPacket` message was committed, if its `SendPacket` event was observed.
+    pub fn receive_packet_committed(
+        &self,
+        src_chain: &ChainId,
+        src_channel: &ChannelId,
+        src_port: &PortId,
+        sequence: Sequence,
+    ) {
+        let observed = self.forget_packet(src_chain, src_channel, src_port, sequence);
+
+        if let Some(observed) = observed {
+            let labels = &[
+                KeyValue::new("src_chain", src_chain.to_string()),
+                KeyValue::new("src_channel", src_channel.to_string()),
+                KeyValue::new("src_port", src_port.to_string()),
+            ];
+
+            self.packet_relay_latency
+                .record(millis(observed.elapsed()), labels);
+        }
+    }
+
+    /// Forget the observation of the `SendPacket` event of a packet which will not be
+    /// received, eg. because it timed out, and return the time at which it was observed.
+    pub fn forget_packet(
+        &self,
+        src_chain: &ChainId,
+        src_channel: &ChannelId,
+        src_port: &PortId,
+        sequence: Sequence,
+    ) -> Option<Instant> {
+        let key = (
+            src_chain.clone(),
+            src_channel.clone(),
+            src_port.clone(),
+            sequence,
+        );
+
+        self.send_packets_observed
+            .lock()
+            .expect("poisoned lock")
+            .forget(&key)
+    }
+
+    /// Time between the broadcast of transactions and their commit, per chain
+    pub fn tx_confirmation_time(&self, chain: &ChainId, elapsed: Duration) {
+        let labels = &[KeyValue::new("chain", chain.to_string())];
+
+        self.tx_confirmation_time.record(millis(elapsed), labels);
+    }
+
+    /// Gas estimated by the simulation of a transaction, per chain
+    pub fn tx_gas_estimated(&self, chain: &ChainId, gas: u64) {
+        let labels = &[KeyValue::new("chain", chain.to_string())];
+
+        self.tx_gas_estimated.record(gas, labels);
+    }
+
+    /// Gas used by a committed transaction, per chain
+    pub fn tx_gas_used(&self, chain: &ChainId, gas: u64) {
+        let labels = &[KeyValue::new("chain", chain.to_string())];
+
+        self.tx_gas_used.record(gas, labels);
+    }
+
+    /// Fees paid by the relayer, per chain and denomination
+    pub fn tx_fees_paid(&self, chain: &ChainId, denom: &str, amount: u64) {
+        let labels = &[
+            KeyValue::new("chain", chain.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.tx_fees_paid.add(amount, labels);
+    }
+
+    /// Latency of an operation of the relayer, such as a query, per operation
+    pub fn query_latency(&self, query: &str, elapsed: Duration) {
+        let labels = &[KeyValue::new("query", query.to_string())];
+
+        self.query_latency.record(millis(elapsed), labels);
+    }
+
+    /// Update the balance of a relayer wallet, per chain, account and denomination
+    pub fn wallet_balance(&self, chain: &ChainId, account: &str, denom: &str, amount: f64) {
+        let key = (chain.clone(), account.to_string(), denom.to_string());
+
+        self.wallet_balances
+            .write()
+            .expect("poisoned lock")
+            .insert(key, amount);
+    }
+
+    /// Update the address of the endpoint in use, per chain and type of endpoint,
+    /// eg. `rpc`, `grpc` or `websocket`
+    pub fn active_endpoint(&self, chain: &ChainId, endpoint_type: &str, address: &str) {
+        let key = (chain.clone(), endpoint_type.to_string());
+
+        self.active_endpoints
+            .write()
+            .expect("poisoned lock")
+            .insert(key, address.to_string());
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
 }
 
 impl Default for TelemetryState {
     fn default() -> Self {
-        let exporter = opentelemetry_prometheus::exporter().init();
+        let exporter = opentelemetry_prometheus::exporter()
+            .with_default_histogram_boundaries(HISTOGRAM_BOUNDARIES.to_vec())
+            .init();
         let meter = global::meter("hermes");
 
+        let wallet_balances: Arc<RwLock<HashMap<BalanceKey, f64>>> = Default::default();
+        let observed_balances = wallet_balances.clone();
+
+        let active_endpoints: Arc<RwLock<HashMap<EndpointKey, String>>> = Default::default();
+        let observed_endpoints = active_endpoints.clone();
+
         Self {
             exporter,
 
@@ -171,6 +425,90 @@ impl Default for TelemetryState {
                 .u64_counter("ibc_timeout_packets")
                 .with_description("Number of timeout packets relayed per channel")
                 .init(),
+
+            tx_sequence_mismatch_retries: meter
+                .u64_counter("tx_sequence_mismatch_retries")
+                .with_description(
+                    "Number of transactions retried after an account sequence mismatch per chain",
+                )
+                .init(),
+
+            packet_relay_latency: meter
+                .u64_value_recorder("packet_relay_latency")
+                .with_description(
+                    "Milliseconds between the observation of a SendPacket event and the commit \
+                     of the corresponding RecvPacket message per channel",
+                )
+                .init(),
+
+            send_packets_observed: Mutex::new(SendPacketObservations::new()),
+
+            tx_confirmation_time: meter
+                .u64_value_recorder("tx_confirmation_time")
+                .with_description(
+                    "Milliseconds between the broadcast of transactions and their commit per chain",
+                )
+                .init(),
+
+            tx_gas_estimated: meter
+                .u64_value_recorder("tx_gas_estimated")
+                .with_description("Gas estimated by the simulation of transactions per chain")
+                .init(),
+
+            tx_gas_used: meter
+                .u64_value_recorder("tx_gas_used")
+                .with_description("Gas used by committed transactions per chain")
+                .init(),
+
+            tx_fees_paid: meter
+                .u64_counter("tx_fees_paid")
+                .with_description("Fees paid by the relayer per chain and denomination")
+                .init(),
+
+            query_latency: meter
+                .u64_value_recorder("query_latency")
+                .with_description(
+                    "Milliseconds taken by queries and other operations per operation",
+                )
+                .init(),
+
+            wallet_balances,
+
+            _wallet_balance_observer: meter
+                .f64_value_observer("wallet_balance", move |result| {
+                    let balances = observed_balances.read().expect("poisoned lock");
+                    for ((chain, account, denom), amount) in balances.iter() {
+                        let labels = &[
+                            KeyValue::new("chain", chain.to_string()),
+                            KeyValue::new("account", account.clone()),
+                            KeyValue::new("denom", denom.clone()),
+                        ];
+                        result.observe(*amount, labels);
+                    }
+                })
+                .with_description(
+                    "Balance of the relayer wallets per chain, account and denomination",
+                )
+                .init(),
+
+            active_endpoints,
+
+            _active_endpoint_observer: meter
+                .u64_value_observer("active_endpoint", move |result| {
+                    let endpoints = observed_endpoints.read().expect("poisoned lock");
+                    for ((chain, endpoint_type), address) in endpoints.iter() {
+                        let labels = &[
+                            KeyValue::new("chain", chain.to_string()),
+                            KeyValue::new("type", endpoint_type.clone()),
+                            KeyValue::new("address", address.clone()),
+                        ];
+                        result.observe(1, labels);
+                    }
+                })
+                .with_description(
+                    "Endpoint in use per chain and type of endpoint, labelled with its address",
+                )
+                .init(),
         }
     }
 }